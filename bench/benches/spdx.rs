@@ -0,0 +1,209 @@
+use bench::corpus;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use spdx::{Expression, Licensee, ParseMode};
+
+/// Licensees commonly accepted by projects, in priority order
+const LICENSEES: &[&str] = &[
+    "MIT",
+    "Apache-2.0",
+    "Apache-2.0 WITH LLVM-exception",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "ISC",
+    "Zlib",
+    "Unicode-DFS-2016",
+    "Unicode-3.0",
+    "MPL-2.0",
+    "CC0-1.0",
+    "0BSD",
+    "BSL-1.0",
+    "Unlicense",
+    "MIT-0",
+    "OpenSSL",
+    "OFL-1.1",
+    "CC-BY-4.0",
+    "X11",
+    "NCSA",
+    "BSD-1-Clause",
+    "PostgreSQL",
+    "Python-2.0",
+    "LGPL-2.1",
+    "LGPL-3.0",
+    "GPL-2.0",
+    "GPL-3.0",
+    "EPL-2.0",
+    "EUPL-1.2",
+    "Artistic-2.0",
+];
+
+fn licensees(count: usize) -> Vec<Licensee> {
+    LICENSEES[..count]
+        .iter()
+        .map(|l| Licensee::parse(l).unwrap())
+        .collect()
+}
+
+fn license_id(c: &mut Criterion) {
+    let mut group = c.benchmark_group("license_id");
+    group.bench_function("hit", |b| {
+        b.iter(|| spdx::license_id(black_box("Apache-2.0")));
+    });
+    group.bench_function("miss", |b| {
+        b.iter(|| spdx::license_id(black_box("Apache-2.1")));
+    });
+    group.finish();
+}
+
+/// The expressions benchmarked by both `parse` and `validate`
+const EXPRESSIONS: &[(&str, &str)] = &[
+    ("short", "MIT"),
+    ("typical", "MIT OR Apache-2.0"),
+    (
+        "long",
+        "((MIT OR Apache-2.0) AND Unicode-DFS-2016) OR (Apache-2.0 WITH LLVM-exception AND \
+         (BSD-3-Clause OR ISC)) OR (GPL-2.0-or-later WITH Classpath-exception-2.0 AND \
+         DocumentRef-Doc:LicenseRef-Embark)",
+    ),
+];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for (name, text) in EXPRESSIONS {
+        group.bench_with_input(BenchmarkId::from_parameter(name), text, |b, text| {
+            b.iter(|| Expression::parse(black_box(text)).unwrap());
+        });
+    }
+
+    group.bench_function("corpus", |b| {
+        b.iter(|| {
+            for text in corpus() {
+                let _ = black_box(Expression::parse_mode(text, ParseMode::LAX));
+            }
+        });
+    });
+    group.finish();
+}
+
+fn validate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate");
+    for (name, text) in EXPRESSIONS {
+        group.bench_with_input(BenchmarkId::from_parameter(name), text, |b, text| {
+            b.iter(|| Expression::validate(black_box(text), ParseMode::STRICT).unwrap());
+        });
+    }
+
+    group.bench_function("corpus", |b| {
+        b.iter(|| {
+            for text in corpus() {
+                let _ = black_box(Expression::validate(text, ParseMode::LAX));
+            }
+        });
+    });
+    group.finish();
+}
+
+fn decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    for (name, text) in EXPRESSIONS {
+        let bytes = Expression::parse(text).unwrap().to_bytes();
+
+        group.bench_with_input(BenchmarkId::from_parameter(name), &bytes, |b, bytes| {
+            b.iter(|| Expression::from_bytes(black_box(bytes)).unwrap());
+        });
+    }
+
+    let encoded: Vec<_> = corpus()
+        .filter_map(|text| Expression::parse_mode(text, ParseMode::LAX).ok())
+        .map(|expr| expr.to_bytes())
+        .collect();
+
+    group.bench_function("corpus", |b| {
+        b.iter(|| {
+            for bytes in &encoded {
+                let _ = black_box(Expression::from_bytes(bytes));
+            }
+        });
+    });
+    group.finish();
+}
+
+fn canonicalize(c: &mut Criterion) {
+    // The strings that only parse in lax mode, ie. the ones that actually
+    // need to be canonicalized
+    let messy: Vec<_> = corpus()
+        .filter(|text| {
+            Expression::parse(text).is_err() && Expression::parse_mode(text, ParseMode::LAX).is_ok()
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("canonicalize");
+    group.bench_function("messy", |b| {
+        b.iter(|| {
+            for text in &messy {
+                let _ = black_box(Expression::canonicalize(text));
+            }
+        });
+    });
+
+    // Every corpus entry, the same work as `canonicalize_batch`, but
+    // allocating a new string for every entry
+    group.bench_function("corpus", |b| {
+        b.iter(|| {
+            for text in corpus() {
+                let _ = black_box(
+                    Expression::validate(text, ParseMode::LAX)
+                        .and_then(|()| Expression::canonicalize(text)),
+                );
+            }
+        });
+    });
+    group.bench_function("corpus_batch", |b| {
+        b.iter(|| black_box(Expression::canonicalize_batch(corpus())));
+    });
+    group.finish();
+}
+
+fn evaluate(c: &mut Criterion) {
+    let expr = Expression::parse(
+        "(MIT OR Apache-2.0) AND Unicode-DFS-2016 AND (BSD-3-Clause OR ISC OR GPL-3.0-or-later)",
+    )
+    .unwrap();
+    let accepted = licensees(10);
+
+    c.bench_function("evaluate", |b| {
+        b.iter(|| black_box(&expr).evaluate(|req| accepted.iter().any(|lic| lic.satisfies(req))));
+    });
+}
+
+fn minimize(c: &mut Criterion) {
+    let expr = Expression::parse(
+        "(MIT OR Apache-2.0 OR Zlib) AND (Unicode-DFS-2016 OR Unicode-3.0) AND \
+         (BSD-3-Clause OR ISC OR 0BSD) AND (MPL-2.0 OR LGPL-2.1-or-later)",
+    )
+    .unwrap();
+
+    let mut group = c.benchmark_group("minimized_requirements");
+    for count in [10, 30] {
+        let accepted = licensees(count);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(count),
+            &accepted,
+            |b, accepted| {
+                b.iter(|| black_box(&expr).minimized_requirements(accepted).unwrap());
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    license_id,
+    parse,
+    validate,
+    decode,
+    canonicalize,
+    evaluate,
+    minimize
+);
+criterion_main!(benches);
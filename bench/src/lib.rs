@@ -0,0 +1,11 @@
+//! Benchmarks for the spdx crate, run with `cargo bench --manifest-path bench/Cargo.toml`
+
+/// The license strings from `tests/fixtures/crates-io-licenses.txt`, which is
+/// shared with the corpus test
+pub fn corpus() -> impl Iterator<Item = &'static str> {
+    const CORPUS: &str = include_str!("../../tests/fixtures/crates-io-licenses.txt");
+
+    CORPUS
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+}
@@ -0,0 +1,84 @@
+use crate::{Expression, ParseError, ParseMode};
+
+/// The license of a crate, as specified by the `license` and `license-file`
+/// fields of its `Cargo.toml`, see [`parse_license_field_opt`]
+#[derive(Debug, PartialEq)]
+// The vast majority of crates have an expression, so boxing it would just be an
+// extra allocation
+#[allow(clippy::large_enum_variant)]
+pub enum CargoLicense {
+    /// The crate has a `license` expression, which takes precedence over a
+    /// `license-file`
+    Expression(Expression),
+    /// The crate only has a `license-file`, the path of which is relative to
+    /// the crate's manifest
+    FileOnly(String),
+    /// The crate specifies neither
+    Missing,
+}
+
+/// Parses the value of the `license` field of a `Cargo.toml` following the
+/// conventions of crates.io, which has accepted non-standard expressions for
+/// many years, so many published crates still use them.
+///
+/// The expression is parsed with [`ParseMode::LAX`], eg. `MIT/Apache-2.0` is
+/// accepted, and is [canonicalized](Expression::parse_canonical), so that
+/// imprecise names are replaced with the correct SPDX identifiers, and
+/// deprecated license identifiers with their
+/// [successors](crate::LicenseId::successor), if they have one.
+///
+/// ```
+/// let expr = spdx::cargo::parse_license_field("MIT/Apache-2.0").unwrap();
+/// assert_eq!(expr.to_string(), "MIT OR Apache-2.0");
+///
+/// let expr = spdx::cargo::parse_license_field("BSD-2-Clause-FreeBSD").unwrap();
+/// assert_eq!(expr.to_string(), "BSD-2-Clause");
+///
+/// let expr = spdx::cargo::parse_license_field("GPL-3.0").unwrap();
+/// assert_eq!(expr.to_string(), "GPL-3.0-only");
+/// ```
+///
+/// # Errors
+///
+/// Fails if the value is not a valid expression even with the crates.io
+/// conventions. The error's span always refers to the value as it was written
+/// in the manifest.
+pub fn parse_license_field(value: &str) -> Result<Expression, ParseError> {
+    Expression::parse_canonical(value, ParseMode::LAX).map(|(expr, _)| expr)
+}
+
+/// Gets the license of a crate from the values of both its `license` and
+/// `license-file` fields, if they are present.
+///
+/// As with Cargo, the `license` takes precedence if both are specified, and
+/// empty fields are treated as if they were not present.
+///
+/// ```
+/// use spdx::cargo::{parse_license_field_opt, CargoLicense};
+///
+/// assert_eq!(
+///     parse_license_field_opt(None, Some("LICENSE")).unwrap(),
+///     CargoLicense::FileOnly("LICENSE".to_owned()),
+/// );
+/// assert_eq!(parse_license_field_opt(Some(" "), None).unwrap(), CargoLicense::Missing);
+/// ```
+///
+/// # Errors
+///
+/// Fails if the `license` is not a valid expression, see [`parse_license_field`]
+pub fn parse_license_field_opt(
+    license: Option<&str>,
+    license_file: Option<&str>,
+) -> Result<CargoLicense, ParseError> {
+    fn non_empty(value: Option<&str>) -> Option<&str> {
+        value.filter(|value| !value.trim().is_empty())
+    }
+
+    if let Some(license) = non_empty(license) {
+        parse_license_field(license).map(CargoLicense::Expression)
+    } else if let Some(license_file) = non_empty(license_file) {
+        Ok(CargoLicense::FileOnly(license_file.to_owned()))
+    } else {
+        Ok(CargoLicense::Missing)
+    }
+}
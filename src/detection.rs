@@ -0,0 +1,46 @@
+mod normalize;
+
+use crate::{text::LICENSE_TEXT_HASHES, LicenseId};
+
+/// Finds the license whose SPDX text is the same as `text`, other than
+/// differences in whitespace, and the copyright notices before the body of the
+/// text, eg. a `LICENSE` file that is a verbatim copy of one of the SPDX texts.
+///
+/// This only hashes the text and looks it up in a table of the hashes of the
+/// SPDX texts, so it is very fast, and doesn't allocate, but any other
+/// differences, no matter how small, mean that no license is found.
+///
+/// ```
+/// let mit = spdx::license_id("MIT").unwrap();
+/// let text = mit
+///     .text()
+///     .replace("<year> <copyright holders>", "2019 Embark Studios");
+///
+/// assert_eq!(spdx::detection::exact_match(&text), Some(mit));
+/// assert_eq!(spdx::detection::exact_match("MIT License"), None);
+/// ```
+#[must_use]
+pub fn exact_match(text: &str) -> Option<LicenseId> {
+    let hash = normalize::text_hash(text)?;
+    let start = LICENSE_TEXT_HASHES.partition_point(|(h, _)| *h < hash);
+
+    let mut found = None;
+    for (_, index) in LICENSE_TEXT_HASHES[start..]
+        .iter()
+        .take_while(|(h, _)| *h == hash)
+    {
+        let Some(id) = LicenseId::from_index(*index) else {
+            continue;
+        };
+
+        // Several licenses can have the same text, eg. the deprecated
+        // `GPL-2.0` and `GPL-2.0-only`, so prefer the ones that are current
+        if !id.is_deprecated() {
+            return Some(id);
+        }
+
+        found.get_or_insert(id);
+    }
+
+    found
+}
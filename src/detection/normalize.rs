@@ -0,0 +1,78 @@
+//! Normalization of license texts for exact matching. This is also used by the
+//! update tool to generate the hashes of the SPDX license texts, so it must not
+//! depend on anything else in the crate.
+
+/// A line in the header, ie. before the body of the text, with at least this
+/// many words is considered to be the start of the body
+const MIN_BODY_WORDS: usize = 8;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Hashes the words of a license text, ignoring any differences in whitespace,
+/// as well as the copyright notices in the header, as they are placeholders in
+/// the SPDX texts, eg. `Copyright (c) <year> <copyright holders>`
+///
+/// Returns `None` if there are no words other than copyright notices.
+pub fn text_hash(text: &str) -> Option<u64> {
+    let mut hash = FNV_OFFSET;
+    let mut has_words = false;
+
+    // Whether the body of the text hasn't been reached yet
+    let mut in_header = true;
+    // Whether the previous line was part of a copyright notice in the header,
+    // so that short lines that continue it, eg. more copyright holders, are
+    // also skipped
+    let mut skipping = false;
+
+    for line in text.lines() {
+        let words = line.split_whitespace().count();
+        if words == 0 {
+            skipping = false;
+            continue;
+        }
+
+        if in_header {
+            if is_copyright(line) {
+                skipping = true;
+                continue;
+            }
+
+            if skipping && words < MIN_BODY_WORDS {
+                continue;
+            }
+
+            skipping = false;
+            in_header = words < MIN_BODY_WORDS;
+        }
+
+        for word in line.split_whitespace() {
+            if has_words {
+                hash = fnv(hash, b" ");
+            }
+
+            has_words = true;
+            hash = fnv(hash, word.as_bytes());
+        }
+    }
+
+    has_words.then_some(hash)
+}
+
+fn is_copyright(line: &str) -> bool {
+    let line = line.trim_start();
+
+    ["copyright", "(c)", "\u{a9}"].iter().any(|prefix| {
+        line.get(..prefix.len())
+            .map_or(false, |start| start.eq_ignore_ascii_case(prefix))
+    })
+}
+
+fn fnv(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    hash
+}
@@ -11,6 +11,8 @@ pub struct ParseError {
     pub span: std::ops::Range<usize>,
     /// The specific reason for the error
     pub reason: Reason,
+    /// The mode the string was parsed with
+    pub mode: crate::ParseMode,
 }
 
 /// The particular reason for a `ParseError`
@@ -41,10 +43,131 @@ pub enum Reason {
     /// 2. Not an exception short-id
     /// 3. Not a document/license ref
     /// 4. Not an AND, OR, or WITH
+    ///
+    /// If only a license, or only an exception, is valid in the position of
+    /// the term, eg. after a `WITH`, [`Self::UnknownLicense`] or
+    /// [`Self::UnknownException`] is used instead
     UnknownTerm,
     /// GNU suffix licenses don't allow `+` because they already have
     /// the `-or-later` suffix to denote that
     GnuNoPlus,
+    /// An `AND` or `OR` operator, or a parenthesized group, was found where
+    /// only a single license, with an optional exception, is allowed, eg. a
+    /// [`Licensee`](crate::Licensee)
+    ExpressionNotAllowed,
+    /// A `DocumentRef-` was not of the form `DocumentRef-<id>:LicenseRef-<id>`
+    MalformedDocumentRef,
+    /// A `DocumentRef-` or `LicenseRef-` was not followed by an id
+    EmptyRefId,
+    /// A `+` was used on a GNU license with the `-only` suffix, eg.
+    /// `GPL-2.0-only+`, which contradicts itself
+    GnuPlusWithSuffix,
+}
+
+impl ParseError {
+    /// If the expression failed to parse because it used non-conforming
+    /// syntax or names, eg. `MIT/Apache-2.0` or `GPL-2.0+`, returns the
+    /// canonical expression to suggest instead, which is the same as parsing
+    /// the original with [`Expression::parse_canonical`](crate::Expression::parse_canonical)
+    /// and [`ParseMode::LAX`](crate::ParseMode::LAX).
+    ///
+    /// Returns `None` if the error can't be fixed that way, including if the
+    /// error was from parsing with a [`mode`](Self::mode) that already allows
+    /// everything that [`ParseMode::LAX`](crate::ParseMode::LAX) does, in which
+    /// case the expression isn't parsed again.
+    ///
+    /// ```
+    /// let err = spdx::Expression::parse("MIT/Apache-2.0").unwrap_err();
+    /// assert_eq!(err.would_lax_help().unwrap(), "MIT OR Apache-2.0");
+    ///
+    /// let err = spdx::Expression::parse("MIT OR").unwrap_err();
+    /// assert!(err.would_lax_help().is_none());
+    /// ```
+    #[must_use]
+    pub fn would_lax_help(&self) -> Option<String> {
+        // Only retry for the errors that the lax syntax and names can fix,
+        // rather than parsing the expression again for every error
+        if !matches!(
+            self.reason,
+            Reason::UnknownLicense
+                | Reason::UnknownException
+                | Reason::UnknownTerm
+                | Reason::InvalidCharacters
+                | Reason::GnuNoPlus
+        ) {
+            return None;
+        }
+
+        // Parsing again can't help if the mode already allowed everything
+        // that LAX does
+        let (mode, lax) = (self.mode, crate::ParseMode::LAX);
+        if (mode.allow_lower_case_operators || !lax.allow_lower_case_operators)
+            && (mode.allow_slash_as_or_operator || !lax.allow_slash_as_or_operator)
+            && (mode.allow_imprecise_license_names || !lax.allow_imprecise_license_names)
+            && (mode.allow_imprecise_name_prefix || !lax.allow_imprecise_name_prefix)
+            && (mode.allow_postfix_plus_on_gpl || !lax.allow_postfix_plus_on_gpl)
+            && (mode.allow_surrounding_quotes || !lax.allow_surrounding_quotes)
+        {
+            return None;
+        }
+
+        crate::Expression::parse_canonical(&self.original, crate::ParseMode::LAX)
+            .ok()
+            .map(|(expr, _)| expr.to_string())
+    }
+
+    /// Returns the identifiers most similar to the unknown identifier, most
+    /// similar first, searching the licenses for [`Reason::UnknownLicense`],
+    /// the exceptions for [`Reason::UnknownException`], and both for
+    /// [`Reason::UnknownTerm`]. Other errors have no suggestions.
+    ///
+    /// ```
+    /// use spdx::error::Reason;
+    ///
+    /// let err = spdx::Expression::parse("Apache-2.0 WITH LLVM-exeption").unwrap_err();
+    /// assert_eq!(err.reason, Reason::UnknownException);
+    /// assert_eq!(err.suggestions(), ["LLVM-exception"]);
+    ///
+    /// let err = spdx::Expression::parse("MTI OR Apache-2.0").unwrap_err();
+    /// assert_eq!(err.reason, Reason::UnknownLicense);
+    /// assert_eq!(err.suggestions()[0], "MIT");
+    /// ```
+    #[must_use]
+    pub fn suggestions(&self) -> Vec<&'static str> {
+        let Some(term) = self.original.get(self.span.clone()) else {
+            return Vec::new();
+        };
+
+        match self.reason {
+            Reason::UnknownLicense => crate::suggest::licenses(term),
+            Reason::UnknownException => crate::suggest::exceptions(term),
+            Reason::UnknownTerm => crate::suggest::ids(term),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Narrows a [`Reason::UnknownTerm`] from the lexer to the kind of
+    /// identifier that was expected in its position
+    pub(crate) fn unknown_as(mut self, reason: Reason) -> Self {
+        if self.reason == Reason::UnknownTerm {
+            self.reason = reason;
+        }
+        self
+    }
+
+    /// The [`span`](Self::span) of the error in UTF-16 code units, as used by
+    /// eg. the Language Server Protocol, see [`span::to_utf16`](crate::span::to_utf16)
+    ///
+    /// ```
+    /// let err = spdx::Expression::parse("😀 OR MIT").unwrap_err();
+    ///
+    /// assert_eq!(err.span, 0..4);
+    /// assert_eq!(err.span_utf16(), 0..2);
+    /// ```
+    #[must_use]
+    pub fn span_utf16(&self) -> std::ops::Range<usize> {
+        crate::span::to_utf16(&self.original, self.span.clone())
+    }
 }
 
 impl fmt::Display for ParseError {
@@ -98,6 +221,16 @@ impl fmt::Display for Reason {
             Self::SeparatedPlus => f.write_str("`+` must not follow whitespace"),
             Self::UnknownTerm => f.write_str("unknown term"),
             Self::GnuNoPlus => f.write_str("a GNU license was followed by a `+`"),
+            Self::ExpressionNotAllowed => {
+                f.write_str("only a single license is allowed here, not an expression; split it into separate licenses, or parse it as an `Expression`")
+            }
+            Self::MalformedDocumentRef => {
+                f.write_str("expected `DocumentRef-<id>:LicenseRef-<id>`")
+            }
+            Self::EmptyRefId => f.write_str("the ref id must not be empty"),
+            Self::GnuPlusWithSuffix => {
+                f.write_str("a `+` can't be used on a GNU license with the `-only` suffix")
+            }
         }
     }
 }
@@ -115,6 +248,206 @@ impl Error for ParseError {
             Reason::SeparatedPlus => "`+` must not follow whitespace",
             Reason::UnknownTerm => "unknown term",
             Reason::GnuNoPlus => "a GNU license was followed by a `+`",
+            Reason::ExpressionNotAllowed => "expression not allowed",
+            Reason::MalformedDocumentRef => "malformed document ref",
+            Reason::EmptyRefId => "empty ref id",
+            Reason::GnuPlusWithSuffix => "`+` on an `-only` GNU license",
+        }
+    }
+}
+
+/// An error returned when a string is not a known SPDX license or exception
+/// identifier, eg. when using [`FromStr`](std::str::FromStr) for
+/// [`LicenseId`](crate::LicenseId) or [`ExceptionId`](crate::ExceptionId)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownIdError {
+    /// The string that was attempting to be parsed
+    pub input: String,
+    /// Known identifiers that are similar to the input, most similar first,
+    /// which can be empty
+    pub suggestions: Vec<&'static str>,
+}
+
+impl fmt::Display for UnknownIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown SPDX identifier '{}'", self.input)?;
+
+        if let Some((last, rest)) = self.suggestions.split_last() {
+            f.write_str(", did you mean ")?;
+
+            for sugg in rest {
+                write!(f, "'{}', ", sugg)?;
+            }
+
+            if !rest.is_empty() {
+                f.write_str("or ")?;
+            }
+
+            write!(f, "'{}'?", last)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for UnknownIdError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for UnknownIdError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut s = serializer.serialize_struct("UnknownIdError", 2)?;
+        s.serialize_field("input", &self.input)?;
+        s.serialize_field("suggestions", &self.suggestions)?;
+        s.end()
+    }
+}
+
+/// An error returned when building a [`LicenseReq`](crate::LicenseReq) from
+/// its parts, eg. with [`LicenseReq::license_ref`](crate::LicenseReq::license_ref)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LicenseReqError {
+    /// A `DocumentRef-` or `LicenseRef-` id was empty
+    EmptyRefId,
+    /// A `DocumentRef-` or `LicenseRef-` id contained a character other than
+    /// an ASCII letter or digit, `-`, or `.`
+    InvalidRefCharacter(char),
+    /// An exception was added to a requirement that already has one
+    ExceptionAlreadySet(crate::ExceptionId),
+    /// `or_later` was set on a license ref, or a GNU license that doesn't have
+    /// an `-or-later` variant, eg. `GPL-2.0-with-GCC-exception`
+    OrLaterNotAllowed,
+}
+
+impl fmt::Display for LicenseReqError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyRefId => f.write_str("the ref id must not be empty"),
+            Self::InvalidRefCharacter(c) => write!(f, "invalid character {c:?} in ref id"),
+            Self::ExceptionAlreadySet(exc) => {
+                write!(
+                    f,
+                    "the requirement already has the exception '{}'",
+                    exc.name
+                )
+            }
+            Self::OrLaterNotAllowed => f.write_str("the license can't be used with `+`"),
+        }
+    }
+}
+
+impl Error for LicenseReqError {}
+
+/// An error returned by [`Expression::from_nodes`](crate::Expression::from_nodes)
+/// when the nodes are not a valid postfix expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodesError {
+    /// There were no nodes
+    Empty,
+    /// The operator at the index didn't have 2 terms before it to join
+    MissingOperand {
+        /// The index of the operator in the nodes
+        index: usize,
+    },
+    /// More than 1 term was left after the last node, so the terms weren't
+    /// all joined by operators
+    MissingOperator {
+        /// The number of terms that were left
+        terms: usize,
+    },
+    /// The license ref of the requirement at the index is not valid, see
+    /// [`LicenseItem::validate`](crate::LicenseItem::validate)
+    InvalidRef {
+        /// The index of the requirement in the nodes
+        index: usize,
+        /// Why the license ref is invalid
+        error: LicenseReqError,
+    },
+}
+
+impl fmt::Display for NodesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("an expression must have at least 1 requirement"),
+            Self::MissingOperand { index } => {
+                write!(f, "the operator at node {index} is missing an operand")
+            }
+            Self::MissingOperator { terms } => {
+                write!(f, "{terms} terms were not joined by an operator")
+            }
+            Self::InvalidRef { index, error } => {
+                write!(f, "the license ref at node {index} is invalid: {error}")
+            }
         }
     }
 }
+
+impl Error for NodesError {}
+
+/// An error returned by [`Expression::from_bytes`](crate::Expression::from_bytes)
+/// and [`FlatExpr::rebuild`](crate::expression::FlatExpr::rebuild)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The data was not encoded by [`Expression::to_bytes`](crate::Expression::to_bytes),
+    /// or was encoded by an incompatible version of this crate
+    UnknownFormat,
+    /// The data was encoded with a different version of the SPDX license list,
+    /// so the license and exception indices it contains can't be used
+    ListMismatch {
+        /// The version of the SPDX license list that was used to encode the data
+        version: String,
+    },
+    /// The data is truncated, or otherwise not a valid expression
+    Malformed,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFormat => f.write_str("the data is not an encoded expression"),
+            Self::ListMismatch { version } => write!(
+                f,
+                "the expression was encoded with version {} of the SPDX license list, but {} is in use",
+                version,
+                crate::identifiers::VERSION
+            ),
+            Self::Malformed => f.write_str("the encoded expression is malformed"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Which part of a license ref was not known, see [`RefValidationError`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UnknownRef {
+    /// The `DocumentRef-` is not one of the known external documents
+    Document,
+    /// The `LicenseRef-`, without a `DocumentRef-`, is not one of the known
+    /// licenses
+    License,
+}
+
+/// A license ref in an expression that isn't known, see
+/// [`Expression::validate_refs`](crate::Expression::validate_refs)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefValidationError {
+    /// The span of the requirement with the unknown ref
+    pub span: std::ops::Range<u32>,
+    /// The id of the unknown ref, without its prefix
+    pub id: String,
+    /// Which part of the ref was unknown
+    pub unknown: UnknownRef,
+}
+
+impl fmt::Display for RefValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unknown {
+            UnknownRef::Document => write!(f, "unknown document 'DocumentRef-{}'", self.id),
+            UnknownRef::License => write!(f, "unknown license 'LicenseRef-{}'", self.id),
+        }
+    }
+}
+
+impl Error for RefValidationError {}
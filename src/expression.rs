@@ -1,19 +1,82 @@
+mod conjuncts;
+mod diagnostics;
+mod encode;
+mod flat;
+mod lint;
 mod minimize;
 mod parser;
+mod walk;
 
-use crate::{error::ParseError, LicenseReq};
+use crate::{
+    error::{NodesError, ParseError, RefValidationError, UnknownRef},
+    identifiers::{IS_FSF_LIBRE, IS_OSI_APPROVED},
+    ExceptionId, LicenseId, LicenseItem, LicenseReq, Licensee,
+};
+pub use conjuncts::ConjunctsError;
+pub use diagnostics::Diagnostic;
+pub use flat::{FlatExpr, FlatNode};
+pub use lint::{Lint, LintKind, Severity};
 pub use minimize::MinimizeError;
+pub use parser::{CanonicalizeResult, ExpressionParser, ParseFidelity};
 use smallvec::SmallVec;
 use std::fmt;
+pub use walk::{ExprStats, WalkEvent};
 
 /// A license requirement inside an SPDX license expression, including
 /// the span in the expression where it is located
 #[derive(Debug, Clone)]
 pub struct ExpressionReq {
     pub req: LicenseReq,
+    /// The span of the requirement in the original expression, from the start
+    /// of its license to the end of its `+` or exception, if it has them
     pub span: std::ops::Range<u32>,
 }
 
+/// The outcome of an expression that was satisfied by
+/// [`Expression::evaluate_map`], with the value that was attached to each
+/// accepted requirement
+#[derive(Debug, Clone)]
+pub struct EvaluationOutcome<'e, T> {
+    /// The accepted requirements that satisfy the expression, in the order
+    /// they appear in the expression
+    pub accepted: Vec<(&'e ExpressionReq, T)>,
+    /// The requirements that were accepted, but were not needed to satisfy
+    /// the expression as an earlier alternative of an `OR` was already
+    /// satisfied
+    pub unneeded: Vec<(&'e ExpressionReq, T)>,
+}
+
+impl ExpressionReq {
+    /// See [`LicenseReq::or_later`]
+    #[inline]
+    #[must_use]
+    pub fn or_later(&self) -> bool {
+        self.req.or_later()
+    }
+
+    /// See [`LicenseReq::license_id`]
+    #[inline]
+    #[must_use]
+    pub fn license_id(&self) -> Option<LicenseId> {
+        self.req.license_id()
+    }
+
+    /// See [`LicenseReq::exception_id`]
+    #[inline]
+    #[must_use]
+    pub fn exception_id(&self) -> Option<ExceptionId> {
+        self.req.exception_id()
+    }
+
+    /// The [`span`](Self::span) of the requirement in UTF-16 code units, as
+    /// used by eg. the Language Server Protocol, given the original text of
+    /// the expression it is from, see [`span::to_utf16`](crate::span::to_utf16)
+    #[must_use]
+    pub fn span_utf16(&self, original: &str) -> std::ops::Range<usize> {
+        crate::span::to_utf16(original, self.span.start as usize..self.span.end as usize)
+    }
+}
+
 impl PartialEq for ExpressionReq {
     fn eq(&self, o: &Self) -> bool {
         self.req == o.req
@@ -33,6 +96,46 @@ pub enum ExprNode {
     Req(ExpressionReq),
 }
 
+/// An iterator over the license requirements in an [`Expression`], see
+/// [`Expression::requirements`]
+#[derive(Clone, Debug)]
+pub struct Requirements<'a> {
+    inner: std::slice::Iter<'a, ExprNode>,
+    remaining: usize,
+}
+
+impl<'a> Iterator for Requirements<'a> {
+    type Item = &'a ExpressionReq;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let req = self.inner.find_map(|node| match node {
+            ExprNode::Req(req) => Some(req),
+            ExprNode::Op(_op) => None,
+        })?;
+        self.remaining -= 1;
+        Some(req)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for Requirements<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let req = self.inner.by_ref().rev().find_map(|node| match node {
+            ExprNode::Req(req) => Some(req),
+            ExprNode::Op(_op) => None,
+        })?;
+        self.remaining -= 1;
+        Some(req)
+    }
+}
+
+impl ExactSizeIterator for Requirements<'_> {}
+impl std::iter::FusedIterator for Requirements<'_> {}
+
 /// An SPDX license expression that is both syntactically and semantically valid,
 /// and can be evaluated
 ///
@@ -65,11 +168,28 @@ pub enum ExprNode {
 /// // will fail to parse
 /// let _this_is_not = Expression::parse("MIT OR NOPE").unwrap_err();
 /// ```
+///
+/// # Equality
+///
+/// Expressions can be compared at 3 levels, from strictest to loosest
+///
+/// 1. [`Expression::identical`] - The original strings are the same, as are
+///    the requirements, operators, and the spans of the requirements, eg. for
+///    snapshot testing
+/// 1. [`Expression::semantically_eq`], or `==` - The requirements and
+///    operators are the same, regardless of the original string, eg.
+///    `MIT OR Apache-2.0` and `(MIT OR Apache-2.0)`
+/// 1. [`Expression::equivalent`] - The same, other than the order and grouping
+///    of terms joined by the same operator, and repeated terms, eg.
+///    `MIT OR Apache-2.0` and `Apache-2.0 OR MIT`
 #[derive(Clone)]
 pub struct Expression {
     pub(crate) expr: SmallVec<[ExprNode; 5]>,
-    // We keep the original string around for display purposes only
-    pub(crate) original: String,
+    // We keep the original string around for display purposes only, it is
+    // borrowed from the license list when the expression is a single license
+    // id, which is the vast majority of expressions, to avoid allocating
+    pub(crate) original: std::borrow::Cow<'static, str>,
+    pub(crate) leniencies: crate::Leniencies,
 }
 
 impl Expression {
@@ -86,11 +206,159 @@ impl Expression {
     ///     ]
     /// );
     /// ```
-    pub fn requirements(&self) -> impl Iterator<Item = &ExpressionReq> {
-        self.expr.iter().filter_map(|item| match item {
-            ExprNode::Req(req) => Some(req),
-            ExprNode::Op(_op) => None,
-        })
+    pub fn requirements(&self) -> Requirements<'_> {
+        Requirements {
+            inner: self.expr.iter(),
+            remaining: self.requirement_count(),
+        }
+    }
+
+    /// Returns the number of license requirements in the expression
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT AND (BSD-2-Clause OR ISC)").unwrap();
+    /// assert_eq!(expr.requirement_count(), 3);
+    /// assert_eq!(expr.requirements().len(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn requirement_count(&self) -> usize {
+        // Every operator is binary, so there is always exactly one more
+        // requirement than there are operators
+        (self.expr.len() + 1) / 2
+    }
+
+    /// Returns the number of `AND` and `OR` operators in the expression
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT AND (BSD-2-Clause OR ISC)").unwrap();
+    /// assert_eq!(expr.operator_count(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn operator_count(&self) -> usize {
+        self.expr.len() / 2
+    }
+
+    /// Returns the license requirements in the expression, ordered by
+    /// [`LicenseReq`]'s `Ord` with duplicates removed, so the result is the
+    /// same regardless of the order the requirements were written in. When
+    /// a requirement appears more than once, the first occurrence is kept.
+    ///
+    /// ```
+    /// let a = spdx::Expression::parse("MIT AND Apache-2.0 AND MIT").unwrap();
+    /// let b = spdx::Expression::parse("Apache-2.0 AND MIT").unwrap();
+    ///
+    /// let reqs = |e: &spdx::Expression| {
+    ///     e.requirements_sorted().into_iter().map(|er| er.req.to_string()).collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert_eq!(reqs(&a), reqs(&b));
+    /// ```
+    #[must_use]
+    pub fn requirements_sorted(&self) -> Vec<&ExpressionReq> {
+        let mut reqs: Vec<_> = self.requirements().collect();
+        // The sort is stable, so the first occurrence of duplicates is retained
+        reqs.sort_by(|a, b| a.req.cmp(&b.req));
+        reqs.dedup_by(|a, b| a.req == b.req);
+        reqs
+    }
+
+    /// Same as [`Expression::requirements_sorted`], but clones the
+    /// requirements, without their spans, into owned [`LicenseReq`]s
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT OR (Apache-2.0 AND MIT)").unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.sorted_unique_reqs().iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+    ///     ["Apache-2.0", "MIT"],
+    /// );
+    /// ```
+    #[must_use]
+    pub fn sorted_unique_reqs(&self) -> Vec<LicenseReq> {
+        self.requirements_sorted()
+            .into_iter()
+            .map(|er| er.req.clone())
+            .collect()
+    }
+
+    /// Returns true if any of the requirements in the expression is equal to
+    /// `req`, ignoring where it is located in the expression
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT OR Apache-2.0 WITH LLVM-exception").unwrap();
+    /// let req = |s| spdx::LicenseReq::parse(s, spdx::ParseMode::STRICT).unwrap();
+    ///
+    /// assert!(expr.contains_req(&req("Apache-2.0 WITH LLVM-exception")));
+    /// assert!(!expr.contains_req(&req("Apache-2.0")));
+    /// ```
+    #[must_use]
+    pub fn contains_req(&self, req: &LicenseReq) -> bool {
+        self.requirements().any(|er| er.req == *req)
+    }
+
+    /// Returns the requirement at the byte offset in the original expression,
+    /// eg. the requirement under the cursor in an editor.
+    ///
+    /// The [span](ExpressionReq::span) of a requirement covers its `+`,
+    /// `WITH` and exception, and the whitespace between them, as well as its
+    /// license. Offsets that are not inside a requirement, eg. on an operator
+    /// or parenthesis, return `None`.
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT OR Apache-2.0 WITH LLVM-exception").unwrap();
+    /// let req_at = |offset| expr.req_at_offset(offset).map(|er| er.req.to_string());
+    ///
+    /// assert_eq!(req_at(1).as_deref(), Some("MIT"));
+    /// assert_eq!(req_at(4), None);
+    /// assert_eq!(req_at(20).as_deref(), Some("Apache-2.0 WITH LLVM-exception"));
+    /// assert_eq!(req_at(30).as_deref(), Some("Apache-2.0 WITH LLVM-exception"));
+    /// ```
+    #[must_use]
+    pub fn req_at_offset(&self, byte_offset: usize) -> Option<&ExpressionReq> {
+        let offset = u32::try_from(byte_offset).ok()?;
+        self.requirements().find(|er| er.span.contains(&offset))
+    }
+
+    /// Returns true if the expressions are identical, ie. they have the same
+    /// original string, and the same requirements, operators, and spans, see
+    /// [`Expression`#equality](Expression#equality).
+    ///
+    /// ```
+    /// let a = spdx::Expression::parse("MIT OR Apache-2.0").unwrap();
+    ///
+    /// assert!(a.identical(&spdx::Expression::parse("MIT OR Apache-2.0").unwrap()));
+    /// assert!(!a.identical(&spdx::Expression::parse("MIT  OR Apache-2.0").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn identical(&self, other: &Self) -> bool {
+        self.original == other.original
+            && self.expr.len() == other.expr.len()
+            && self
+                .expr
+                .iter()
+                .zip(other.expr.iter())
+                .all(|(a, b)| match (a, b) {
+                    (ExprNode::Req(a), ExprNode::Req(b)) => a.req == b.req && a.span == b.span,
+                    (a, b) => a == b,
+                })
+    }
+
+    /// Returns true if the expressions have the same requirements and
+    /// operators, regardless of how they were written, which is the same as
+    /// `==`, see [`Expression`#equality](Expression#equality).
+    ///
+    /// ```
+    /// let a = spdx::Expression::parse("MIT OR Apache-2.0").unwrap();
+    /// let b = spdx::Expression::parse_mode("(mit / Apache-2.0)", spdx::ParseMode::LAX).unwrap();
+    ///
+    /// assert!(a.semantically_eq(&b));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self == other
     }
 
     /// Returns both the license requirements and the operators that join them
@@ -106,10 +374,84 @@ impl Expression {
     /// assert!(ei.next().is_some()); // MIT
     /// assert_eq!(*ei.next().unwrap(), ExprNode::Op(Operator::Or));
     /// ```
-    pub fn iter(&self) -> impl Iterator<Item = &ExprNode> {
+    pub fn iter(&self) -> std::slice::Iter<'_, ExprNode> {
         self.expr.iter()
     }
 
+    /// Builds an expression from nodes in postfix order, the same order as
+    /// [`Expression::iter`], eg. to construct an expression programmatically
+    /// rather than parsing it.
+    ///
+    /// The original string is the canonical form of the expression, see
+    /// [`Expression`]'s `Display` impl, and the spans of the requirements are
+    /// replaced to point into it.
+    ///
+    /// ```
+    /// use spdx::{expression::{ExprNode, ExpressionReq, Operator}, Expression, LicenseReq};
+    ///
+    /// let req = |id| ExprNode::Req(ExpressionReq {
+    ///     req: LicenseReq::from(spdx::license_id(id).unwrap()),
+    ///     span: 0..0,
+    /// });
+    ///
+    /// let expr = Expression::from_nodes(vec![
+    ///     req("MIT"),
+    ///     req("Apache-2.0"),
+    ///     ExprNode::Op(Operator::Or),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(expr.as_ref(), "MIT OR Apache-2.0");
+    /// assert_eq!(expr, Expression::parse("MIT OR Apache-2.0").unwrap());
+    ///
+    /// // An operator needs 2 terms to join
+    /// Expression::from_nodes(vec![req("MIT"), ExprNode::Op(Operator::Or)]).unwrap_err();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if there are no nodes, an operator doesn't have 2 terms to join,
+    /// there are terms left that aren't joined by an operator, or a license
+    /// ref is not a valid SPDX idstring.
+    pub fn from_nodes(nodes: Vec<ExprNode>) -> Result<Self, NodesError> {
+        let mut terms = 0usize;
+        for (index, node) in nodes.iter().enumerate() {
+            match node {
+                ExprNode::Req(er) => {
+                    er.req
+                        .license
+                        .validate()
+                        .map_err(|error| NodesError::InvalidRef { index, error })?;
+                    terms += 1;
+                }
+                ExprNode::Op(_) if terms < 2 => return Err(NodesError::MissingOperand { index }),
+                ExprNode::Op(_) => terms -= 1,
+            }
+        }
+
+        match terms {
+            0 => return Err(NodesError::Empty),
+            1 => {}
+            terms => return Err(NodesError::MissingOperator { terms }),
+        }
+
+        let mut expr = SmallVec::from_vec(nodes);
+        let (original, spans) = render(&expr).ok_or(NodesError::Empty)?;
+
+        let reqs = expr.iter_mut().filter_map(|node| match node {
+            ExprNode::Req(er) => Some(er),
+            ExprNode::Op(_) => None,
+        });
+        for (er, span) in reqs.zip(spans) {
+            er.span = span;
+        }
+
+        Ok(Self {
+            expr,
+            original: original.into(),
+            leniencies: crate::Leniencies::empty(),
+        })
+    }
+
     /// Evaluates the expression, using the provided function to determine if the
     /// licensee meets the requirements for each license term. If enough requirements are
     /// satisfied the evaluation will return true.
@@ -124,33 +466,457 @@ impl Expression {
     /// }));
     /// ```
     pub fn evaluate<AF: FnMut(&LicenseReq) -> bool>(&self, mut allow_func: AF) -> bool {
-        let mut result_stack = SmallVec::<[bool; 8]>::new();
+        self.evaluate_map(|req| allow_func(req).then_some(()))
+            .is_some()
+    }
+
+    /// The same as [`Expression::evaluate`], but taking a trait object rather
+    /// than being generic over the function, eg. for a `Box<dyn FnMut>` from
+    /// a plugin
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT OR Apache-2.0").unwrap();
+    ///
+    /// let mut allow: Box<dyn FnMut(&spdx::LicenseReq) -> bool> =
+    ///     Box::new(|req| req.license.id() == spdx::license_id("MIT"));
+    /// assert!(expr.evaluate_dyn(&mut *allow));
+    /// ```
+    pub fn evaluate_dyn(&self, allow_func: &mut dyn FnMut(&LicenseReq) -> bool) -> bool {
+        self.evaluate_map(|req| allow_func(req).then_some(()))
+            .is_some()
+    }
+
+    /// Evaluates the expression, using the provided function to both determine
+    /// if each license requirement is accepted, by returning `Some`, and to
+    /// attach a value to it, eg. the reason it was accepted, so that it can be
+    /// reported along with the requirement.
+    ///
+    /// If the expression is satisfied, the outcome contains the value for each
+    /// requirement that was used to satisfy it, as well as those that were
+    /// accepted but not needed because an earlier alternative of an `OR` was
+    /// already satisfied.
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT OR Apache-2.0").unwrap();
+    ///
+    /// let outcome = expr
+    ///     .evaluate_map(|req| match req.license.id()?.name {
+    ///         "MIT" => Some("permissive"),
+    ///         "Apache-2.0" => Some("patent grant"),
+    ///         _ => None,
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(outcome.accepted[0].0.req.to_string(), "MIT");
+    /// assert_eq!(outcome.accepted[0].1, "permissive");
+    /// assert_eq!(outcome.unneeded[0].1, "patent grant");
+    /// ```
+    pub fn evaluate_map<T>(
+        &self,
+        mut map_func: impl FnMut(&LicenseReq) -> Option<T>,
+    ) -> Option<EvaluationOutcome<'_, T>> {
+        let mut reqs = SmallVec::<[(&ExpressionReq, Option<T>); 8]>::new();
+        // Whether each requirement is part of the alternatives that are used
+        // to satisfy the expression
+        let mut used = SmallVec::<[bool; 8]>::new();
+        // Whether each subexpression is satisfied, and the index of its first
+        // requirement, the requirements of a subexpression being contiguous
+        let mut result_stack = SmallVec::<[(bool, usize); 8]>::new();
 
         // We store the expression as postfix, so just evaluate each license
         // requirement in the order it comes, and then combining the previous
-        // results according to each operator as it comes
+        // results according to each operator as it comes. The nodes are always
+        // a valid postfix expression, as every way of building an `Expression`,
+        // including `Expression::from_nodes`, checks that each operator has 2
+        // operands, so popping can't fail
         for node in self.expr.iter() {
             match node {
                 ExprNode::Req(req) => {
-                    let allowed = allow_func(&req.req);
-                    result_stack.push(allowed);
+                    let value = map_func(&req.req);
+                    result_stack.push((value.is_some(), reqs.len()));
+                    used.push(true);
+                    reqs.push((req, value));
                 }
-                ExprNode::Op(Operator::Or) => {
-                    let a = result_stack.pop().unwrap();
-                    let b = result_stack.pop().unwrap();
+                ExprNode::Op(op) => {
+                    let (b, b_start) = result_stack.pop().unwrap();
+                    let (a, a_start) = result_stack.pop().unwrap();
 
-                    result_stack.push(a || b);
+                    let satisfied = match op {
+                        Operator::And => a && b,
+                        Operator::Or => {
+                            // Only one of the alternatives is used, preferring
+                            // the first if both are satisfied
+                            let unused = if a {
+                                b_start..reqs.len()
+                            } else {
+                                a_start..b_start
+                            };
+
+                            for u in &mut used[unused] {
+                                *u = false;
+                            }
+
+                            a || b
+                        }
+                    };
+
+                    result_stack.push((satisfied, a_start));
                 }
-                ExprNode::Op(Operator::And) => {
-                    let a = result_stack.pop().unwrap();
-                    let b = result_stack.pop().unwrap();
+            }
+        }
 
-                    result_stack.push(a && b);
+        if !result_stack.pop().unwrap().0 {
+            return None;
+        }
+
+        let mut outcome = EvaluationOutcome {
+            accepted: Vec::new(),
+            unneeded: Vec::new(),
+        };
+
+        for ((req, value), used) in reqs.into_iter().zip(used) {
+            if let Some(value) = value {
+                if used {
+                    outcome.accepted.push((req, value));
+                } else {
+                    outcome.unneeded.push((req, value));
                 }
             }
         }
 
-        result_stack.pop().unwrap()
+        Some(outcome)
+    }
+
+    /// Evaluates the expression against a list of accepted licensees, each
+    /// requirement being accepted if [any](Licensee::satisfies) of the
+    /// licensees satisfies it. On failure, the requirements that were not
+    /// satisfied are returned, just as with [`Expression::evaluate_with_failures`]
+    ///
+    /// ```
+    /// use spdx::{Expression, Licensee};
+    ///
+    /// let accepted: Vec<_> = ["MIT", "Apache-2.0"]
+    ///     .iter()
+    ///     .map(|l| Licensee::parse(l).unwrap())
+    ///     .collect();
+    ///
+    /// let expr = Expression::parse("MIT OR ISC").unwrap();
+    /// expr.satisfied_by(&accepted).unwrap();
+    ///
+    /// let expr = Expression::parse("MIT AND ISC").unwrap();
+    /// let failures = expr.satisfied_by(&accepted).unwrap_err();
+    /// assert_eq!(failures.len(), 1);
+    /// assert_eq!(failures[0].req.to_string(), "ISC");
+    /// ```
+    pub fn satisfied_by<'lic>(
+        &self,
+        licensees: impl IntoIterator<Item = &'lic Licensee> + Clone,
+    ) -> Result<(), Vec<&ExpressionReq>> {
+        self.evaluate_with_failures(|req| {
+            licensees
+                .clone()
+                .into_iter()
+                .any(|licensee| licensee.satisfies(req))
+        })
+    }
+
+    /// Returns true if the expression is satisfied by the list of accepted
+    /// licensees, see [`Expression::satisfied_by`]
+    ///
+    /// ```
+    /// let accepted = [spdx::Licensee::parse("MIT").unwrap()];
+    ///
+    /// assert!(spdx::Expression::parse("MIT OR Apache-2.0").unwrap().is_satisfied_by(&accepted));
+    /// assert!(!spdx::Expression::parse("MIT AND Apache-2.0").unwrap().is_satisfied_by(&accepted));
+    /// ```
+    #[must_use]
+    pub fn is_satisfied_by<'lic>(
+        &self,
+        licensees: impl IntoIterator<Item = &'lic Licensee> + Clone,
+    ) -> bool {
+        self.evaluate(|req| {
+            licensees
+                .clone()
+                .into_iter()
+                .any(|licensee| licensee.satisfies(req))
+        })
+    }
+
+    /// Returns true if this expression offers a choice of licenses that are
+    /// all acceptable, where what is acceptable is itself an expression, eg.
+    /// whether a dependency licensed `Apache-2.0 OR GPL-3.0-only` can be used
+    /// under one of the licenses of a project licensed `MIT AND Apache-2.0`.
+    /// See [`Expression::satisfying_offer`] for the details.
+    ///
+    /// ```
+    /// let project = spdx::Expression::parse("MIT AND Apache-2.0").unwrap();
+    ///
+    /// assert!(spdx::Expression::parse("Apache-2.0 OR GPL-3.0-only").unwrap().offers_satisfying(&project));
+    /// assert!(!spdx::Expression::parse("GPL-3.0-only").unwrap().offers_satisfying(&project));
+    /// ```
+    #[must_use]
+    pub fn offers_satisfying(&self, acceptable: &Expression) -> bool {
+        self.satisfying_offer(acceptable).is_some()
+    }
+
+    /// Evaluates this expression, treating each requirement of `acceptable`
+    /// as a [`Licensee`], and returns the chosen requirements along with the
+    /// requirement of `acceptable` that satisfied each of them, just as with
+    /// [`Expression::evaluate_map`].
+    ///
+    /// This only matches the licenses that are available, the operators of
+    /// `acceptable` are ignored, as every one of its requirements is treated
+    /// as acceptable, and it says nothing about whether the licenses are
+    /// legally compatible with each other.
+    ///
+    /// Licensees can't have a `+`, so requirements of `acceptable` that allow
+    /// later versions only accept the version that is named, eg.
+    /// `GPL-2.0-or-later` accepts `GPL-2.0-only` and `GPL-2.0-or-later`, but
+    /// not `GPL-3.0-only`, which never accepts a license that wasn't acceptable.
+    ///
+    /// ```
+    /// let project = spdx::Expression::parse("ISC OR BSD-3-Clause").unwrap();
+    /// let dep = spdx::Expression::parse("(MIT OR ISC) AND (BSD-3-Clause OR Apache-2.0)").unwrap();
+    ///
+    /// let offer = dep.satisfying_offer(&project).unwrap();
+    /// let chosen: Vec<_> = offer.accepted.iter().map(|(er, _)| er.req.to_string()).collect();
+    /// assert_eq!(chosen, ["ISC", "BSD-3-Clause"]);
+    /// ```
+    #[must_use]
+    pub fn satisfying_offer<'a>(
+        &self,
+        acceptable: &'a Expression,
+    ) -> Option<EvaluationOutcome<'_, &'a ExpressionReq>> {
+        let licensees: Vec<_> = acceptable
+            .requirements()
+            .map(|er| {
+                let license = match &er.req.license {
+                    LicenseItem::Spdx { id, .. } => LicenseItem::Spdx {
+                        id: *id,
+                        or_later: false,
+                    },
+                    other @ LicenseItem::Other { .. } => other.clone(),
+                };
+
+                (Licensee::new(license, er.req.exception), er)
+            })
+            .collect();
+
+        self.evaluate_map(|req| {
+            licensees
+                .iter()
+                .find(|(licensee, _)| licensee.satisfies(req))
+                .map(|(_, er)| *er)
+        })
+    }
+
+    /// Evaluates the expression, accepting each SPDX license requirement iff
+    /// the license has all of the `required` flags, and none of the `forbidden`
+    /// flags, see the `IS_*` constants in [`identifiers`](crate::identifiers).
+    ///
+    /// Requirements that are not SPDX licenses, ie. `LicenseRef-`s, are
+    /// accepted iff `accept_other` is true.
+    ///
+    /// ```
+    /// use spdx::identifiers::{IS_COPYLEFT, IS_OSI_APPROVED};
+    ///
+    /// let expr = spdx::Expression::parse("GPL-3.0-only OR MIT").unwrap();
+    ///
+    /// assert!(expr.evaluate_flags(IS_OSI_APPROVED, IS_COPYLEFT, false));
+    /// assert!(!expr.evaluate_flags(IS_COPYLEFT, IS_OSI_APPROVED, false));
+    /// ```
+    #[must_use]
+    pub fn evaluate_flags(&self, required: u16, forbidden: u16, accept_other: bool) -> bool {
+        self.evaluate(|req| match req.license {
+            LicenseItem::Spdx { id, .. } => {
+                id.flags & required == required && id.flags & forbidden == 0
+            }
+            LicenseItem::Other { .. } => accept_other,
+        })
+    }
+
+    /// Returns true if the expression can be satisfied using only
+    /// [OSI approved](crate::LicenseId::is_osi_approved) licenses
+    ///
+    /// ```
+    /// assert!(spdx::Expression::parse("Borceux OR MIT").unwrap().is_osi_compatible());
+    /// assert!(!spdx::Expression::parse("Borceux AND MIT").unwrap().is_osi_compatible());
+    /// ```
+    #[must_use]
+    pub fn is_osi_compatible(&self) -> bool {
+        self.evaluate_flags(IS_OSI_APPROVED, 0, false)
+    }
+
+    /// Returns true if the expression can be satisfied using only
+    /// [FSF free/libre](crate::LicenseId::is_fsf_free_libre) licenses
+    ///
+    /// ```
+    /// assert!(spdx::Expression::parse("Borceux OR BitTorrent-1.1").unwrap().is_fsf_free());
+    /// assert!(!spdx::Expression::parse("Borceux AND BitTorrent-1.1").unwrap().is_fsf_free());
+    /// ```
+    #[must_use]
+    pub fn is_fsf_free(&self) -> bool {
+        self.evaluate_flags(IS_FSF_LIBRE, 0, false)
+    }
+
+    /// Returns true if any of the licenses in the expression are
+    /// [copyleft](crate::LicenseId::is_copyleft), regardless of whether they
+    /// are actually required to satisfy the expression
+    ///
+    /// ```
+    /// assert!(spdx::Expression::parse("MIT OR LGPL-2.1-only").unwrap().contains_copyleft());
+    /// assert!(!spdx::Expression::parse("MIT OR Apache-2.0").unwrap().contains_copyleft());
+    /// ```
+    #[must_use]
+    pub fn contains_copyleft(&self) -> bool {
+        self.requirements()
+            .any(|er| er.req.license.id().map_or(false, LicenseId::is_copyleft))
+    }
+
+    /// Returns true if any of the requirements in the expression is a
+    /// `LicenseRef-`, with or without a `DocumentRef-`, ie. a license that is
+    /// not in the SPDX license list
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT OR LicenseRef-Embark-Proprietary").unwrap();
+    ///
+    /// if expr.has_refs() {
+    ///     // Licenses outside of the SPDX list can't be checked automatically
+    ///     let refs: Vec<_> = expr.refs().map(|er| er.req.to_string()).collect();
+    ///     assert_eq!(refs, ["LicenseRef-Embark-Proprietary"]);
+    /// } else {
+    ///     unreachable!("the expression contains a ref");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn has_refs(&self) -> bool {
+        self.refs().next().is_some()
+    }
+
+    /// Returns each of the requirements in the expression that is a
+    /// `LicenseRef-`, with or without a `DocumentRef-`
+    pub fn refs(&self) -> impl Iterator<Item = &ExpressionReq> {
+        self.requirements()
+            .filter(|er| matches!(er.req.license, LicenseItem::Other { .. }))
+    }
+
+    /// Returns the unique ids of the `DocumentRef-`s in the expression,
+    /// without their prefix, in the order they first appear
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse(
+    ///     "DocumentRef-a:LicenseRef-1 OR (DocumentRef-b:LicenseRef-2 AND DocumentRef-a:LicenseRef-3)",
+    /// ).unwrap();
+    ///
+    /// assert_eq!(expr.document_refs().collect::<Vec<_>>(), ["a", "b"]);
+    /// ```
+    pub fn document_refs(&self) -> impl Iterator<Item = &str> {
+        let mut seen = Vec::new();
+        self.refs()
+            .filter_map(|er| match &er.req.license {
+                LicenseItem::Other {
+                    doc_ref: Some(doc_ref),
+                    ..
+                } => Some(doc_ref.as_str()),
+                _ => None,
+            })
+            .filter(move |doc_ref| {
+                if seen.contains(doc_ref) {
+                    false
+                } else {
+                    seen.push(*doc_ref);
+                    true
+                }
+            })
+    }
+
+    /// Checks that every ref in the expression refers to a known external
+    /// document, or license, eg. the ones declared in the SPDX document the
+    /// expression is from. The ids are without their `DocumentRef-` or
+    /// `LicenseRef-` prefix.
+    ///
+    /// A `LicenseRef-` in an external document is only checked against the
+    /// `known_docs`, as the licenses it declares aren't known. All of the
+    /// unknown refs are returned, in the order they appear.
+    ///
+    /// ```
+    /// use spdx::error::UnknownRef;
+    ///
+    /// let expr = spdx::Expression::parse("LicenseRef-Mine OR DocumentRef-Theirs:LicenseRef-Other").unwrap();
+    ///
+    /// expr.validate_refs(&["Theirs"], &["Mine"]).unwrap();
+    ///
+    /// let errors = expr.validate_refs(&[], &["Mine"]).unwrap_err();
+    /// assert_eq!(errors[0].unknown, UnknownRef::Document);
+    /// assert_eq!(errors[0].span, 19..54);
+    /// ```
+    pub fn validate_refs(
+        &self,
+        known_docs: &[&str],
+        known_lics: &[&str],
+    ) -> Result<(), Vec<RefValidationError>> {
+        let errors: Vec<_> = self
+            .refs()
+            .filter_map(|er| {
+                let LicenseItem::Other { doc_ref, lic_ref } = &er.req.license else {
+                    return None;
+                };
+
+                let (id, known, unknown) = match doc_ref {
+                    Some(doc_ref) => (doc_ref, known_docs, UnknownRef::Document),
+                    None => (lic_ref, known_lics, UnknownRef::License),
+                };
+
+                (!known.contains(&id.as_str())).then(|| RefValidationError {
+                    span: er.span.clone(),
+                    id: id.clone(),
+                    unknown,
+                })
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns true if every license in the expression is in the SPDX license
+    /// list, ie. the expression doesn't contain any refs. Exceptions are
+    /// always in the SPDX exception list.
+    ///
+    /// ```
+    /// assert!(spdx::Expression::parse("MIT OR Apache-2.0 WITH LLVM-exception").unwrap().is_pure_spdx());
+    /// assert!(!spdx::Expression::parse("MIT AND DocumentRef-d:LicenseRef-l").unwrap().is_pure_spdx());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_pure_spdx(&self) -> bool {
+        !self.has_refs()
+    }
+
+    /// The non-strict syntax and names that were needed to parse the
+    /// expression with its [`ParseMode`](crate::ParseMode), eg. so that the
+    /// maintainers of a crate can be told exactly how to fix its license field.
+    ///
+    /// For expressions created with [`Expression::parse_canonical`] these are
+    /// the leniencies of the original, rather than the canonical, expression.
+    ///
+    /// ```
+    /// use spdx::{Expression, Leniencies, ParseMode};
+    ///
+    /// let expr = Expression::parse_mode("MIT/Apache-2.0 or GPL-2.0", ParseMode::LAX).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.leniencies(),
+    ///     Leniencies::SLASH_OPERATOR | Leniencies::LOWERCASE_OPERATOR | Leniencies::DEPRECATED_ID
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn leniencies(&self) -> crate::Leniencies {
+        self.leniencies
     }
 
     /// Just as with evaluate, the license expression is evaluated to see if
@@ -161,6 +927,24 @@ impl Expression {
     pub fn evaluate_with_failures<AF: FnMut(&LicenseReq) -> bool>(
         &self,
         mut allow_func: AF,
+    ) -> Result<(), Vec<&ExpressionReq>> {
+        self.evaluate_with_failures_dyn(&mut allow_func)
+    }
+
+    /// The same as [`Expression::evaluate_with_failures`], but taking a trait
+    /// object rather than being generic over the function
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT AND Apache-2.0").unwrap();
+    ///
+    /// let mut allow: Box<dyn FnMut(&spdx::LicenseReq) -> bool> =
+    ///     Box::new(|req| req.license.id() == spdx::license_id("MIT"));
+    /// let failures = expr.evaluate_with_failures_dyn(&mut *allow).unwrap_err();
+    /// assert_eq!(failures[0].req.to_string(), "Apache-2.0");
+    /// ```
+    pub fn evaluate_with_failures_dyn(
+        &self,
+        allow_func: &mut dyn FnMut(&LicenseReq) -> bool,
     ) -> Result<(), Vec<&ExpressionReq>> {
         let mut result_stack = SmallVec::<[bool; 8]>::new();
         let mut failures = Vec::new();
@@ -207,6 +991,28 @@ impl AsRef<str> for Expression {
     }
 }
 
+/// Iterates over the nodes of the expression in postfix order, see [`Expression::iter`]
+///
+/// ```
+/// let expr = spdx::Expression::parse("Apache-2.0 OR MIT").unwrap();
+///
+/// let mut reqs = 0;
+/// for node in &expr {
+///     if let spdx::expression::ExprNode::Req(_) = node {
+///         reqs += 1;
+///     }
+/// }
+/// assert_eq!(reqs, 2);
+/// ```
+impl<'a> IntoIterator for &'a Expression {
+    type Item = &'a ExprNode;
+    type IntoIter = std::slice::Iter<'a, ExprNode>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.expr.iter()
+    }
+}
+
 impl fmt::Debug for Expression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (i, node) in self.expr.iter().enumerate() {
@@ -225,9 +1031,97 @@ impl fmt::Debug for Expression {
     }
 }
 
+/// Displays the original expression, or with the alternate flag, `{:#}`, the
+/// canonical form of the parsed expression, ie. with uppercase operators, the
+/// full license identifiers, `-only` or `-or-later` suffixes for GNU licenses,
+/// see [`LicenseReq`](crate::LicenseReq), and only
+/// the parentheses needed to parse to the same expression
+///
+/// ```
+/// let expr = spdx::Expression::parse_mode(
+///     "(mit/apache2) and ((gpl-2.0+))",
+///     spdx::ParseMode::LAX,
+/// ).unwrap();
+///
+/// assert_eq!(expr.to_string(), "(mit/apache2) and ((gpl-2.0+))");
+/// assert_eq!(format!("{expr:#}"), "(MIT OR Apache-2.0) AND GPL-2.0-or-later");
+/// ```
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.original)
+        if !f.alternate() {
+            return f.write_str(&self.original);
+        }
+
+        let (rendered, _spans) = render(&self.expr).ok_or(fmt::Error)?;
+        f.write_str(&rendered)
+    }
+}
+
+/// Renders the canonical form of the nodes, along with the span of each
+/// requirement in the rendered string, or `None` if the nodes are malformed
+fn render(nodes: &[ExprNode]) -> Option<(String, Vec<std::ops::Range<u32>>)> {
+    // Each operand is rendered along with the operator that joined it, if
+    // any, to know if it needs to be parenthesized when it is joined again
+    type Operand = (String, Option<Operator>, Vec<std::ops::Range<u32>>);
+    let mut stack: Vec<Operand> = Vec::new();
+
+    let parenthesize = |(s, op, mut spans): Operand| {
+        for span in &mut spans {
+            *span = span.start + 1..span.end + 1;
+        }
+        (format!("({s})"), op, spans)
+    };
+
+    for node in nodes {
+        match node {
+            ExprNode::Req(er) => {
+                // The span covers the whole requirement, the same as the parser
+                let rendered = format!("{:#}", er.req);
+                let span = 0..rendered.len() as u32;
+                stack.push((rendered, None, vec![span]));
+            }
+            ExprNode::Op(op) => {
+                let b = stack.pop()?;
+                let a = stack.pop()?;
+
+                // AND binds tighter than OR, and the parser treats both as
+                // right associative, so an OR inside an AND always needs
+                // parentheses, as does the same operator on the left hand side
+                let a = match (a.1, op) {
+                    (Some(Operator::Or), Operator::And) => parenthesize(a),
+                    (Some(aop), op) if aop == *op => parenthesize(a),
+                    _ => a,
+                };
+                let b = match (b.1, op) {
+                    (Some(Operator::Or), Operator::And) => parenthesize(b),
+                    _ => b,
+                };
+
+                let op_str = match op {
+                    Operator::And => "AND",
+                    Operator::Or => "OR",
+                };
+
+                let (mut rendered, _, mut spans) = a;
+                rendered.push(' ');
+                rendered.push_str(op_str);
+                rendered.push(' ');
+
+                let offset = rendered.len() as u32;
+                rendered.push_str(&b.0);
+                spans.extend(
+                    b.2.into_iter()
+                        .map(|span| span.start + offset..span.end + offset),
+                );
+
+                stack.push((rendered, Some(*op), spans));
+            }
+        }
+    }
+
+    match stack.pop() {
+        Some((rendered, _, spans)) if stack.is_empty() => Some((rendered, spans)),
+        _ => None,
     }
 }
 
@@ -238,6 +1132,13 @@ impl std::str::FromStr for Expression {
     }
 }
 
+impl TryFrom<&str> for Expression {
+    type Error = ParseError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
 impl PartialEq for Expression {
     fn eq(&self, o: &Self) -> bool {
         // The expressions can be semantically the same but not
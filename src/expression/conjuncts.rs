@@ -0,0 +1,125 @@
+use super::{walk::Tree, Expression, Operator};
+use crate::LicenseReq;
+use std::fmt;
+
+/// Errors that can occur when getting the [`Expression::conjuncts`]
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConjunctsError {
+    /// The expression has more conjuncts than the specified limit
+    TooManyConjuncts(usize),
+}
+
+impl fmt::Display for ConjunctsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyConjuncts(limit) => write!(
+                f,
+                "the license expression has more conjuncts than the limit of {}",
+                limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConjunctsError {
+    fn description(&self) -> &str {
+        match self {
+            Self::TooManyConjuncts(_) => "too many conjuncts in license expression",
+        }
+    }
+}
+
+type Conjuncts = Vec<Vec<LicenseReq>>;
+
+impl Expression {
+    /// Gets the groups of alternative requirements that all need to be
+    /// satisfied, ie. one requirement from each group needs to be picked to
+    /// satisfy the expression, eg. `(MIT OR Apache-2.0) AND (Zlib OR ISC)` is
+    /// `[[MIT, Apache-2.0], [Zlib, ISC]]`.
+    ///
+    /// Expressions where an `OR` contains an `AND` are distributed to get
+    /// this form, eg. `MIT OR (Apache-2.0 AND ISC)` is
+    /// `[[MIT, Apache-2.0], [MIT, ISC]]`, which can result in exponentially
+    /// more groups than there are requirements in the expression, so an error
+    /// is returned if there would be more groups than `limit`.
+    ///
+    /// The groups, and the requirements in each group, are in the order they
+    /// appear in the expression, with any duplicates removed.
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT AND (Apache-2.0 OR ISC)").unwrap();
+    /// let conjuncts: Vec<Vec<String>> = expr
+    ///     .conjuncts(16)
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|group| group.into_iter().map(|req| req.to_string()).collect())
+    ///     .collect();
+    ///
+    /// assert_eq!(conjuncts, [vec!["MIT"], vec!["Apache-2.0", "ISC"]]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if the expression has more conjuncts than `limit`
+    pub fn conjuncts(&self, limit: usize) -> Result<Vec<Vec<LicenseReq>>, ConjunctsError> {
+        match self.tree() {
+            Some(tree) => conjuncts(&tree, limit),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+fn conjuncts(tree: &Tree<'_>, limit: usize) -> Result<Conjuncts, ConjunctsError> {
+    let result = match tree {
+        Tree::Req(er) => vec![vec![er.req.clone()]],
+        Tree::Op(Operator::And, children) => {
+            let mut result = Vec::new();
+            for child in children {
+                for group in conjuncts(child, limit)? {
+                    if !result.contains(&group) {
+                        result.push(group);
+                    }
+                }
+            }
+
+            result
+        }
+        Tree::Op(Operator::Or, children) => {
+            // (A AND B) OR (C AND D) == (A OR C) AND (A OR D) AND (B OR C) AND (B OR D)
+            let mut result = vec![Vec::new()];
+            for child in children {
+                let child = conjuncts(child, limit)?;
+                let mut distributed = Vec::with_capacity(result.len() * child.len());
+
+                for left in &result {
+                    for right in &child {
+                        let mut group: Vec<LicenseReq> = left.clone();
+                        for req in right {
+                            if !group.contains(req) {
+                                group.push(req.clone());
+                            }
+                        }
+
+                        if !distributed.contains(&group) {
+                            distributed.push(group);
+                        }
+                    }
+                }
+
+                if distributed.len() > limit {
+                    return Err(ConjunctsError::TooManyConjuncts(limit));
+                }
+
+                result = distributed;
+            }
+
+            result
+        }
+    };
+
+    if result.len() > limit {
+        return Err(ConjunctsError::TooManyConjuncts(limit));
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,222 @@
+use super::{parser::LicenseFixup, Expression, Severity};
+use crate::{
+    error::ParseError,
+    lexer::{surrounding_quotes, Leniencies, Lexer, Token},
+    ParseMode,
+};
+use std::ops::Range;
+
+/// Non-strict syntax, or a deprecated identifier, that was accepted when
+/// parsing an expression, see [`Expression::parse_mode_with_diagnostics`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The span of the accepted text in the original expression
+    pub span: Range<usize>,
+    /// How strongly the accepted text should be fixed
+    pub severity: Severity,
+    /// The single leniency that was needed to accept the text, see
+    /// [`Diagnostic::code`]
+    pub leniency: Leniencies,
+    /// The text the span should be replaced with to fix it, if there is a fix
+    pub replacement: Option<String>,
+}
+
+impl Diagnostic {
+    fn new(span: Range<usize>, leniency: Leniencies, replacement: Option<String>) -> Self {
+        let severity = if leniency == Leniencies::LOWERCASE_OPERATOR
+            || leniency == Leniencies::SURROUNDING_QUOTES
+        {
+            Severity::Info
+        } else {
+            Severity::Warning
+        };
+
+        Self {
+            span,
+            severity,
+            leniency,
+            replacement,
+        }
+    }
+
+    /// A stable code for the kind of diagnostic, which is the name of its
+    /// [`Leniencies`] constant, eg. `SLASH_OPERATOR`
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        self.leniency.iter().next().map_or("", |(name, _)| name)
+    }
+}
+
+impl Expression {
+    /// Parses the expression, exactly as with [`Expression::parse_mode`], but
+    /// also adds a [`Diagnostic`] to `diagnostics` for each piece of
+    /// non-strict syntax, or deprecated identifier, that the `mode` accepted,
+    /// eg. so that a linter can report them as warnings and offer fixes.
+    ///
+    /// The diagnostics are in the order they appear in the expression, and
+    /// together they cover exactly the [`Expression::leniencies`]. All of the
+    /// diagnostics for the same license term have the same span, which
+    /// includes a `+` that was fixed, and the same replacement, so applying the
+    /// replacement for each distinct span gives the same expression as
+    /// [`Expression::canonicalize`], apart from whitespace, which is only
+    /// fixed next to a removed byte order mark or surrounding quotes.
+    ///
+    /// ```
+    /// use spdx::{Expression, ParseMode};
+    ///
+    /// let mut diagnostics = Vec::new();
+    /// let expr =
+    ///     Expression::parse_mode_with_diagnostics("mit or Apache-2.0", ParseMode::LAX, &mut diagnostics)
+    ///         .unwrap();
+    ///
+    /// assert_eq!(expr, Expression::parse("MIT OR Apache-2.0").unwrap());
+    ///
+    /// let fixes: Vec<_> = diagnostics
+    ///     .iter()
+    ///     .map(|d| (d.code(), d.span.clone(), d.replacement.as_deref()))
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     fixes,
+    ///     [
+    ///         ("IMPRECISE_NAME", 0..3, Some("MIT")),
+    ///         ("LOWERCASE_OPERATOR", 4..6, Some("OR")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn parse_mode_with_diagnostics(
+        original: &str,
+        mode: ParseMode,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Result<Self, ParseError> {
+        let expr = Self::parse_mode(original, mode)?;
+
+        if !expr.leniencies.is_empty() {
+            let start = diagnostics.len();
+            diagnose(original, mode, diagnostics);
+            diagnostics[start..].sort_by_key(|d| d.span.start);
+        }
+
+        Ok(expr)
+    }
+}
+
+/// Lexes an expression that is known to be valid in the mode, adding the
+/// diagnostics for each leniency that was needed
+fn diagnose(original: &str, mode: ParseMode, diagnostics: &mut Vec<Diagnostic>) {
+    if mode.allow_surrounding_quotes {
+        diagnose_quotes(original, diagnostics);
+    }
+
+    let mut lexer = Lexer::new_mode(original, mode)
+        .map_while(Result::ok)
+        .peekable();
+
+    while let Some(lt) = lexer.next() {
+        let text = lt.text;
+
+        match lt.token {
+            Token::And | Token::Or | Token::With => {
+                let op = match lt.token {
+                    Token::And => "AND",
+                    Token::Or => "OR",
+                    _ => "WITH",
+                };
+
+                if text == "/" {
+                    // `/` doesn't need to be separated from its terms, but
+                    // `OR` does
+                    let is_separated = |c: Option<char>| c.map_or(true, char::is_whitespace);
+                    let before = if is_separated(original[..lt.span.start].chars().next_back()) {
+                        ""
+                    } else {
+                        " "
+                    };
+                    let after = if is_separated(original[lt.span.end..].chars().next()) {
+                        ""
+                    } else {
+                        " "
+                    };
+
+                    diagnostics.push(Diagnostic::new(
+                        lt.span,
+                        Leniencies::SLASH_OPERATOR,
+                        Some(format!("{before}{op}{after}")),
+                    ));
+                } else if text != op {
+                    diagnostics.push(Diagnostic::new(
+                        lt.span,
+                        Leniencies::LOWERCASE_OPERATOR,
+                        Some(op.to_owned()),
+                    ));
+                }
+            }
+            Token::Spdx(id) => {
+                let next = lexer.peek().map(|next| &next.token);
+
+                let mut span = lt.span.clone();
+                let mut leniencies = Vec::new();
+
+                // An imprecise name is only reported as such, even if it is
+                // for a deprecated id, as it wasn't the id that was written
+                if lt.imprecise {
+                    leniencies.push(Leniencies::IMPRECISE_NAME);
+                } else if id.is_deprecated() {
+                    leniencies.push(Leniencies::DEPRECATED_ID);
+                }
+
+                let fix = LicenseFixup::new(id, next);
+                let mut canonical = String::new();
+                fix.write(&mut canonical);
+
+                // The parser accepts a `+` on any GNU license in the mode, even
+                // if there is no `-or-later` variant to rewrite it to
+                if id.is_gnu() && next == Some(&Token::Plus) {
+                    leniencies.push(Leniencies::GPL_PLUS);
+                    span.end = lexer.next().map_or(span.end, |plus| plus.span.end);
+
+                    if !fix.plus {
+                        canonical.push('+');
+                    }
+                }
+
+                let replacement = (canonical != original[span.clone()]).then_some(canonical);
+                for leniency in leniencies {
+                    diagnostics.push(Diagnostic::new(span.clone(), leniency, replacement.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Adds the diagnostics for a leading byte order mark, and quotes around the
+/// entire expression, the same as the lexer ignores them, including the
+/// whitespace between them and the expression
+fn diagnose_quotes(original: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let (bom_len, unquoted) = surrounding_quotes(original);
+
+    let mut push = |span: Range<usize>| {
+        diagnostics.push(Diagnostic::new(
+            span,
+            Leniencies::SURROUNDING_QUOTES,
+            Some(String::new()),
+        ));
+    };
+
+    let leading_ws = |text: &str| text.len() - text.trim_start().len();
+
+    match unquoted {
+        Some(unquoted) => {
+            if bom_len > 0 {
+                push(0..bom_len);
+            }
+
+            let inner = &original[unquoted.clone()];
+            push(bom_len..unquoted.start + leading_ws(inner));
+            push(unquoted.start + inner.trim_end().len()..original.len());
+        }
+        None if bom_len > 0 => push(0..bom_len + leading_ws(&original[bom_len..])),
+        None => {}
+    }
+}
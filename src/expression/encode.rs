@@ -0,0 +1,312 @@
+use super::{ExprNode, Expression, FlatExpr, FlatNode, Operator};
+use crate::{error::DecodeError, identifiers, LicenseItem};
+
+/// Identifies the encoding, and its version, so that data that was encoded by
+/// something else, or a different version of the encoding, is rejected
+const MAGIC: &[u8; 5] = b"spdx\x01";
+
+const AND: u8 = 0;
+const OR: u8 = 1;
+const SPDX: u8 = 2;
+const REF: u8 = 3;
+
+const OR_LATER: u8 = 0x1;
+const HAS_EXCEPTION: u8 = 0x2;
+const HAS_DOC_REF: u8 = 0x4;
+
+impl Expression {
+    /// Encodes the parsed expression in a compact binary form, so that it can
+    /// be cached, eg. between processes, and decoded with
+    /// [`Expression::from_bytes`] without needing to parse it again.
+    ///
+    /// The licenses and exceptions are encoded by their
+    /// [index](crate::LicenseId::index), which is only stable for a single
+    /// version of the SPDX license list, so the version of the license list,
+    /// as well as its [hash](crate::identifiers::CONTENT_HASH), are also
+    /// encoded, and decoding fails if they don't match.
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("MIT OR Apache-2.0 WITH LLVM-exception").unwrap();
+    /// let decoded = spdx::Expression::from_bytes(&expr.to_bytes()).unwrap();
+    ///
+    /// assert_eq!(expr, decoded);
+    /// assert_eq!(expr.as_ref(), decoded.as_ref());
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32 + self.original.len() + self.expr.len() * 8);
+
+        out.extend_from_slice(MAGIC);
+        write_str(&mut out, identifiers::VERSION);
+        out.extend_from_slice(&identifiers::CONTENT_HASH.to_le_bytes());
+        out.push(self.leniencies.bits());
+        write_str(&mut out, &self.original);
+        write_varint(&mut out, self.expr.len() as u64);
+
+        for node in &self.expr {
+            let er = match node {
+                ExprNode::Op(Operator::And) => {
+                    out.push(AND);
+                    continue;
+                }
+                ExprNode::Op(Operator::Or) => {
+                    out.push(OR);
+                    continue;
+                }
+                ExprNode::Req(er) => er,
+            };
+
+            let mut flags = 0;
+            if er.req.exception.is_some() {
+                flags |= HAS_EXCEPTION;
+            }
+
+            match &er.req.license {
+                LicenseItem::Spdx { id, or_later } => {
+                    if *or_later {
+                        flags |= OR_LATER;
+                    }
+
+                    out.extend_from_slice(&[SPDX, flags]);
+                    write_varint(&mut out, id.index() as u64);
+                }
+                LicenseItem::Other { doc_ref, lic_ref } => {
+                    if doc_ref.is_some() {
+                        flags |= HAS_DOC_REF;
+                    }
+
+                    out.extend_from_slice(&[REF, flags]);
+                    if let Some(doc_ref) = doc_ref {
+                        write_str(&mut out, doc_ref);
+                    }
+                    write_str(&mut out, lic_ref);
+                }
+            }
+
+            if let Some(exc) = er.req.exception {
+                write_varint(&mut out, exc.index() as u64);
+            }
+
+            write_varint(&mut out, u64::from(er.span.start));
+            write_varint(&mut out, u64::from(er.span.end));
+        }
+
+        out
+    }
+
+    /// Decodes an expression that was encoded with [`Expression::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// The data must have been encoded with the same version of the SPDX
+    /// license list as this crate uses, and must be a valid expression,
+    /// otherwise an error is returned, rather than an expression with the
+    /// wrong licenses.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader(bytes);
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(DecodeError::UnknownFormat);
+        }
+
+        let version = reader.str()?;
+        let hash = u64::from_le_bytes(
+            reader
+                .take(8)?
+                .try_into()
+                .map_err(|_err| DecodeError::Malformed)?,
+        );
+
+        if version != identifiers::VERSION || hash != identifiers::CONTENT_HASH {
+            return Err(DecodeError::ListMismatch {
+                version: version.to_owned(),
+            });
+        }
+
+        let leniencies = reader.byte()?;
+        let original = reader.str()?.to_owned();
+
+        // The nodes are decoded into a flat expression, so that they are
+        // validated in the same way as a rebuilt one
+        let count = reader.varint()?;
+        let mut nodes = Vec::new();
+        let mut strings = Vec::new();
+        let mut push_string = |s: &str| {
+            strings.push(s.to_owned());
+            strings.len() as i32 - 1
+        };
+
+        for _ in 0..count {
+            let tag = reader.byte()?;
+            let (flags, mut node) = match tag {
+                AND => {
+                    nodes.push(FlatNode::op(FlatNode::AND));
+                    continue;
+                }
+                OR => {
+                    nodes.push(FlatNode::op(FlatNode::OR));
+                    continue;
+                }
+                SPDX => {
+                    let flags = reader.byte()?;
+
+                    (
+                        flags,
+                        FlatNode {
+                            or_later: flags & OR_LATER != 0,
+                            license_index: reader.flat_index()?,
+                            ..FlatNode::op(FlatNode::LICENSE)
+                        },
+                    )
+                }
+                REF => {
+                    let flags = reader.byte()?;
+                    let mut node = FlatNode::op(FlatNode::REF);
+
+                    if flags & HAS_DOC_REF != 0 {
+                        node.doc_ref_string_index = push_string(reader.str()?);
+                    }
+                    node.ref_string_index = push_string(reader.str()?);
+                    (flags, node)
+                }
+                _ => return Err(DecodeError::Malformed),
+            };
+
+            if flags & HAS_EXCEPTION != 0 {
+                node.exception_index = reader.flat_index()?;
+            }
+
+            node.span_start =
+                u32::try_from(reader.varint()?).map_err(|_err| DecodeError::Malformed)?;
+            node.span_end =
+                u32::try_from(reader.varint()?).map_err(|_err| DecodeError::Malformed)?;
+            nodes.push(node);
+        }
+
+        if !reader.0.is_empty() {
+            return Err(DecodeError::Malformed);
+        }
+
+        FlatExpr {
+            original,
+            leniencies,
+            nodes,
+            strings,
+        }
+        .rebuild()
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+
+        out.push(byte | 0x80);
+    }
+}
+
+fn write_str(out: &mut Vec<u8>, s: &str) {
+    write_varint(out, s.len() as u64);
+    out.extend_from_slice(s.as_bytes());
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.0.len() < len {
+            return Err(DecodeError::Malformed);
+        }
+
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn byte(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<u64, DecodeError> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(DecodeError::Malformed)
+    }
+
+    fn index(&mut self) -> Result<usize, DecodeError> {
+        usize::try_from(self.varint()?).map_err(|_err| DecodeError::Malformed)
+    }
+
+    /// Reads a license or exception index as a [`FlatNode`] index
+    fn flat_index(&mut self) -> Result<i32, DecodeError> {
+        i32::try_from(self.varint()?).map_err(|_err| DecodeError::Malformed)
+    }
+
+    fn str(&mut self) -> Result<&'a str, DecodeError> {
+        let len = self.index()?;
+        std::str::from_utf8(self.take(len)?).map_err(|_err| DecodeError::Malformed)
+    }
+}
+
+/// Serializes the expression as the bytes from [`Expression::to_bytes`]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Expression {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+/// Deserializes the expression from the bytes from [`Expression::to_bytes`],
+/// failing if they were encoded with a different version of the SPDX license
+/// list, see [`Expression::from_bytes`]
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Expression {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+            type Value = Expression;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("an encoded SPDX expression")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Expression, E> {
+                Expression::from_bytes(bytes).map_err(E::custom)
+            }
+
+            // Formats without native byte strings, eg. JSON, serialize bytes as
+            // a sequence of numbers
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Expression, A::Error> {
+                // The length hint comes from the input, so don't trust it with
+                // a large allocation
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default().min(4096));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+
+                self.visit_bytes(&bytes)
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
@@ -0,0 +1,255 @@
+use super::{ExprNode, Expression, ExpressionReq, Operator};
+use crate::{error::DecodeError, ExceptionId, Leniencies, LicenseId, LicenseItem, LicenseReq};
+use smallvec::SmallVec;
+
+/// A flat, plain-old-data, representation of an [`Expression`], which is easy
+/// to pass across an FFI boundary, see [`Expression::flatten`]
+///
+/// # Stability
+///
+/// The layout of [`FlatNode`], and the meaning of its op codes, won't change
+/// other than in a semver breaking release of this crate. However, the license
+/// and exception indices are only valid for a single version of the SPDX
+/// license list, see [`LicenseId::index`], so a `FlatExpr` should only be
+/// rebuilt by the same version of this crate that flattened it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatExpr {
+    /// The original expression string
+    pub original: String,
+    /// The [bits](Leniencies::bits) of the [`Expression::leniencies`]
+    pub leniencies: u8,
+    /// The nodes of the expression in postfix order, the same as
+    /// [`Expression::iter`]
+    pub nodes: Vec<FlatNode>,
+    /// The `DocumentRef` and `LicenseRef` ids that are referred to by the
+    /// nodes, without their prefixes
+    pub strings: Vec<String>,
+}
+
+/// A single node of a [`FlatExpr`], either an operator or a license
+/// requirement, depending on its `op_code`
+///
+/// Indices that are not applicable to the node are `-1`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct FlatNode {
+    /// One of [`FlatNode::AND`], [`FlatNode::OR`], [`FlatNode::LICENSE`], or
+    /// [`FlatNode::REF`]
+    pub op_code: u8,
+    /// Whether a [`FlatNode::LICENSE`] is followed by a `+` or uses the GNU
+    /// `-or-later` suffix
+    pub or_later: bool,
+    /// The [index](LicenseId::index) of the license of a [`FlatNode::LICENSE`]
+    pub license_index: i32,
+    /// The [index](ExceptionId::index) of the exception of a requirement
+    pub exception_index: i32,
+    /// The index in [`FlatExpr::strings`] of the `LicenseRef` id of a
+    /// [`FlatNode::REF`]
+    pub ref_string_index: i32,
+    /// The index in [`FlatExpr::strings`] of the `DocumentRef` id of a
+    /// [`FlatNode::REF`]
+    pub doc_ref_string_index: i32,
+    /// The byte offset in [`FlatExpr::original`] where a requirement starts
+    pub span_start: u32,
+    /// The byte offset in [`FlatExpr::original`] where a requirement ends
+    pub span_end: u32,
+}
+
+impl FlatNode {
+    /// Joins the previous 2 terms with an `AND`
+    pub const AND: u8 = 0;
+    /// Joins the previous 2 terms with an `OR`
+    pub const OR: u8 = 1;
+    /// A requirement for an SPDX license
+    pub const LICENSE: u8 = 2;
+    /// A requirement for a `LicenseRef`, optionally in a `DocumentRef`
+    pub const REF: u8 = 3;
+
+    const NONE: i32 = -1;
+
+    pub(super) fn op(op_code: u8) -> Self {
+        Self {
+            op_code,
+            or_later: false,
+            license_index: Self::NONE,
+            exception_index: Self::NONE,
+            ref_string_index: Self::NONE,
+            doc_ref_string_index: Self::NONE,
+            span_start: 0,
+            span_end: 0,
+        }
+    }
+}
+
+impl Expression {
+    /// Flattens the expression into plain-old-data, eg. to pass it across an
+    /// FFI boundary without needing to parse it again, which can be turned
+    /// back into an expression with [`FlatExpr::rebuild`].
+    ///
+    /// ```
+    /// use spdx::expression::FlatNode;
+    ///
+    /// let expr = spdx::Expression::parse("MIT OR LicenseRef-Embark").unwrap();
+    /// let flat = expr.flatten();
+    ///
+    /// let ops: Vec<_> = flat.nodes.iter().map(|node| node.op_code).collect();
+    /// assert_eq!(ops, [FlatNode::LICENSE, FlatNode::REF, FlatNode::OR]);
+    /// assert_eq!(flat.strings, ["Embark"]);
+    ///
+    /// assert_eq!(flat.rebuild().unwrap(), expr);
+    /// ```
+    #[must_use]
+    pub fn flatten(&self) -> FlatExpr {
+        let mut strings = Vec::new();
+        let mut intern = |s: &str| {
+            let index = strings.iter().position(|existing| existing == s);
+            index.unwrap_or_else(|| {
+                strings.push(s.to_owned());
+                strings.len() - 1
+            }) as i32
+        };
+
+        let nodes = self
+            .expr
+            .iter()
+            .map(|node| {
+                let er = match node {
+                    ExprNode::Op(Operator::And) => return FlatNode::op(FlatNode::AND),
+                    ExprNode::Op(Operator::Or) => return FlatNode::op(FlatNode::OR),
+                    ExprNode::Req(er) => er,
+                };
+
+                let mut node = FlatNode {
+                    exception_index: er
+                        .req
+                        .exception
+                        .map_or(FlatNode::NONE, |exc| exc.index() as i32),
+                    span_start: er.span.start,
+                    span_end: er.span.end,
+                    ..FlatNode::op(FlatNode::LICENSE)
+                };
+
+                match &er.req.license {
+                    LicenseItem::Spdx { id, or_later } => {
+                        node.license_index = id.index() as i32;
+                        node.or_later = *or_later;
+                    }
+                    LicenseItem::Other { doc_ref, lic_ref } => {
+                        node.op_code = FlatNode::REF;
+                        node.ref_string_index = intern(lic_ref);
+                        if let Some(doc_ref) = doc_ref {
+                            node.doc_ref_string_index = intern(doc_ref);
+                        }
+                    }
+                }
+
+                node
+            })
+            .collect();
+
+        FlatExpr {
+            original: self.original.to_string(),
+            leniencies: self.leniencies.bits(),
+            nodes,
+            strings,
+        }
+    }
+}
+
+impl FlatExpr {
+    /// Rebuilds the [`Expression`] that was [flattened](Expression::flatten)
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`DecodeError::Malformed`] if the nodes are not a valid
+    /// expression, eg. an unknown op code, an index that is out of bounds, or
+    /// an operator without 2 terms to join. The nodes decoded by
+    /// [`Expression::from_bytes`] are validated in the same way.
+    pub fn rebuild(&self) -> Result<Expression, DecodeError> {
+        let leniencies = Leniencies::from_bits(self.leniencies).ok_or(DecodeError::Malformed)?;
+
+        let string = |index: i32| {
+            usize::try_from(index)
+                .ok()
+                .and_then(|index| self.strings.get(index))
+                .map(String::as_str)
+                .ok_or(DecodeError::Malformed)
+        };
+
+        let mut expr = SmallVec::with_capacity(self.nodes.len());
+        // The number of terms on the stack, so that the nodes are known to be
+        // a valid postfix expression
+        let mut depth = 0usize;
+
+        for node in &self.nodes {
+            let license = match node.op_code {
+                FlatNode::AND | FlatNode::OR => {
+                    if depth < 2 {
+                        return Err(DecodeError::Malformed);
+                    }
+
+                    expr.push(ExprNode::Op(if node.op_code == FlatNode::AND {
+                        Operator::And
+                    } else {
+                        Operator::Or
+                    }));
+                    depth -= 1;
+                    continue;
+                }
+                FlatNode::LICENSE => LicenseItem::Spdx {
+                    id: usize::try_from(node.license_index)
+                        .ok()
+                        .and_then(LicenseId::from_index)
+                        .ok_or(DecodeError::Malformed)?,
+                    or_later: node.or_later,
+                },
+                FlatNode::REF => {
+                    let doc_ref = if node.doc_ref_string_index == FlatNode::NONE {
+                        None
+                    } else {
+                        Some(string(node.doc_ref_string_index)?)
+                    };
+
+                    LicenseReq::license_ref(doc_ref, string(node.ref_string_index)?)
+                        .map_err(|_err| DecodeError::Malformed)?
+                        .license
+                }
+                _ => return Err(DecodeError::Malformed),
+            };
+
+            let exception = if node.exception_index == FlatNode::NONE {
+                None
+            } else {
+                Some(
+                    usize::try_from(node.exception_index)
+                        .ok()
+                        .and_then(ExceptionId::from_index)
+                        .ok_or(DecodeError::Malformed)?,
+                )
+            };
+
+            if node.span_start > node.span_end
+                || !self.original.is_char_boundary(node.span_start as usize)
+                || !self.original.is_char_boundary(node.span_end as usize)
+            {
+                return Err(DecodeError::Malformed);
+            }
+
+            expr.push(ExprNode::Req(ExpressionReq {
+                req: LicenseReq { license, exception },
+                span: node.span_start..node.span_end,
+            }));
+            depth += 1;
+        }
+
+        if depth != 1 {
+            return Err(DecodeError::Malformed);
+        }
+
+        Ok(Expression {
+            expr,
+            original: self.original.clone().into(),
+            leniencies,
+        })
+    }
+}
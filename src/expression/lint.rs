@@ -0,0 +1,281 @@
+use super::{walk::Tree, Expression, Operator};
+use crate::{version::compare_versions, LicenseId, LicenseItem, LicenseReq};
+use std::{cmp::Ordering, fmt, ops::Range};
+
+/// How likely a [`Lint`] is to indicate an actual problem with an [`Expression`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The expression is valid, but can be simplified
+    Info,
+    /// The expression is valid, but uses discouraged syntax or identifiers
+    Warning,
+    /// The expression is valid, but is very likely not what the author intended
+    Error,
+}
+
+/// The kind of finding reported by [`Expression::lint`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LintKind {
+    /// The same requirement appears multiple times in the same `AND` or `OR`,
+    /// eg. `MIT AND MIT`
+    DuplicateRequirement,
+    /// A deprecated license or exception identifier was used
+    DeprecatedId,
+    /// A `+` was used on a license that already has the `-or-later` suffix,
+    /// eg. `GPL-2.0-or-later+`
+    PlusOnOrLater,
+    /// A branch of an `OR` doesn't allow anything that another branch of the
+    /// same `OR` doesn't already allow, eg. `Apache-2.0 OR Apache-2.0 WITH LLVM-exception`
+    SubsumedBranch,
+    /// Different versions of the same GNU license, without the `-or-later`
+    /// suffix, are joined by an `AND`, eg. `GPL-2.0-only AND GPL-3.0-only`,
+    /// which generally can't be satisfied at the same time
+    ExclusiveGnuVersions,
+}
+
+impl LintKind {
+    /// The severity of this kind of lint
+    #[must_use]
+    pub fn severity(self) -> Severity {
+        match self {
+            Self::SubsumedBranch => Severity::Info,
+            Self::DuplicateRequirement | Self::DeprecatedId | Self::PlusOnOrLater => {
+                Severity::Warning
+            }
+            Self::ExclusiveGnuVersions => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for LintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::DuplicateRequirement => "duplicate license requirement",
+            Self::DeprecatedId => "deprecated license or exception identifier",
+            Self::PlusOnOrLater => "`+` used on a license that is already `-or-later`",
+            Self::SubsumedBranch => "license requirement is redundant with another OR branch",
+            Self::ExclusiveGnuVersions => {
+                "different versions of the same GNU license are required at the same time"
+            }
+        })
+    }
+}
+
+/// An advisory finding about an otherwise valid [`Expression`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// The kind of finding
+    pub kind: LintKind,
+    /// The severity of the finding, see [`LintKind::severity`]
+    pub severity: Severity,
+    /// The spans of the license requirement(s) involved, in the order they
+    /// appear in the expression
+    pub spans: Vec<Range<u32>>,
+}
+
+impl Lint {
+    fn new(kind: LintKind, spans: Vec<Range<u32>>) -> Self {
+        Self {
+            kind,
+            severity: kind.severity(),
+            spans,
+        }
+    }
+}
+
+impl Expression {
+    /// Checks the expression for constructs that are valid, but are likely
+    /// mistakes or could be simplified, such as duplicate or redundant
+    /// requirements, deprecated identifiers, or `AND`s of mutually exclusive
+    /// GNU license versions.
+    ///
+    /// This is purely advisory, the expression is still valid and evaluates
+    /// the same regardless of any lints. The lints are sorted by the position
+    /// of their first span.
+    ///
+    /// ```
+    /// use spdx::expression::LintKind;
+    ///
+    /// let expr = spdx::Expression::parse("MIT AND MIT").unwrap();
+    /// let lints = expr.lint();
+    ///
+    /// assert_eq!(lints.len(), 1);
+    /// assert_eq!(lints[0].kind, LintKind::DuplicateRequirement);
+    /// assert_eq!(lints[0].spans, vec![0..3, 8..11]);
+    ///
+    /// assert!(spdx::Expression::parse("MIT OR Apache-2.0").unwrap().lint().is_empty());
+    /// ```
+    #[must_use]
+    pub fn lint(&self) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for er in self.requirements() {
+            let start = er.span.start as usize;
+            let end = er.span.end as usize;
+            let Some(text) = self.original.get(start..end) else {
+                continue;
+            };
+
+            // The span covers the whole requirement, so split off the license
+            // as it was written, which ends at whitespace or a `+`
+            let license = text
+                .split(|c: char| c.is_whitespace() || c == '+')
+                .next()
+                .unwrap_or(text);
+
+            // The parsed license id is the root id for GNU licenses, so use the
+            // id as it was actually written
+            let written = crate::license_id(license);
+
+            if written.map_or(false, |id| id.is_deprecated())
+                || er.req.exception.map_or(false, |exc| exc.is_deprecated())
+            {
+                lints.push(Lint::new(LintKind::DeprecatedId, vec![er.span.clone()]));
+            }
+
+            if license.ends_with("-or-later") && text[license.len()..].starts_with('+') {
+                lints.push(Lint::new(LintKind::PlusOnOrLater, vec![er.span.clone()]));
+            }
+        }
+
+        if let Some(tree) = self.tree() {
+            lint_tree(&tree, &mut lints);
+        }
+
+        lints.sort_by_key(|lint| lint.spans.first().map_or(0, |span| span.start));
+        lints
+    }
+}
+
+fn lint_tree(tree: &Tree<'_>, lints: &mut Vec<Lint>) {
+    let Tree::Op(op, children) = tree else {
+        return;
+    };
+
+    let reqs: Vec<_> = children
+        .iter()
+        .filter_map(|child| match child {
+            Tree::Req(req) => Some(*req),
+            Tree::Op(..) => None,
+        })
+        .collect();
+
+    for (i, a) in reqs.iter().enumerate() {
+        // Only report a duplicate once, for the first time it is seen
+        if reqs[..i].iter().any(|prev| prev.req == a.req) {
+            continue;
+        }
+
+        let dupes: Vec<_> = reqs[i + 1..]
+            .iter()
+            .filter(|b| b.req == a.req)
+            .map(|b| b.span.clone())
+            .collect();
+
+        if !dupes.is_empty() {
+            let mut spans = vec![a.span.clone()];
+            spans.extend(dupes);
+            lints.push(Lint::new(LintKind::DuplicateRequirement, spans));
+        }
+    }
+
+    for (i, a) in reqs.iter().enumerate() {
+        for b in &reqs[i + 1..] {
+            if a.req == b.req {
+                continue;
+            }
+
+            match op {
+                Operator::Or => {
+                    let subsumed = if subsumes(&a.req, &b.req) {
+                        Some(b)
+                    } else if subsumes(&b.req, &a.req) {
+                        Some(a)
+                    } else {
+                        None
+                    };
+
+                    if let Some(subsumed) = subsumed {
+                        lints.push(Lint::new(
+                            LintKind::SubsumedBranch,
+                            vec![subsumed.span.clone()],
+                        ));
+                    }
+                }
+                Operator::And => {
+                    if exclusive_gnu_versions(&a.req, &b.req) {
+                        lints.push(Lint::new(
+                            LintKind::ExclusiveGnuVersions,
+                            vec![a.span.clone(), b.span.clone()],
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    for child in children {
+        lint_tree(child, lints);
+    }
+}
+
+/// Returns true if `a` allows at least the same licenses as `b`, ie. they are
+/// the same license, `a` is `or_later` if `b` is, and `a` either has the same
+/// exception as `b`, or no exception at all
+fn subsumes(a: &LicenseReq, b: &LicenseReq) -> bool {
+    let same_license = match (&a.license, &b.license) {
+        (
+            LicenseItem::Spdx {
+                id: a_id,
+                or_later: a_later,
+            },
+            LicenseItem::Spdx {
+                id: b_id,
+                or_later: b_later,
+            },
+        ) => a_id == b_id && (*a_later || !*b_later),
+        (a_other @ LicenseItem::Other { .. }, b_other @ LicenseItem::Other { .. }) => {
+            a_other == b_other
+        }
+        _ => false,
+    };
+
+    same_license && (a.exception.is_none() || a.exception == b.exception)
+}
+
+/// Returns true if `a` and `b` are different versions of the same GNU license,
+/// and neither of them allows later versions
+fn exclusive_gnu_versions(a: &LicenseReq, b: &LicenseReq) -> bool {
+    match (&a.license, &b.license) {
+        (
+            LicenseItem::Spdx {
+                id: a_id,
+                or_later: false,
+            },
+            LicenseItem::Spdx {
+                id: b_id,
+                or_later: false,
+            },
+        ) if a_id.is_gnu() && b_id.is_gnu() => match (gnu_version(*a_id), gnu_version(*b_id)) {
+            (Some((a_family, a_version)), Some((b_family, b_version))) => {
+                a_family == b_family && compare_versions(a_version, b_version) != Ordering::Equal
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Splits a GNU license id into its family and version, eg. `GPL` and `2.0`,
+/// ignoring any exception that a composite id includes, as well as variants
+/// that are the same version, eg. `GFDL-1.3-invariants`
+fn gnu_version(id: LicenseId) -> Option<(&'static str, &'static str)> {
+    let id = id.decompose().map_or(id, |(base, _)| base);
+    let mut parts = id.name.split('-');
+    let family = parts.next()?;
+    let version = parts.next()?;
+
+    version
+        .starts_with(|c: char| c.is_ascii_digit())
+        .then_some((family, version))
+}
@@ -47,6 +47,13 @@ impl Expression {
     /// `Apache-2.0 OR MIT` expression to minimize to just `Apache-2.0` as only
     /// 1 of the licenses is required, and `Apache-2.0` has priority.
     ///
+    /// The result is always deterministic. Of all of the sets of licensees
+    /// that satisfy the expression, the smallest is chosen, and if several
+    /// sets have the same smallest size, the one that comes first when
+    /// comparing the priorities of their licensees, highest first, is chosen.
+    /// For example, with the licensees `[A, B, C, D]`, `[A, D]` is preferred
+    /// over `[B, C]`, as `A` has a higher priority than `B`.
+    ///
     /// # Errors
     ///
     /// This method will fail if more than 64 unique licensees are satisfied by
@@ -93,31 +100,38 @@ impl Expression {
             found_set
         };
 
-        let set_size = (1 << found_set.len()) as u64;
+        // Candidate sets are ordered first by their size, and then sets of the
+        // same size are ordered lexicographically by the priority, ie. the
+        // position in `accepted`, of their licensees, so the first candidate
+        // that satisfies the expression is the smallest set, and of the
+        // smallest sets, the one that prefers the highest priority licensees
+        let count = found_set.len();
+        for size in 1..=count {
+            let mut candidate: smallvec::SmallVec<[usize; 5]> = (0..size).collect();
 
-        for mask in 1..=set_size {
-            let eval_res = self.evaluate(|req| {
-                for (ind, lic) in found_set.iter().enumerate() {
-                    if mask & (1 << ind) != 0 && lic.satisfies(req) {
-                        return true;
-                    }
+            loop {
+                if self.evaluate(|req| candidate.iter().any(|&ind| found_set[ind].satisfies(req))) {
+                    return Ok(found_set
+                        .into_iter()
+                        .enumerate()
+                        .filter_map(|(ind, lic)| candidate.contains(&ind).then(|| lic.into_req()))
+                        .collect());
                 }
 
-                false
-            });
+                // Advance to the next candidate of the same size by bumping the
+                // rightmost index that still has room, and packing the indices
+                // after it directly behind it
+                let Some(pos) = (0..size)
+                    .rev()
+                    .find(|&pos| candidate[pos] < count - size + pos)
+                else {
+                    break;
+                };
 
-            if eval_res {
-                return Ok(found_set
-                    .into_iter()
-                    .enumerate()
-                    .filter_map(|(ind, lic)| {
-                        if mask & (1 << ind) == 0 {
-                            None
-                        } else {
-                            Some(lic.into_req())
-                        }
-                    })
-                    .collect());
+                candidate[pos] += 1;
+                for next in pos + 1..size {
+                    candidate[next] = candidate[next - 1] + 1;
+                }
             }
         }
 
@@ -1,10 +1,11 @@
 use crate::{
     error::{ParseError, Reason},
     expression::{ExprNode, Expression, ExpressionReq, Operator},
-    lexer::{Lexer, Token},
-    LicenseItem, LicenseReq, ParseMode,
+    lexer::{Leniencies, Lexer, Synonyms, Token},
+    ExceptionId, LicenseId, LicenseItem, LicenseReq, ParseMode,
 };
 use smallvec::SmallVec;
+use std::ops::Range;
 
 impl Expression {
     /// Given a license expression, attempts to parse and validate it as a valid
@@ -33,9 +34,27 @@ impl Expression {
     ///
     /// 1. '/' is replaced with ' OR '
     /// 1. Lower-cased operators ('or', 'and', 'with') are upper-cased
-    /// 1. '+' is tranformed to `-or-later` for GNU licenses
+    /// 1. '+' is tranformed to the [`-or-later` variant](crate::LicenseId::to_or_later)
+    ///     of GNU licenses that have one, and removed if the GNU license already
+    ///     has the `-or-later` suffix. A '+' on a GNU license with the `-only`
+    ///     suffix is an error, as it contradicts itself
     /// 1. Invalid/imprecise license identifiers (eg. `apache2`) are replaced
     ///     with their valid identifiers
+    /// 1. Deprecated license identifiers are replaced with their
+    ///     [successor](crate::LicenseId::successor), if they have one. Successors
+    ///     that include an exception are not used if the deprecated license is
+    ///     followed by a `WITH`, or by a `+` that isn't rewritten to an
+    ///     `-or-later` variant, eg. `GPL-2.0-with-classpath-exception+` is
+    ///     `GPL-2.0-or-later WITH Classpath-exception-2.0`, but `eCos-2.0+` is
+    ///     left as is.
+    ///
+    /// A `+` that does not directly follow an SPDX license identifier, eg.
+    /// `LicenseRef-Foo+` or `(MIT)+`, results in the same error that
+    /// [`Expression::parse`] would give, rather than being passed through.
+    ///
+    /// Deprecated license identifiers without a successor are left as is, so
+    /// the canonical expression can still contain deprecated licenses, which
+    /// can be checked with [`LicenseId::is_deprecated`](crate::LicenseId::is_deprecated).
     ///
     /// If the provided expression is not modified then `None` is returned
     ///
@@ -48,47 +67,178 @@ impl Expression {
     /// ```
     pub fn canonicalize(original: &str) -> Result<Option<String>, ParseError> {
         let mut can = String::with_capacity(original.len());
+        Self::canonicalize_into(original, ParseMode::LAX, &mut can)?;
+        Ok((can != original).then_some(can))
+    }
+
+    /// Canonicalizes the input expression, as with [`Expression::canonicalize`],
+    /// while parsing it, so that the [`Display`](std::fmt::Display) of the
+    /// returned expression is always the canonical string, which the spans of
+    /// its requirements refer to.
+    ///
+    /// The `mode` controls which non-conforming syntax is accepted and fixed
+    /// up, eg. using [`ParseMode::STRICT`] here only normalizes whitespace.
+    ///
+    /// The returned `bool` is `true` if the canonical string differs from the
+    /// provided expression.
+    ///
+    /// Errors always refer to the provided expression, rather than the
+    /// canonical string, so that their spans can be shown in the original.
+    ///
+    /// ```
+    /// let (expr, modified) = spdx::Expression::parse_canonical(
+    ///     "( mit/apache )and gpl-2.0+",
+    ///     spdx::ParseMode::LAX,
+    /// ).unwrap();
+    ///
+    /// assert!(modified);
+    /// assert_eq!(expr.to_string(), "(MIT OR Apache-2.0) AND GPL-2.0-or-later");
+    /// ```
+    pub fn parse_canonical(original: &str, mode: ParseMode) -> Result<(Self, bool), ParseError> {
+        let mut can = String::with_capacity(original.len());
+        let mut op_stack = SmallVec::new();
+        let mut expr_queue = SmallVec::new();
 
-        let lexer = Lexer::new_mode(original, ParseMode::LAX);
+        let leniencies = parse_into(
+            original,
+            mode,
+            None,
+            Some(&mut can),
+            &mut op_stack,
+            &mut expr_queue,
+        )?;
+        let modified = can != original;
 
-        // Keep track if the last license id is a GNU license that uses the -or-later
-        // convention rather than the + like all other licenses
-        let mut last_is_gnu = false;
-        for tok in lexer {
-            let tok = tok?;
+        Ok((
+            Expression {
+                original: can.into(),
+                expr: expr_queue,
+                leniencies,
+            },
+            modified,
+        ))
+    }
 
-            match tok.token {
-                Token::Spdx(id) => {
-                    last_is_gnu = id.is_gnu();
-                    can.push_str(id.name);
-                }
-                Token::And => can.push_str(" AND "),
-                Token::Or => can.push_str(" OR "),
-                Token::With => can.push_str(" WITH "),
-                Token::Plus => {
-                    if last_is_gnu {
-                        can.push_str("-or-later");
-                    } else {
-                        can.push('+');
-                    }
+    /// Canonicalizes each of the inputs, as with [`Expression::canonicalize`],
+    /// reporting whether each input was already canonical, was fixed, or
+    /// failed to parse, in the order the inputs were provided.
+    ///
+    /// Unlike [`Expression::canonicalize`], which only fixes up the tokens of
+    /// the expression, each input is also validated, so eg. `MIT OR` is an
+    /// error, the same one as [`Expression::parse_mode`] with
+    /// [`ParseMode::LAX`] would return.
+    ///
+    /// The canonical string is built in a buffer that is reused for every
+    /// input, so only the inputs that needed fixing allocate.
+    ///
+    /// ```
+    /// use spdx::{expression::CanonicalizeResult, Expression};
+    ///
+    /// let results = Expression::canonicalize_batch(["MIT", "mit/apache2", "MIT OR"]);
+    ///
+    /// assert_eq!(results[0], CanonicalizeResult::Canonical { index: 0 });
+    /// assert_eq!(
+    ///     results[1],
+    ///     CanonicalizeResult::Fixed {
+    ///         index: 1,
+    ///         canonical: "MIT OR Apache-2.0".to_owned(),
+    ///     }
+    /// );
+    /// assert!(matches!(results[2], CanonicalizeResult::Error { index: 2, .. }));
+    /// ```
+    pub fn canonicalize_batch<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<CanonicalizeResult> {
+        let mut can = String::new();
+
+        inputs
+            .into_iter()
+            .enumerate()
+            .map(|(index, original)| {
+                can.clear();
+                let res = parse_into(
+                    original,
+                    ParseMode::LAX,
+                    None,
+                    Some(&mut can),
+                    &mut SmallVec::new(),
+                    &mut (),
+                );
+
+                match res {
+                    Ok(_) if can == original => CanonicalizeResult::Canonical { index },
+                    Ok(_) => CanonicalizeResult::Fixed {
+                        index,
+                        canonical: can.clone(),
+                    },
+                    Err(error) => CanonicalizeResult::Error { index, error },
                 }
-                Token::OpenParen => can.push('('),
-                Token::CloseParen => can.push(')'),
-                Token::Exception(exc) => can.push_str(exc.name),
-                Token::LicenseRef { doc_ref, lic_ref } => {
-                    if let Some(dr) = doc_ref {
-                        can.push_str("DocumentRef-");
-                        can.push_str(dr);
-                        can.push(':');
+            })
+            .collect()
+    }
+
+    /// Writes the canonical form of the expression's tokens into `can`,
+    /// without validating the expression
+    fn canonicalize_into(
+        original: &str,
+        mode: ParseMode,
+        can: &mut String,
+    ) -> Result<(), ParseError> {
+        let mut tokens = Lexer::new_mode(original, mode).peekable();
+
+        // Keep track of the last token, both so that we know if a `+` follows
+        // a GNU license that uses the -or-later convention rather than the +
+        // like all other licenses, and so that we can reject a `+` that
+        // doesn't follow a license id at all, just as the parser would
+        let mut last_token: Option<Token<'_>> = None;
+        while let Some(tok) = tokens.next() {
+            let tok = tok.map_err(|err| narrow_unknown(err, last_token.as_ref()))?;
+
+            match &tok.token {
+                Token::Spdx(id) => {
+                    let next = match tokens.peek() {
+                        Some(Ok(next)) => Some(&next.token),
+                        _ => None,
+                    };
+
+                    let fix = LicenseFixup::new(*id, next);
+                    if fix.plus {
+                        tokens.next();
                     }
 
-                    can.push_str("LicenseRef-");
-                    can.push_str(lic_ref);
+                    fix.write(can);
+                    last_token = Some(fix.exception.map_or(Token::Spdx(fix.id), Token::Exception));
+                    continue;
                 }
+                Token::Plus => match last_token {
+                    // GNU licenses with an `-or-later` variant have already been
+                    // rewritten, so the `+` is kept on any others
+                    Some(Token::Spdx(id)) if !(id.is_gnu() && id.name.ends_with("-only")) => {}
+                    Some(Token::Spdx(_)) => {
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            mode,
+                            span: tok.span,
+                            reason: Reason::GnuPlusWithSuffix,
+                        });
+                    }
+                    _ => {
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            mode,
+                            span: tok.span,
+                            reason: Reason::Unexpected(expected_after(last_token.as_ref())),
+                        });
+                    }
+                },
+                _ => {}
             }
+
+            write_token(can, &tok.token);
+            last_token = Some(tok.token);
         }
 
-        Ok((can != original).then_some(can))
+        Ok(())
     }
 
     /// Parses an expression with the specified `ParseMode`. With
@@ -102,249 +252,833 @@ impl Expression {
     /// ).unwrap();
     /// ```
     pub fn parse_mode(original: &str, mode: ParseMode) -> Result<Self, ParseError> {
-        // Operator precedence in SPDX 2.1
-        // +
-        // WITH
-        // AND
-        // OR
-        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
-        enum Op {
-            //Plus,
-            //With,
-            And,
-            Or,
-            Open,
+        if let Some(expr) = Self::parse_single_id(original) {
+            return Ok(expr);
         }
 
-        struct OpAndSpan {
-            op: Op,
-            span: std::ops::Range<usize>,
+        let mut op_stack = SmallVec::new();
+        let mut expr_queue = SmallVec::new();
+
+        let leniencies = parse_into(original, mode, None, None, &mut op_stack, &mut expr_queue)?;
+
+        // TODO: Investigate using https://github.com/oli-obk/quine-mc_cluskey to simplify
+        // expressions, but not really critical. Just cool.
+
+        Ok(Expression {
+            original: original.to_owned().into(),
+            expr: expr_queue,
+            leniencies,
+        })
+    }
+
+    /// The fast path for the overwhelmingly common case of an expression that
+    /// is exactly a single license id, eg. `MIT`, which is parsed without
+    /// allocating by borrowing the id's name as the original string
+    fn parse_single_id(original: &str) -> Option<Self> {
+        let id = crate::license_id(original)?;
+
+        // GNU ids are normalized by the lexer, eg. `GPL-2.0-or-later` is
+        // `GPL-2.0` with `or_later`, and deprecated ids are recorded in the
+        // leniencies, so they take the slow path
+        if id.name != original || id.is_gnu() || id.is_deprecated() {
+            return None;
         }
 
-        let lexer = Lexer::new_mode(original, mode);
-        let mut op_stack = SmallVec::<[OpAndSpan; 3]>::new();
-        let mut expr_queue = SmallVec::<[ExprNode; 5]>::new();
+        let mut expr = SmallVec::new();
+        expr.push(ExprNode::Req(ExpressionReq {
+            req: LicenseReq {
+                license: LicenseItem::Spdx {
+                    id,
+                    or_later: false,
+                },
+                exception: None,
+            },
+            span: 0..original.len() as u32,
+        }));
 
-        // Keep track of the last token to simplify validation of the token stream
-        let mut last_token: Option<Token<'_>> = None;
+        Some(Expression {
+            expr,
+            original: std::borrow::Cow::Borrowed(id.name),
+            leniencies: Leniencies::empty(),
+        })
+    }
+
+    /// Checks if the expression is valid with the specified `ParseMode`, giving
+    /// the exact same result as [`Expression::parse_mode`], but without
+    /// building the expression, which makes it cheaper when the expression is
+    /// only being validated, eg. when rejecting user input.
+    ///
+    /// ```
+    /// use spdx::{Expression, ParseMode};
+    ///
+    /// Expression::validate("MIT OR Apache-2.0", ParseMode::STRICT).unwrap();
+    /// Expression::validate("MIT/Apache-2.0", ParseMode::LAX).unwrap();
+    ///
+    /// assert_eq!(
+    ///     Expression::validate("MIT OR NOPE", ParseMode::STRICT).unwrap_err(),
+    ///     Expression::parse("MIT OR NOPE").unwrap_err(),
+    /// );
+    /// ```
+    pub fn validate(original: &str, mode: ParseMode) -> Result<(), ParseError> {
+        let mut op_stack = SmallVec::new();
+
+        parse_into(original, mode, None, None, &mut op_stack, &mut ())?;
+        Ok(())
+    }
+
+    /// Parses an expression the way crates.io license fields are usually
+    /// handled, strictly if possible, but falling back to [`ParseMode::LAX`]
+    /// if not, also returning which of them was needed.
+    ///
+    /// The expression is only lexed once, tracking whether any of the lax
+    /// syntax or names were actually used.
+    ///
+    /// ```
+    /// use spdx::{expression::ParseFidelity, Expression};
+    ///
+    /// let (expr, fidelity) = Expression::parse_lossy("MIT/Apache-2.0").unwrap();
+    /// assert_eq!(fidelity, ParseFidelity::Lax);
+    /// assert_eq!(expr, Expression::parse("MIT OR Apache-2.0").unwrap());
+    ///
+    /// // Errors are only returned if the expression can't be parsed even with LAX
+    /// Expression::parse_lossy("MIT OR").unwrap_err();
+    /// ```
+    pub fn parse_lossy(original: &str) -> Result<(Self, ParseFidelity), ParseError> {
+        let mut op_stack = SmallVec::new();
+        let mut expr_queue = SmallVec::new();
 
-        let apply_op = |op: OpAndSpan, q: &mut SmallVec<[ExprNode; 5]>| {
-            let op = match op.op {
-                Op::And => Operator::And,
-                Op::Or => Operator::Or,
-                Op::Open => unreachable!(),
-            };
+        let leniencies = parse_into(
+            original,
+            ParseMode::LAX,
+            None,
+            None,
+            &mut op_stack,
+            &mut expr_queue,
+        )?;
 
-            q.push(ExprNode::Op(op));
-            Ok(())
+        let fidelity = if leniencies.contains(Leniencies::IMPRECISE_NAME) {
+            ParseFidelity::LaxCanonicalized
+        } else if leniencies.requires_lax() {
+            ParseFidelity::Lax
+        } else {
+            ParseFidelity::Strict
         };
 
-        let make_err_for_token = |last_token: Option<Token<'_>>, span: std::ops::Range<usize>| {
-            let expected: &[&str] = match last_token {
-                None | Some(Token::And | Token::Or | Token::OpenParen) => &["<license>", "("],
-                Some(Token::CloseParen) => &["AND", "OR"],
-                Some(Token::Exception(_)) => &["AND", "OR", ")"],
-                Some(Token::Spdx(_)) => &["AND", "OR", "WITH", ")", "+"],
-                Some(Token::LicenseRef { .. } | Token::Plus) => &["AND", "OR", "WITH", ")"],
-                Some(Token::With) => &["<exception>"],
-            };
-
-            Err(ParseError {
-                original: original.to_owned(),
+        Ok((
+            Expression {
+                original: original.to_owned().into(),
+                expr: expr_queue,
+                leniencies,
+            },
+            fidelity,
+        ))
+    }
+
+    /// Parses an expression with the specified `ParseMode`, also accepting
+    /// the caller supplied [`Synonyms`].
+    ///
+    /// ```
+    /// let mut synonyms = spdx::Synonyms::new();
+    /// synonyms.add_license_ref("Company Standard License", None, "Acme-1.0");
+    ///
+    /// let expr = spdx::Expression::parse_with_synonyms(
+    ///     "MIT OR Company Standard License",
+    ///     spdx::ParseMode::STRICT,
+    ///     &synonyms,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     expr.requirements().last().unwrap().req.to_string(),
+    ///     "LicenseRef-Acme-1.0"
+    /// );
+    /// ```
+    pub fn parse_with_synonyms(
+        original: &str,
+        mode: ParseMode,
+        synonyms: &Synonyms,
+    ) -> Result<Self, ParseError> {
+        let mut op_stack = SmallVec::new();
+        let mut expr_queue = SmallVec::new();
+
+        let leniencies = parse_into(
+            original,
+            mode,
+            Some(synonyms),
+            None,
+            &mut op_stack,
+            &mut expr_queue,
+        )?;
+
+        Ok(Expression {
+            original: original.to_owned().into(),
+            expr: expr_queue,
+            leniencies,
+        })
+    }
+}
+
+/// How strictly an expression parsed with [`Expression::parse_lossy`]
+/// conformed to the SPDX specification
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ParseFidelity {
+    /// The expression is valid with [`ParseMode::STRICT`]
+    Strict,
+    /// The expression required [`ParseMode::LAX`] syntax, eg. `/` or
+    /// lower case operators, but all of the license identifiers were valid
+    Lax,
+    /// The expression required [`ParseMode::LAX`], and one or more imprecise
+    /// license names, eg. `apache2`, were replaced with a license identifier
+    LaxCanonicalized,
+}
+
+/// The outcome of canonicalizing a single input with
+/// [`Expression::canonicalize_batch`], along with the index of the input
+#[derive(Debug, PartialEq, Eq)]
+pub enum CanonicalizeResult {
+    /// The input was already canonical
+    Canonical {
+        /// The index of the input
+        index: usize,
+    },
+    /// The input was not canonical, and was fixed
+    Fixed {
+        /// The index of the input
+        index: usize,
+        /// The canonical form of the input
+        canonical: String,
+    },
+    /// The input could not be parsed, even with [`ParseMode::LAX`]
+    Error {
+        /// The index of the input
+        index: usize,
+        /// Why the input could not be parsed
+        error: ParseError,
+    },
+}
+
+impl CanonicalizeResult {
+    /// The index of the input this is the outcome for
+    #[inline]
+    #[must_use]
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Canonical { index } | Self::Fixed { index, .. } | Self::Error { index, .. } => {
+                *index
+            }
+        }
+    }
+}
+
+/// A reusable expression parser, which keeps the allocations used while
+/// parsing, so that parsing many expressions in a row, such as every license
+/// expression on crates.io, only needs to allocate the parsed [`Expression`]s
+/// themselves.
+///
+/// ```
+/// let mut parser = spdx::expression::ExpressionParser::new();
+///
+/// for text in ["MIT OR Apache-2.0", "(MIT AND ISC) OR (Apache-2.0 AND Zlib) OR BSD-3-Clause"] {
+///     assert_eq!(
+///         parser.parse_into(text, spdx::ParseMode::STRICT).unwrap(),
+///         spdx::Expression::parse(text).unwrap(),
+///     );
+/// }
+/// ```
+#[derive(Default)]
+pub struct ExpressionParser {
+    op_stack: SmallVec<[OpAndSpan; 3]>,
+    expr_queue: SmallVec<[ExprNode; 5]>,
+}
+
+impl ExpressionParser {
+    /// Creates a new parser, no allocations are made until an expression
+    /// large enough to need them is parsed
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses an expression with the specified `ParseMode`, exactly as
+    /// [`Expression::parse_mode`] does, but reusing this parser's buffers
+    pub fn parse_into(
+        &mut self,
+        original: &str,
+        mode: ParseMode,
+    ) -> Result<Expression, ParseError> {
+        self.op_stack.clear();
+        self.expr_queue.clear();
+
+        let leniencies = parse_into(
+            original,
+            mode,
+            None,
+            None,
+            &mut self.op_stack,
+            &mut self.expr_queue,
+        )?;
+
+        Ok(Expression {
+            original: original.to_owned().into(),
+            expr: self.expr_queue.drain(..).collect(),
+            leniencies,
+        })
+    }
+
+    /// Parses each of the expressions with the specified `ParseMode`,
+    /// returning the successfully parsed expressions and the errors for
+    /// the expressions that failed to parse, in the order they were provided
+    ///
+    /// ```
+    /// let mut parser = spdx::expression::ExpressionParser::new();
+    ///
+    /// let (exprs, errors) = parser.parse_many(
+    ///     ["MIT", "MIT OR", "Apache-2.0 WITH LLVM-exception", "NOPE"],
+    ///     spdx::ParseMode::STRICT,
+    /// );
+    ///
+    /// assert_eq!(exprs.len(), 2);
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[1].original, "NOPE");
+    /// ```
+    pub fn parse_many<'a>(
+        &mut self,
+        texts: impl IntoIterator<Item = &'a str>,
+        mode: ParseMode,
+    ) -> (Vec<Expression>, Vec<ParseError>) {
+        let mut exprs = Vec::new();
+        let mut errors = Vec::new();
+
+        for text in texts {
+            match self.parse_into(text, mode) {
+                Ok(expr) => exprs.push(expr),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        (exprs, errors)
+    }
+}
+
+// Operator precedence in SPDX 2.1
+// +
+// WITH
+// AND
+// OR
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum Op {
+    //Plus,
+    //With,
+    And,
+    Or,
+    Open,
+}
+
+struct OpAndSpan {
+    op: Op,
+    span: std::ops::Range<usize>,
+}
+
+/// Receives the requirements and operators of an expression, in postfix
+/// order, as they are parsed, so that the same parser can either build an
+/// [`Expression`], or only validate it
+trait Sink {
+    /// A license id was parsed
+    fn push_license(&mut self, id: LicenseId, span: Range<usize>);
+    /// A `DocumentRef-`/`LicenseRef-` was parsed
+    fn push_ref(&mut self, doc_ref: Option<&str>, lic_ref: &str, span: Range<usize>);
+    /// A `+` followed the most recently pushed license id, extending its span
+    /// to `end`
+    fn set_or_later(&mut self, end: usize);
+    /// An exception was applied to the most recently pushed requirement,
+    /// extending its span to `end`
+    fn set_exception(&mut self, exc: ExceptionId, end: usize);
+    /// The most recently pushed requirement was a composite license id that is
+    /// expanded to the license `WITH` the exception
+    fn expand_composite(&mut self, id: LicenseId, exc: ExceptionId);
+    /// An operator was applied to the previous 2 requirements or operators
+    fn push_op(&mut self, op: Operator);
+}
+
+impl Sink for SmallVec<[ExprNode; 5]> {
+    fn push_license(&mut self, id: LicenseId, span: Range<usize>) {
+        self.push(ExprNode::Req(ExpressionReq {
+            req: LicenseReq::from(id),
+            span: span.start as u32..span.end as u32,
+        }));
+    }
+
+    fn push_ref(&mut self, doc_ref: Option<&str>, lic_ref: &str, span: Range<usize>) {
+        self.push(ExprNode::Req(ExpressionReq {
+            req: LicenseReq {
+                license: LicenseItem::Other {
+                    doc_ref: doc_ref.map(String::from),
+                    lic_ref: String::from(lic_ref),
+                },
+                exception: None,
+            },
+            span: span.start as u32..span.end as u32,
+        }));
+    }
+
+    fn set_or_later(&mut self, end: usize) {
+        match self.last_mut() {
+            Some(ExprNode::Req(ExpressionReq {
+                req:
+                    LicenseReq {
+                        license: LicenseItem::Spdx { or_later, .. },
+                        ..
+                    },
                 span,
-                reason: Reason::Unexpected(expected),
-            })
+            })) => {
+                *or_later = true;
+                span.end = end as u32;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn set_exception(&mut self, exc: ExceptionId, end: usize) {
+        match self.last_mut() {
+            Some(ExprNode::Req(lic)) => {
+                lic.req.exception = Some(exc);
+                lic.span.end = end as u32;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn expand_composite(&mut self, id: LicenseId, exc: ExceptionId) {
+        match self.last_mut() {
+            Some(ExprNode::Req(lic)) => {
+                let was_or_later = lic.req.or_later();
+                lic.req = LicenseReq::from(id);
+                if let LicenseItem::Spdx { or_later, .. } = &mut lic.req.license {
+                    *or_later |= was_or_later;
+                }
+                lic.req.exception = Some(exc);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn push_op(&mut self, op: Operator) {
+        self.push(ExprNode::Op(op));
+    }
+}
+
+/// Discards everything, for when the expression is only being validated
+impl Sink for () {
+    fn push_license(&mut self, _id: LicenseId, _span: Range<usize>) {}
+    fn push_ref(&mut self, _doc_ref: Option<&str>, _lic_ref: &str, _span: Range<usize>) {}
+    fn set_or_later(&mut self, _end: usize) {}
+    fn set_exception(&mut self, _exc: ExceptionId, _end: usize) {}
+    fn expand_composite(&mut self, _id: LicenseId, _exc: ExceptionId) {}
+    fn push_op(&mut self, _op: Operator) {}
+}
+
+/// Parses the expression into the sink. If `canonical` is provided, the
+/// canonical form of the expression is appended to it while parsing, and the
+/// spans given to the sink refer to it rather than the original, though errors
+/// still refer to the original
+fn parse_into(
+    original: &str,
+    mode: ParseMode,
+    synonyms: Option<&Synonyms>,
+    mut canonical: Option<&mut String>,
+    op_stack: &mut SmallVec<[OpAndSpan; 3]>,
+    sink: &mut impl Sink,
+) -> Result<Leniencies, ParseError> {
+    let mut lexer = Lexer::new_mode(original, mode);
+    if let Some(synonyms) = synonyms {
+        lexer = lexer.with_synonyms(synonyms);
+    }
+
+    // Keep track of the last token to simplify validation of the token stream
+    let mut last_token: Option<Token<'_>> = None;
+
+    let apply_op = |op: OpAndSpan, sink: &mut _| {
+        let op = match op.op {
+            Op::And => Operator::And,
+            Op::Or => Operator::Or,
+            Op::Open => unreachable!(),
         };
 
-        // Basic implementation of the https://en.wikipedia.org/wiki/Shunting-yard_algorithm
-        'outer: for tok in lexer {
-            let lt = tok?;
-            match &lt.token {
-                Token::Spdx(id) => match last_token {
-                    None | Some(Token::And | Token::Or | Token::OpenParen) => {
-                        expr_queue.push(ExprNode::Req(ExpressionReq {
-                            req: LicenseReq::from(*id),
-                            span: lt.span.start as u32..lt.span.end as u32,
-                        }));
-                    }
-                    _ => return make_err_for_token(last_token, lt.span),
-                },
-                Token::LicenseRef { doc_ref, lic_ref } => match last_token {
-                    None | Some(Token::And | Token::Or | Token::OpenParen) => {
-                        expr_queue.push(ExprNode::Req(ExpressionReq {
-                            req: LicenseReq {
-                                license: LicenseItem::Other {
-                                    doc_ref: doc_ref.map(String::from),
-                                    lic_ref: String::from(*lic_ref),
-                                },
-                                exception: None,
-                            },
-                            span: lt.span.start as u32..lt.span.end as u32,
-                        }));
-                    }
-                    _ => return make_err_for_token(last_token, lt.span),
-                },
-                Token::Plus => match last_token {
-                    Some(Token::Spdx(_)) => match expr_queue.last_mut().unwrap() {
-                        ExprNode::Req(ExpressionReq {
-                            req:
-                                LicenseReq {
-                                    license: LicenseItem::Spdx { or_later, id },
-                                    ..
-                                },
-                            ..
-                        }) => {
-                            // Handle GNU licenses differently, as they should *NOT* be used with the `+`
-                            if !mode.allow_postfix_plus_on_gpl && id.is_gnu() {
+        Sink::push_op(sink, op);
+        Ok(())
+    };
+
+    let make_err_for_token = |last_token: Option<Token<'_>>, span: std::ops::Range<usize>| {
+        Err(ParseError {
+            original: original.to_owned(),
+            mode,
+            span,
+            reason: Reason::Unexpected(expected_after(last_token.as_ref())),
+        })
+    };
+
+    // Basic implementation of the https://en.wikipedia.org/wiki/Shunting-yard_algorithm
+    // `+` on a GNU license isn't known to the lexer
+    let mut gnu_plus = false;
+
+    // A composite license id that is expanded once it's known that it isn't
+    // followed by its own `WITH`
+    let mut composite = None;
+
+    // The canonical form of a license id depends on the token after it, eg.
+    // a `+` after a GNU license, so a single token of lookahead is needed
+    let mut tokens = lexer.by_ref().peekable();
+
+    'outer: while let Some(tok) = tokens.next() {
+        let lt = tok.map_err(|err| narrow_unknown(err, last_token.as_ref()))?;
+
+        if !matches!(lt.token, Token::Plus | Token::With) {
+            if let Some((id, exc)) = composite.take() {
+                sink.expand_composite(id, exc);
+            }
+        }
+
+        // License ids are written once the token after them is known
+        let span = match canonical.as_deref_mut() {
+            Some(can) if !matches!(lt.token, Token::Spdx(_)) => {
+                let start = can.len();
+                write_token(can, &lt.token);
+                start..can.len()
+            }
+            _ => lt.span.clone(),
+        };
+
+        match &lt.token {
+            Token::Spdx(id) => match last_token {
+                None | Some(Token::And | Token::Or | Token::OpenParen) => {
+                    if let Some(can) = canonical.as_deref_mut() {
+                        let next = match tokens.peek() {
+                            Some(Ok(next)) => Some(&next.token),
+                            _ => None,
+                        };
+                        let fix = LicenseFixup::new(*id, next);
+
+                        let start = can.len();
+                        can.push_str(fix.id.name);
+                        sink.push_license(fix.id, start..can.len());
+
+                        last_token = Some(Token::Spdx(fix.id));
+                        if let Some(exc) = fix.exception {
+                            can.push_str(" WITH ");
+                            can.push_str(exc.name);
+                            sink.set_exception(exc, can.len());
+                            last_token = Some(Token::Exception(exc));
+                        }
+
+                        if fix.plus {
+                            let plus_span = match tokens.next() {
+                                Some(Ok(plus)) => plus.span,
+                                _ => unreachable!(),
+                            };
+
+                            if !mode.allow_postfix_plus_on_gpl {
                                 return Err(ParseError {
                                     original: original.to_owned(),
-                                    span: lt.span,
+                                    mode,
+                                    span: plus_span,
                                     reason: Reason::GnuNoPlus,
                                 });
                             }
 
-                            *or_later = true;
+                            gnu_plus = true;
+                            if fix.exception.is_none() {
+                                last_token = Some(Token::Plus);
+                            }
+                        }
+
+                        continue;
+                    }
+
+                    sink.push_license(*id, span);
+
+                    if mode.expand_composite_ids {
+                        composite = id.decompose();
+                    }
+                }
+                _ => return make_err_for_token(last_token, lt.span),
+            },
+            Token::LicenseRef { doc_ref, lic_ref } => match last_token {
+                None | Some(Token::And | Token::Or | Token::OpenParen) => {
+                    sink.push_ref(*doc_ref, lic_ref, span);
+                }
+                _ => return make_err_for_token(last_token, lt.span),
+            },
+            Token::Plus => match last_token {
+                Some(Token::Spdx(written)) => {
+                    // Handle GNU licenses differently, as they should *NOT* be used with the `+`
+                    if written.is_gnu() {
+                        if !mode.allow_postfix_plus_on_gpl {
+                            return Err(ParseError {
+                                original: original.to_owned(),
+                                mode,
+                                span: lt.span,
+                                reason: Reason::GnuNoPlus,
+                            });
+                        }
+
+                        // `-or-later+` is redundant, and already has `or_later`
+                        // set, but `-only+` contradicts itself
+                        if written.name.ends_with("-only") {
+                            return Err(ParseError {
+                                original: original.to_owned(),
+                                mode,
+                                span: lt.span,
+                                reason: Reason::GnuPlusWithSuffix,
+                            });
                         }
+
+                        gnu_plus = true;
+                    }
+
+                    sink.set_or_later(span.end);
+                }
+                _ => return make_err_for_token(last_token, lt.span),
+            },
+            Token::With => match last_token {
+                Some(Token::Spdx(_) | Token::LicenseRef { .. } | Token::Plus) => composite = None,
+                _ => return make_err_for_token(last_token, lt.span),
+            },
+            Token::Or | Token::And => match last_token {
+                Some(
+                    Token::Spdx(_)
+                    | Token::LicenseRef { .. }
+                    | Token::CloseParen
+                    | Token::Exception(_)
+                    | Token::Plus,
+                ) => {
+                    let new_op = match lt.token {
+                        Token::Or => Op::Or,
+                        Token::And => Op::And,
                         _ => unreachable!(),
-                    },
-                    _ => return make_err_for_token(last_token, lt.span),
-                },
-                Token::With => match last_token {
-                    Some(Token::Spdx(_) | Token::LicenseRef { .. } | Token::Plus) => {}
-                    _ => return make_err_for_token(last_token, lt.span),
-                },
-                Token::Or | Token::And => match last_token {
+                    };
+
+                    while let Some(op) = op_stack.last() {
+                        match &op.op {
+                            Op::Open => break,
+                            top => {
+                                if *top < new_op {
+                                    let top = op_stack.pop().unwrap();
+
+                                    match top.op {
+                                        Op::And | Op::Or => apply_op(top, sink)?,
+                                        Op::Open => unreachable!(),
+                                    }
+                                } else {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+
+                    op_stack.push(OpAndSpan {
+                        op: new_op,
+                        span: lt.span,
+                    });
+                }
+                _ => return make_err_for_token(last_token, lt.span),
+            },
+            Token::OpenParen => match last_token {
+                None | Some(Token::And | Token::Or | Token::OpenParen) => {
+                    op_stack.push(OpAndSpan {
+                        op: Op::Open,
+                        span: lt.span,
+                    });
+                }
+                _ => return make_err_for_token(last_token, lt.span),
+            },
+            Token::CloseParen => {
+                match last_token {
                     Some(
                         Token::Spdx(_)
                         | Token::LicenseRef { .. }
-                        | Token::CloseParen
+                        | Token::Plus
                         | Token::Exception(_)
-                        | Token::Plus,
+                        | Token::CloseParen,
                     ) => {
-                        let new_op = match lt.token {
-                            Token::Or => Op::Or,
-                            Token::And => Op::And,
-                            _ => unreachable!(),
-                        };
-
-                        while let Some(op) = op_stack.last() {
-                            match &op.op {
-                                Op::Open => break,
-                                top => {
-                                    if *top < new_op {
-                                        let top = op_stack.pop().unwrap();
-
-                                        match top.op {
-                                            Op::And | Op::Or => apply_op(top, &mut expr_queue)?,
-                                            Op::Open => unreachable!(),
-                                        }
-                                    } else {
-                                        break;
-                                    }
+                        while let Some(top) = op_stack.pop() {
+                            match top.op {
+                                Op::And | Op::Or => apply_op(top, sink)?,
+                                Op::Open => {
+                                    // This is the only place we go back to the top of the outer loop,
+                                    // so make sure we correctly record this token
+                                    last_token = Some(Token::CloseParen);
+                                    continue 'outer;
                                 }
                             }
                         }
 
-                        op_stack.push(OpAndSpan {
-                            op: new_op,
+                        // We didn't have an opening parentheses if we get here
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            mode,
                             span: lt.span,
+                            reason: Reason::UnopenedParens,
                         });
                     }
                     _ => return make_err_for_token(last_token, lt.span),
-                },
-                Token::OpenParen => match last_token {
-                    None | Some(Token::And | Token::Or | Token::OpenParen) => {
-                        op_stack.push(OpAndSpan {
-                            op: Op::Open,
-                            span: lt.span,
-                        });
-                    }
-                    _ => return make_err_for_token(last_token, lt.span),
-                },
-                Token::CloseParen => {
-                    match last_token {
-                        Some(
-                            Token::Spdx(_)
-                            | Token::LicenseRef { .. }
-                            | Token::Plus
-                            | Token::Exception(_)
-                            | Token::CloseParen,
-                        ) => {
-                            while let Some(top) = op_stack.pop() {
-                                match top.op {
-                                    Op::And | Op::Or => apply_op(top, &mut expr_queue)?,
-                                    Op::Open => {
-                                        // This is the only place we go back to the top of the outer loop,
-                                        // so make sure we correctly record this token
-                                        last_token = Some(Token::CloseParen);
-                                        continue 'outer;
-                                    }
-                                }
-                            }
-
-                            // We didn't have an opening parentheses if we get here
-                            return Err(ParseError {
-                                original: original.to_owned(),
-                                span: lt.span,
-                                reason: Reason::UnopenedParens,
-                            });
-                        }
-                        _ => return make_err_for_token(last_token, lt.span),
-                    }
                 }
-                Token::Exception(exc) => match last_token {
-                    Some(Token::With) => match expr_queue.last_mut() {
-                        Some(ExprNode::Req(lic)) => {
-                            lic.req.exception = Some(*exc);
-                        }
-                        _ => unreachable!(),
-                    },
-                    _ => return make_err_for_token(last_token, lt.span),
-                },
             }
+            Token::Exception(exc) => match last_token {
+                Some(Token::With) => sink.set_exception(*exc, span.end),
+                _ => return make_err_for_token(last_token, lt.span),
+            },
+        }
+
+        last_token = Some(lt.token);
+    }
+
+    if let Some((id, exc)) = composite {
+        sink.expand_composite(id, exc);
+    }
 
-            last_token = Some(lt.token);
+    // Validate that the terminating token is valid
+    match last_token {
+        Some(
+            Token::Spdx(_)
+            | Token::LicenseRef { .. }
+            | Token::Exception(_)
+            | Token::CloseParen
+            | Token::Plus,
+        ) => {}
+        // We have to have at least one valid license requirement
+        None => {
+            return Err(ParseError {
+                original: original.to_owned(),
+                mode,
+                span: 0..original.len(),
+                reason: Reason::Empty,
+            });
         }
+        Some(_) => return make_err_for_token(last_token, original.len()..original.len()),
+    }
 
-        // Validate that the terminating token is valid
-        match last_token {
-            Some(
-                Token::Spdx(_)
-                | Token::LicenseRef { .. }
-                | Token::Exception(_)
-                | Token::CloseParen
-                | Token::Plus,
-            ) => {}
-            // We have to have at least one valid license requirement
-            None => {
+    while let Some(top) = op_stack.pop() {
+        match top.op {
+            Op::And | Op::Or => apply_op(top, sink)?,
+            Op::Open => {
                 return Err(ParseError {
                     original: original.to_owned(),
-                    span: 0..original.len(),
-                    reason: Reason::Empty,
+                    mode,
+                    span: top.span,
+                    reason: Reason::UnclosedParens,
                 });
             }
-            Some(_) => return make_err_for_token(last_token, original.len()..original.len()),
         }
+    }
 
-        while let Some(top) = op_stack.pop() {
-            match top.op {
-                Op::And | Op::Or => apply_op(top, &mut expr_queue)?,
-                Op::Open => {
-                    return Err(ParseError {
-                        original: original.to_owned(),
-                        span: top.span,
-                        reason: Reason::UnclosedParens,
-                    });
-                }
+    let mut leniencies = lexer.leniencies;
+    if gnu_plus {
+        leniencies |= Leniencies::GPL_PLUS;
+    }
+    Ok(leniencies)
+}
+
+/// The canonical form of an SPDX license id, which depends on the token after
+/// it, shared by canonicalization and the fixes of diagnostics
+pub(super) struct LicenseFixup {
+    /// The license id to write
+    pub(super) id: LicenseId,
+    /// The exception of a deprecated license that was replaced by a license
+    /// `WITH` an exception
+    pub(super) exception: Option<ExceptionId>,
+    /// True if the `+` after the license is part of the fix, as it was
+    /// replaced by the `-or-later` variant of a GNU license
+    pub(super) plus: bool,
+}
+
+impl LicenseFixup {
+    pub(super) fn new(id: LicenseId, next: Option<&Token<'_>>) -> Self {
+        // A `+` after a GNU license is its `-or-later` variant, which for a
+        // deprecated license is the variant of its successor, eg.
+        // `GPL-2.0-with-classpath-exception+` is
+        // `GPL-2.0-or-later WITH Classpath-exception-2.0`
+        if id.is_gnu() && next == Some(&Token::Plus) && !id.name.ends_with("-only") {
+            let (base, exception) = id.successor().unwrap_or((id, None));
+
+            if let Some(or_later) = base.to_or_later() {
+                return Self {
+                    id: or_later,
+                    exception,
+                    plus: true,
+                };
             }
         }
 
-        // TODO: Investigate using https://github.com/oli-obk/quine-mc_cluskey to simplify
-        // expressions, but not really critical. Just cool.
+        let (id, exception) = match id.successor() {
+            // The exception can't be used if there is already an exception, or
+            // if it would come before a `+`
+            Some((_, Some(_))) if matches!(next, Some(Token::Plus | Token::With)) => (id, None),
+            Some(successor) => successor,
+            None => (id, None),
+        };
 
-        Ok(Expression {
-            original: original.to_owned(),
-            expr: expr_queue,
-        })
+        Self {
+            id,
+            exception,
+            plus: false,
+        }
+    }
+
+    pub(super) fn write(&self, can: &mut String) {
+        can.push_str(self.id.name);
+
+        if let Some(exc) = self.exception {
+            can.push_str(" WITH ");
+            can.push_str(exc.name);
+        }
+    }
+}
+
+/// Writes the canonical form of any token other than an SPDX license id, which
+/// is written with [`LicenseFixup`]
+fn write_token(can: &mut String, token: &Token<'_>) {
+    match token {
+        Token::Spdx(id) => can.push_str(id.name),
+        Token::And => can.push_str(" AND "),
+        Token::Or => can.push_str(" OR "),
+        Token::With => can.push_str(" WITH "),
+        Token::Plus => can.push('+'),
+        Token::OpenParen => can.push('('),
+        Token::CloseParen => can.push(')'),
+        Token::Exception(exc) => can.push_str(exc.name),
+        Token::LicenseRef { doc_ref, lic_ref } => {
+            if let Some(dr) = doc_ref {
+                can.push_str("DocumentRef-");
+                can.push_str(dr);
+                can.push(':');
+            }
+
+            can.push_str("LicenseRef-");
+            can.push_str(lic_ref);
+        }
+    }
+}
+
+/// The lexer doesn't know what was expected when it finds an unknown term, but
+/// if only a license, or only an exception, is valid after the last token, the
+/// error can be more specific
+fn narrow_unknown(err: ParseError, last_token: Option<&Token<'_>>) -> ParseError {
+    match last_token {
+        None | Some(Token::And | Token::Or | Token::OpenParen) => {
+            err.unknown_as(Reason::UnknownLicense)
+        }
+        Some(Token::With) => err.unknown_as(Reason::UnknownException),
+        _ => err,
+    }
+}
+
+/// The tokens that are valid after the specified token
+fn expected_after(last_token: Option<&Token<'_>>) -> &'static [&'static str] {
+    match last_token {
+        None | Some(Token::And | Token::Or | Token::OpenParen) => &["<license>", "("],
+        Some(Token::CloseParen) => &["AND", "OR"],
+        Some(Token::Exception(_)) => &["AND", "OR", ")"],
+        Some(Token::Spdx(_)) => &["AND", "OR", "WITH", ")", "+"],
+        Some(Token::LicenseRef { .. } | Token::Plus) => &["AND", "OR", "WITH", ")"],
+        Some(Token::With) => &["<exception>"],
     }
 }
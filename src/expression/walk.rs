@@ -0,0 +1,285 @@
+use super::{ExprNode, Expression, ExpressionReq, Operator};
+use crate::{LicenseId, LicenseItem, LicenseReq, Restrictiveness};
+
+/// The expression as a tree, with nested operators of the same kind flattened,
+/// eg. `A OR (B OR C)` is a single `OR` with 3 children
+pub(super) enum Tree<'e> {
+    Req(&'e ExpressionReq),
+    Op(Operator, Vec<Tree<'e>>),
+}
+
+/// An event emitted by [`Expression::walk`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WalkEvent<'e> {
+    /// The start of a group of 2 or more terms joined by the same operator
+    EnterGroup(Operator),
+    /// The end of the most recently entered group
+    LeaveGroup,
+    /// A license requirement
+    Requirement(&'e ExpressionReq),
+}
+
+/// Statistics about the structure of an expression, eg. to flag complex
+/// expressions for review, see [`Expression::stats`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ExprStats {
+    /// The maximum nesting of groups of terms joined by an operator, as
+    /// decided by precedence rather than parentheses, eg. 0 for `MIT`, 1 for
+    /// `MIT OR (Apache-2.0 OR ISC)`, and 2 for `MIT AND (Apache-2.0 OR ISC)`
+    pub depth: usize,
+    /// The number of `AND` operators
+    pub and_count: usize,
+    /// The number of `OR` operators
+    pub or_count: usize,
+    /// The number of license requirements
+    pub req_count: usize,
+    /// The number of requirements with an exception
+    pub with_count: usize,
+    /// The number of requirements that are a `LicenseRef`, with or without a
+    /// `DocumentRef`
+    pub ref_count: usize,
+}
+
+impl Expression {
+    /// Walks the structure of the expression, calling the callback with each
+    /// requirement, and the start and end of each group of terms joined by
+    /// an operator, in the order they appear in the expression.
+    ///
+    /// The groups reflect operator precedence rather than the parentheses that
+    /// were actually written, and terms joined by the same operator are in a
+    /// single group, eg. `A OR (B OR C AND D)` is walked as an `OR` group
+    /// containing `A`, `B`, and an `AND` group containing `C` and `D`.
+    ///
+    /// ```
+    /// use spdx::expression::{Operator, WalkEvent};
+    ///
+    /// let expr = spdx::Expression::parse("MIT AND (Apache-2.0 OR ISC)").unwrap();
+    ///
+    /// let mut depth = 0;
+    /// let mut report = Vec::new();
+    /// expr.walk(|event| match event {
+    ///     WalkEvent::EnterGroup(op) => {
+    ///         report.push(format!("{}{op:?}", "  ".repeat(depth)));
+    ///         depth += 1;
+    ///     }
+    ///     WalkEvent::LeaveGroup => depth -= 1,
+    ///     WalkEvent::Requirement(er) => {
+    ///         report.push(format!("{}{}", "  ".repeat(depth), er.req));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(report, ["And", "  MIT", "  Or", "    Apache-2.0", "    ISC"]);
+    /// ```
+    pub fn walk<'e>(&'e self, mut callback: impl FnMut(WalkEvent<'e>)) {
+        fn walk_tree<'e>(tree: &Tree<'e>, callback: &mut impl FnMut(WalkEvent<'e>)) {
+            match tree {
+                Tree::Req(req) => callback(WalkEvent::Requirement(req)),
+                Tree::Op(op, children) => {
+                    callback(WalkEvent::EnterGroup(*op));
+                    for child in children {
+                        walk_tree(child, callback);
+                    }
+                    callback(WalkEvent::LeaveGroup);
+                }
+            }
+        }
+
+        if let Some(tree) = self.tree() {
+            walk_tree(&tree, &mut callback);
+        }
+    }
+
+    /// Gets statistics about the structure of the expression, using the same
+    /// grouping as [`Expression::walk`]
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse(
+    ///     "MIT AND (Apache-2.0 WITH LLVM-exception OR LicenseRef-Foo OR ISC)",
+    /// )
+    /// .unwrap();
+    /// let stats = expr.stats();
+    ///
+    /// assert_eq!(stats.depth, 2);
+    /// assert_eq!((stats.and_count, stats.or_count), (1, 2));
+    /// assert_eq!((stats.req_count, stats.with_count, stats.ref_count), (4, 1, 1));
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> ExprStats {
+        let mut stats = ExprStats::default();
+        // The operator and number of terms of each group that is being walked
+        let mut groups = Vec::new();
+
+        self.walk(|event| match event {
+            WalkEvent::EnterGroup(op) => {
+                groups.push((op, 0));
+                stats.depth = stats.depth.max(groups.len());
+            }
+            WalkEvent::LeaveGroup => {
+                if let Some((op, terms)) = groups.pop() {
+                    // A group of N terms is joined by N - 1 operators
+                    match op {
+                        Operator::And => stats.and_count += terms - 1,
+                        Operator::Or => stats.or_count += terms - 1,
+                    }
+                }
+
+                if let Some((_, terms)) = groups.last_mut() {
+                    *terms += 1;
+                }
+            }
+            WalkEvent::Requirement(er) => {
+                stats.req_count += 1;
+
+                if er.req.exception.is_some() {
+                    stats.with_count += 1;
+                }
+
+                if let LicenseItem::Other { .. } = er.req.license {
+                    stats.ref_count += 1;
+                }
+
+                if let Some((_, terms)) = groups.last_mut() {
+                    *terms += 1;
+                }
+            }
+        });
+
+        stats
+    }
+
+    /// Returns true if the expressions are equivalent, ie. they are equal
+    /// other than the order and grouping of terms joined by the same operator,
+    /// and repeated terms, see [`Expression`#equality](Expression#equality).
+    ///
+    /// ```
+    /// let a = spdx::Expression::parse("MIT OR (Apache-2.0 OR ISC)").unwrap();
+    /// let b = spdx::Expression::parse("ISC OR Apache-2.0 OR MIT OR ISC").unwrap();
+    /// assert!(a.equivalent(&b));
+    ///
+    /// // Distributing isn't taken into account
+    /// let a = spdx::Expression::parse("MIT AND (Apache-2.0 OR ISC)").unwrap();
+    /// let b = spdx::Expression::parse("MIT AND Apache-2.0 OR MIT AND ISC").unwrap();
+    /// assert!(!a.equivalent(&b));
+    /// ```
+    #[must_use]
+    pub fn equivalent(&self, other: &Self) -> bool {
+        /// Renders the tree with the terms of each group sorted and deduplicated
+        fn key(tree: &Tree<'_>) -> String {
+            match tree {
+                Tree::Req(er) => er.req.to_string(),
+                Tree::Op(op, children) => {
+                    let mut terms: Vec<_> = children.iter().map(key).collect();
+                    terms.sort_unstable();
+                    terms.dedup();
+
+                    if terms.len() == 1 {
+                        return terms.remove(0);
+                    }
+
+                    format!("{op:?}({})", terms.join(","))
+                }
+            }
+        }
+
+        self.tree().map(|tree| key(&tree)) == other.tree().map(|tree| key(&tree))
+    }
+
+    /// Chooses the licenses to use to satisfy the expression that are the
+    /// least [restrictive](crate::LicenseId::restrictiveness), by choosing the
+    /// branch of each `OR` whose most restrictive license is the least
+    /// restrictive. Ties are broken by the [index](crate::LicenseId::index) of
+    /// the licenses, so the choice is always the same for the same expression.
+    ///
+    /// The licenses are returned in the order they appear in the expression,
+    /// without duplicates. `None` is returned if every choice includes a
+    /// license that isn't known, ie. a `LicenseRef-`.
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("GPL-3.0-only OR (MIT AND Apache-2.0)").unwrap();
+    /// let choice: Vec<_> = expr
+    ///     .most_permissive_choice()
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|req| req.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(choice, ["MIT", "Apache-2.0"]);
+    /// ```
+    #[must_use]
+    pub fn most_permissive_choice(&self) -> Option<Vec<LicenseReq>> {
+        /// The most restrictive license of the choice, the sorted indices of
+        /// its licenses for tie-breaking, and the requirements in the choice
+        type Choice<'e> = (Restrictiveness, Vec<usize>, Vec<&'e ExpressionReq>);
+
+        fn choose<'e>(tree: &Tree<'e>) -> Choice<'e> {
+            match tree {
+                Tree::Req(er) => {
+                    let id = er.req.license.id();
+                    (
+                        id.map_or(Restrictiveness::Unknown, LicenseId::restrictiveness),
+                        vec![id.map_or(usize::MAX, LicenseId::index)],
+                        vec![*er],
+                    )
+                }
+                Tree::Op(Operator::And, children) => {
+                    let mut choice = (Restrictiveness::Permissive, Vec::new(), Vec::new());
+                    for (restrictiveness, indices, reqs) in children.iter().map(choose) {
+                        choice.0 = choice.0.max(restrictiveness);
+                        choice.1.extend(indices);
+                        choice.2.extend(reqs);
+                    }
+
+                    choice.1.sort_unstable();
+                    choice
+                }
+                Tree::Op(Operator::Or, children) => children
+                    .iter()
+                    .map(choose)
+                    .min_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)))
+                    .expect("an OR always has at least 2 terms"),
+            }
+        }
+
+        let (restrictiveness, _, reqs) = choose(&self.tree()?);
+        if restrictiveness == Restrictiveness::Unknown {
+            return None;
+        }
+
+        let mut choice: Vec<LicenseReq> = Vec::with_capacity(reqs.len());
+        for er in reqs {
+            if !choice.contains(&er.req) {
+                choice.push(er.req.clone());
+            }
+        }
+
+        Some(choice)
+    }
+
+    pub(super) fn tree(&self) -> Option<Tree<'_>> {
+        let mut stack = Vec::new();
+
+        for node in self.expr.iter() {
+            match node {
+                ExprNode::Req(req) => stack.push(Tree::Req(req)),
+                ExprNode::Op(op) => {
+                    let b = stack.pop()?;
+                    let a = stack.pop()?;
+
+                    let mut children = Vec::new();
+                    for child in [a, b] {
+                        match child {
+                            Tree::Op(cop, grandchildren) if cop == *op => {
+                                children.extend(grandchildren);
+                            }
+                            other => children.push(other),
+                        }
+                    }
+
+                    stack.push(Tree::Op(*op, children));
+                }
+            }
+        }
+
+        stack.pop()
+    }
+}
@@ -7,16 +7,24 @@
  * cargo run --manifest-path update/Cargo.toml -- v<version> > src/identifiers.rs
 */
 
-pub const IS_FSF_LIBRE: u8 = 0x1;
-pub const IS_OSI_APPROVED: u8 = 0x2;
-pub const IS_DEPRECATED: u8 = 0x4;
-pub const IS_COPYLEFT: u8 = 0x8;
-pub const IS_GNU: u8 = 0x10;
+pub const IS_FSF_LIBRE: u16 = 0x1;
+pub const IS_OSI_APPROVED: u16 = 0x2;
+pub const IS_DEPRECATED: u16 = 0x4;
+pub const IS_COPYLEFT: u16 = 0x8;
+pub const IS_GNU: u16 = 0x10;
+pub const IS_CC: u16 = 0x20;
+pub const IS_NON_COMMERCIAL: u16 = 0x40;
+pub const IS_PUBLIC_DOMAIN_EQUIVALENT: u16 = 0x80;
 
 pub const VERSION: &str = "3.26.0";
+pub const RELEASE_DATE: &str = "2024-12-30";
 
-pub const LICENSES: &[(&str, &str, u8)] = &[
-    ("0BSD", r#"BSD Zero Clause License"#, IS_OSI_APPROVED),
+pub const LICENSES: &[(&str, &str, u16)] = &[
+    (
+        "0BSD",
+        r#"BSD Zero Clause License"#,
+        IS_OSI_APPROVED | IS_PUBLIC_DOMAIN_EQUIVALENT,
+    ),
     ("3D-Slicer-1.0", r#"3D Slicer License v1.0"#, 0x0),
     ("AAL", r#"Attribution Assurance License"#, IS_OSI_APPROVED),
     ("ADSL", r#"Amazon Digital Services License"#, 0x0),
@@ -84,7 +92,11 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
         r#"Academy of Motion Picture Arts and Sciences BSD"#,
         0x0,
     ),
-    ("ANTLR-PD", r#"ANTLR Software Rights Notice"#, 0x0),
+    (
+        "ANTLR-PD",
+        r#"ANTLR Software Rights Notice"#,
+        IS_PUBLIC_DOMAIN_EQUIVALENT,
+    ),
     (
         "ANTLR-PD-fallback",
         r#"ANTLR Software Rights Notice with license fallback"#,
@@ -365,277 +377,277 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
     (
         "CC-BY-1.0",
         r#"Creative Commons Attribution 1.0 Generic"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-2.0",
         r#"Creative Commons Attribution 2.0 Generic"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-2.5",
         r#"Creative Commons Attribution 2.5 Generic"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-2.5-AU",
         r#"Creative Commons Attribution 2.5 Australia"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-3.0",
         r#"Creative Commons Attribution 3.0 Unported"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-3.0-AT",
         r#"Creative Commons Attribution 3.0 Austria"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-3.0-AU",
         r#"Creative Commons Attribution 3.0 Australia"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-3.0-DE",
         r#"Creative Commons Attribution 3.0 Germany"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-3.0-IGO",
         r#"Creative Commons Attribution 3.0 IGO"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-3.0-NL",
         r#"Creative Commons Attribution 3.0 Netherlands"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-3.0-US",
         r#"Creative Commons Attribution 3.0 United States"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-4.0",
         r#"Creative Commons Attribution 4.0 International"#,
-        IS_FSF_LIBRE,
+        IS_FSF_LIBRE | IS_CC,
     ),
     (
         "CC-BY-NC-1.0",
         r#"Creative Commons Attribution Non Commercial 1.0 Generic"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-2.0",
         r#"Creative Commons Attribution Non Commercial 2.0 Generic"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-2.5",
         r#"Creative Commons Attribution Non Commercial 2.5 Generic"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-3.0",
         r#"Creative Commons Attribution Non Commercial 3.0 Unported"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-3.0-DE",
         r#"Creative Commons Attribution Non Commercial 3.0 Germany"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-4.0",
         r#"Creative Commons Attribution Non Commercial 4.0 International"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-ND-1.0",
         r#"Creative Commons Attribution Non Commercial No Derivatives 1.0 Generic"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-ND-2.0",
         r#"Creative Commons Attribution Non Commercial No Derivatives 2.0 Generic"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-ND-2.5",
         r#"Creative Commons Attribution Non Commercial No Derivatives 2.5 Generic"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-ND-3.0",
         r#"Creative Commons Attribution Non Commercial No Derivatives 3.0 Unported"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-ND-3.0-DE",
         r#"Creative Commons Attribution Non Commercial No Derivatives 3.0 Germany"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-ND-3.0-IGO",
         r#"Creative Commons Attribution Non Commercial No Derivatives 3.0 IGO"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-ND-4.0",
         r#"Creative Commons Attribution Non Commercial No Derivatives 4.0 International"#,
-        0x0,
+        IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-1.0",
         r#"Creative Commons Attribution Non Commercial Share Alike 1.0 Generic"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-2.0",
         r#"Creative Commons Attribution Non Commercial Share Alike 2.0 Generic"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-2.0-DE",
         r#"Creative Commons Attribution Non Commercial Share Alike 2.0 Germany"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-2.0-FR",
         r#"Creative Commons Attribution-NonCommercial-ShareAlike 2.0 France"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-2.0-UK",
         r#"Creative Commons Attribution Non Commercial Share Alike 2.0 England and Wales"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-2.5",
         r#"Creative Commons Attribution Non Commercial Share Alike 2.5 Generic"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-3.0",
         r#"Creative Commons Attribution Non Commercial Share Alike 3.0 Unported"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-3.0-DE",
         r#"Creative Commons Attribution Non Commercial Share Alike 3.0 Germany"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-3.0-IGO",
         r#"Creative Commons Attribution Non Commercial Share Alike 3.0 IGO"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-NC-SA-4.0",
         r#"Creative Commons Attribution Non Commercial Share Alike 4.0 International"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC | IS_NON_COMMERCIAL,
     ),
     (
         "CC-BY-ND-1.0",
         r#"Creative Commons Attribution No Derivatives 1.0 Generic"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-ND-2.0",
         r#"Creative Commons Attribution No Derivatives 2.0 Generic"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-ND-2.5",
         r#"Creative Commons Attribution No Derivatives 2.5 Generic"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-ND-3.0",
         r#"Creative Commons Attribution No Derivatives 3.0 Unported"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-ND-3.0-DE",
         r#"Creative Commons Attribution No Derivatives 3.0 Germany"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-ND-4.0",
         r#"Creative Commons Attribution No Derivatives 4.0 International"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC-BY-SA-1.0",
         r#"Creative Commons Attribution Share Alike 1.0 Generic"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-2.0",
         r#"Creative Commons Attribution Share Alike 2.0 Generic"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-2.0-UK",
         r#"Creative Commons Attribution Share Alike 2.0 England and Wales"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-2.1-JP",
         r#"Creative Commons Attribution Share Alike 2.1 Japan"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-2.5",
         r#"Creative Commons Attribution Share Alike 2.5 Generic"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-3.0",
         r#"Creative Commons Attribution Share Alike 3.0 Unported"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-3.0-AT",
         r#"Creative Commons Attribution Share Alike 3.0 Austria"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-3.0-DE",
         r#"Creative Commons Attribution Share Alike 3.0 Germany"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-3.0-IGO",
         r#"Creative Commons Attribution-ShareAlike 3.0 IGO"#,
-        IS_COPYLEFT,
+        IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-BY-SA-4.0",
         r#"Creative Commons Attribution Share Alike 4.0 International"#,
-        IS_FSF_LIBRE | IS_COPYLEFT,
+        IS_FSF_LIBRE | IS_COPYLEFT | IS_CC,
     ),
     (
         "CC-PDDC",
         r#"Creative Commons Public Domain Dedication and Certification"#,
-        0x0,
+        IS_CC | IS_PUBLIC_DOMAIN_EQUIVALENT,
     ),
     (
         "CC-PDM-1.0",
         r#"Creative    Commons Public Domain Mark 1.0 Universal"#,
-        0x0,
+        IS_CC | IS_PUBLIC_DOMAIN_EQUIVALENT,
     ),
     (
         "CC-SA-1.0",
         r#"Creative Commons Share Alike 1.0 Generic"#,
-        0x0,
+        IS_CC,
     ),
     (
         "CC0-1.0",
         r#"Creative Commons Zero v1.0 Universal"#,
-        IS_FSF_LIBRE,
+        IS_FSF_LIBRE | IS_CC | IS_PUBLIC_DOMAIN_EQUIVALENT,
     ),
     (
         "CDDL-1.0",
@@ -1523,8 +1535,16 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
         IS_OSI_APPROVED,
     ),
     ("NBPL-1.0", r#"Net Boolean Public License v1"#, 0x0),
-    ("NCBI-PD", r#"NCBI Public Domain Notice"#, 0x0),
-    ("NCGL-UK-2.0", r#"Non-Commercial Government Licence"#, 0x0),
+    (
+        "NCBI-PD",
+        r#"NCBI Public Domain Notice"#,
+        IS_PUBLIC_DOMAIN_EQUIVALENT,
+    ),
+    (
+        "NCGL-UK-2.0",
+        r#"Non-Commercial Government Licence"#,
+        IS_NON_COMMERCIAL,
+    ),
     ("NCL", r#"NCL Source Code License"#, 0x0),
     (
         "NCSA",
@@ -1537,7 +1557,11 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
         r#"NICTA Public Software License, Version 1.0"#,
         0x0,
     ),
-    ("NIST-PD", r#"NIST Public Domain Notice"#, 0x0),
+    (
+        "NIST-PD",
+        r#"NIST Public Domain Notice"#,
+        IS_PUBLIC_DOMAIN_EQUIVALENT,
+    ),
     (
         "NIST-PD-fallback",
         r#"NIST Public Domain Notice with license fallback"#,
@@ -1725,7 +1749,7 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
     (
         "PDDL-1.0",
         r#"Open Data Commons Public Domain Dedication & License 1.0"#,
-        0x0,
+        IS_PUBLIC_DOMAIN_EQUIVALENT,
     ),
     ("PHP-3.0", r#"PHP License v3.0"#, IS_OSI_APPROVED),
     (
@@ -1746,7 +1770,7 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
     (
         "PolyForm-Noncommercial-1.0.0",
         r#"PolyForm Noncommercial License 1.0.0"#,
-        0x0,
+        IS_NON_COMMERCIAL,
     ),
     (
         "PolyForm-Small-Business-1.0.0",
@@ -1796,8 +1820,16 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
     ("Rdisc", r#"Rdisc License"#, 0x0),
     ("Ruby", r#"Ruby License"#, IS_FSF_LIBRE),
     ("Ruby-pty", r#"Ruby pty extension license"#, 0x0),
-    ("SAX-PD", r#"Sax Public Domain Notice"#, 0x0),
-    ("SAX-PD-2.0", r#"Sax Public Domain Notice 2.0"#, 0x0),
+    (
+        "SAX-PD",
+        r#"Sax Public Domain Notice"#,
+        IS_PUBLIC_DOMAIN_EQUIVALENT,
+    ),
+    (
+        "SAX-PD-2.0",
+        r#"Sax Public Domain Notice 2.0"#,
+        IS_PUBLIC_DOMAIN_EQUIVALENT,
+    ),
     ("SCEA", r#"SCEA Shared Source License"#, 0x0),
     ("SGI-B-1.0", r#"SGI Free Software License B v1.0"#, 0x0),
     ("SGI-B-1.1", r#"SGI Free Software License B v1.1"#, 0x0),
@@ -1938,7 +1970,7 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
     (
         "Unlicense",
         r#"The Unlicense"#,
-        IS_OSI_APPROVED | IS_FSF_LIBRE,
+        IS_OSI_APPROVED | IS_FSF_LIBRE | IS_PUBLIC_DOMAIN_EQUIVALENT,
     ),
     ("VOSTROM", r#"VOSTROM Public License for Open Source"#, 0x0),
     (
@@ -2021,7 +2053,11 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
         r#"bcrypt Solar Designer License"#,
         0x0,
     ),
-    ("blessing", r#"SQLite Blessing"#, 0x0),
+    (
+        "blessing",
+        r#"SQLite Blessing"#,
+        IS_PUBLIC_DOMAIN_EQUIVALENT,
+    ),
     (
         "bzip2-1.0.5",
         r#"bzip2 and libbzip2 License v1.0.5"#,
@@ -2109,41 +2145,804 @@ pub const LICENSES: &[(&str, &str, u8)] = &[
     ),
 ];
 
+/// The length in bytes, and 64-bit FNV-1a hash, of the SPDX text of each
+/// license in [`LICENSES`], or `(0, 0)` if the license has no text
+pub const LICENSE_TEXT_INFO: &[(u32, u64)] = &[
+    (643, 0x8c42bf4698cbf8e3),
+    (10299, 0x29dd7591cba9fd5d),
+    (2529, 0xda0bafc85b5afc52),
+    (488, 0x46d445cbf15a872a),
+    (4676, 0x9c726c18c8692e6e),
+    (4950, 0xa7e3d498dc207ad6),
+    (8987, 0x8921d1d67ae585eb),
+    (8948, 0x391ba806c9249103),
+    (10334, 0xb82d2e1b7c57f6c8),
+    (15839, 0xae4b1de9217c4f00),
+    (15839, 0xae4b1de9217c4f00),
+    (15839, 0xae4b1de9217c4f00),
+    (34020, 0x34cfe474e58810dd),
+    (34020, 0x34cfe474e58810dd),
+    (34020, 0x34cfe474e58810dd),
+    (545, 0x371b1d3b4a358694),
+    (5642, 0x095ae6aabddb8b34),
+    (2358, 0xd8cf9cfd7305b199),
+    (2373, 0xc88040be9ed994dc),
+    (2192, 0xf7d6564964c7dbc9),
+    (987, 0x3a67da56091251e3),
+    (1261, 0xceab601d79f6c7f9),
+    (617, 0x62a658712c18d783),
+    (46064, 0xa03cb87fb0bd5946),
+    (19644, 0xfe6cdc9115c96278),
+    (20151, 0xa8cf7027080278df),
+    (19800, 0x4ec81a14a8fff157),
+    (20285, 0xa8dc8f5c966435df),
+    (2230, 0xec4287037c764a7d),
+    (2346, 0xe1bd9df649196d5f),
+    (731, 0xd5a6fd2f68176ac3),
+    (236, 0x8be5ed777e735fe0),
+    (1776, 0xb5fb3e84d651c694),
+    (1883, 0x560fd4a2c9ed9e3a),
+    (1608, 0x784103d69c6484fb),
+    (481, 0x74e5f640f0fc6a2d),
+    (893, 0xfed369dfa8ffbb9a),
+    (11604, 0xd3e0b78ef323c126),
+    (2507, 0xd353c7053223ec67),
+    (2474, 0xf16ff68e5bb0817b),
+    (10280, 0xa0173a7041e58fd6),
+    (224, 0xa05f07a1357d4237),
+    (6901, 0x8cdfb19891fe299c),
+    (4854, 0xcdda373bdc04a8e4),
+    (6060, 0x583bb5b11b7efdaa),
+    (5184, 0xebe75f71a24e1b34),
+    (8764, 0x2acff5f33c77f91b),
+    (1086, 0x3466b0d490600ded),
+    (1267, 0x0f2df934d747df21),
+    (1593, 0xf45aa2d484de4652),
+    (1518, 0x3ffd68cacc38b084),
+    (1383, 0xbd4fe837f9b6a1d0),
+    (2569, 0xaf27c16a9dfa5e70),
+    (1519, 0x3a8398e2a186cf38),
+    (1329, 0xe6c5d1057e4db9e7),
+    (1460, 0x4e31aa09d78a02b2),
+    (1646, 0x7224157ddafba025),
+    (1676, 0x4480ef2525db647e),
+    (1393, 0x53dbdb6ac5e89fae),
+    (2388, 0x47b5c661b8d3c852),
+    (1624, 0xc12e6baf899e7878),
+    (1647, 0x3e0d3d9c0d30943d),
+    (1693, 0x706e905cc3b4e746),
+    (1709, 0xa592a9e50aed237a),
+    (1699, 0xdd453b9d4c4bc59c),
+    (1820, 0x17f1d65952a97072),
+    (1620, 0xea2575237f9951f0),
+    (1568, 0xeb2045f2321b9367),
+    (1855, 0x2ff0887fa11e483c),
+    (1618, 0xd3d4e610eaf39f1a),
+    (1048, 0x9fdfa9f04b0ded15),
+    (1771, 0xa18511c5b495118d),
+    (476, 0x9ecc7a99ff39155e),
+    (781, 0x9f9c68d27c0da8ea),
+    (1626, 0x79e92747055eb063),
+    (1679, 0x181b1e071d5c62eb),
+    (2085, 0x39f79de09918a98b),
+    (6244, 0xbf266853a262c820),
+    (1301, 0xcd43ef72605dd3e8),
+    (1284, 0x0c151780ac6c8dbd),
+    (2225, 0x50ab29d2a67c3917),
+    (3180, 0xfe835edb7c56d36d),
+    (1338, 0x6dbacae96b9acf63),
+    (3425, 0xfa4657e31e3f077a),
+    (479, 0xaca71762c7b99a7a),
+    (1377, 0x2e3e65ddfb23cdbf),
+    (603, 0xcea7f8208cf3d9ab),
+    (266, 0x5f352cde67152a78),
+    (24219, 0xc49ae381b0ff6c73),
+    (27782, 0x26319f3966d19cb7),
+    (572, 0x98f063b08a571186),
+    (2318, 0x3e99784089629e84),
+    (1552, 0x0d256a323d391b5e),
+    (506, 0x9aa567267f515f08),
+    (586, 0x2b2a72b52657b478),
+    (647, 0x83025f4280067e5b),
+    (655, 0x8fa4a53cdd9555d4),
+    (1096, 0xd7e0a4f7dcd0eb74),
+    (3451, 0x7d70999d50ffdbb3),
+    (16125, 0xee0a71332ada4cf8),
+    (16125, 0xee0a71332ada4cf8),
+    (19046, 0xf915164643e39419),
+    (11405, 0x618186203ade67bf),
+    (12572, 0x7fca0c44b3c28e79),
+    (12781, 0xd29525cfa7370358),
+    (17126, 0xb4af189410e3ae4e),
+    (19467, 0x5f12f1c3859f5ea3),
+    (19189, 0x72bf167da8d629c1),
+    (15262, 0xfb3ec3647bebca95),
+    (19219, 0xe7b0effa4feb89b1),
+    (17572, 0xa41986ee5901455b),
+    (18371, 0x20455ccfc709a9c2),
+    (14654, 0xaa2f6b86c4ae620d),
+    (17023, 0x8064c33877af3030),
+    (11902, 0x3b4079a193f33505),
+    (13585, 0x853355d8e5509474),
+    (13790, 0xfa99b786c9f2c4f9),
+    (20479, 0x751006c69a0b14ca),
+    (20434, 0x2bc9dece8efec6a3),
+    (17649, 0x9b037a9a8faff7ea),
+    (11032, 0x9b519109350c0fe5),
+    (12711, 0xefc3ee6ce2337209),
+    (12936, 0x6aa9a81c0d458224),
+    (18650, 0xf90f01d2a7c8dea7),
+    (19469, 0x64c92b5b783e8d84),
+    (17672, 0x7519d10869f431ef),
+    (17452, 0xa0c5148fa74619cb),
+    (13065, 0x9e748bd7a8253483),
+    (15177, 0x757358ef130aaed8),
+    (15676, 0x9fa4bb66f3054a1a),
+    (18798, 0x5639c1f417c9a7a6),
+    (11347, 0xa7cd97398f476bb8),
+    (15386, 0x3a3e20f32ab25c3c),
+    (22306, 0x4edcb368ff2a1682),
+    (22415, 0x36628f3929d7b725),
+    (20330, 0x7f87e21670a2f7c6),
+    (19018, 0xa3a0be003968e8bc),
+    (10488, 0x3cc9eed5cc4085b5),
+    (11696, 0x10ef002de3e7eeb4),
+    (11921, 0x1d3a3d4644bdd038),
+    (17650, 0x8404980fc9abe670),
+    (18247, 0x41e5b4bda7a2fa7d),
+    (16830, 0x45554618d55b7194),
+    (12523, 0x8ab3a1fde627dfd4),
+    (14122, 0x22745397a9e1e1ef),
+    (10810, 0x5218bfacf0c099e1),
+    (16303, 0x52ad4bded4f61740),
+    (14331, 0xd402c08c5848b805),
+    (22240, 0xfb87743a9b50d4d5),
+    (22343, 0x87f14608ec006376),
+    (22422, 0x180385606b5297dc),
+    (20189, 0x74b943a0e14c5869),
+    (18375, 0x8c40366f70ada13f),
+    (1593, 0x15a49e4d9a4d607a),
+    (1111, 0x45ab9fd08466844d),
+    (11937, 0x1be3f9f1028bda59),
+    (7048, 0xf92ec4039367c961),
+    (16514, 0x007b7214a277d03d),
+    (17390, 0x5db315a26402b5ef),
+    (8592, 0x9487fe1a51c54f64),
+    (10462, 0xcaba304e9c5a9334),
+    (2354, 0x3ad92323ea4a9096),
+    (11233, 0x39e4b6255d86f4d3),
+    (21842, 0x5dbebed649681d94),
+    (21219, 0xf7c231a4f03f6efa),
+    (21097, 0x030ed3b543dcfc7b),
+    (21778, 0x9e0203fd129b8f0e),
+    (21393, 0xc6594b2dd0207a49),
+    (21863, 0x9cb55af216b427a7),
+    (8114, 0x8d7ba04f6868d690),
+    (9169, 0xf60697749a34bc54),
+    (8855, 0x8662ee30b5f545f4),
+    (13419, 0xa1a162f475a6f0fe),
+    (14529, 0x939fb12a89003a1b),
+    (1408, 0x7dd38b76b71ef169),
+    (938, 0x93006ac22cb2ab78),
+    (499, 0x2b26982588f9c9f6),
+    (3923, 0x5737e9dc1ce0983f),
+    (3381, 0xc450e694809cc427),
+    (4004, 0xa7814f868e3962cd),
+    (1657, 0x94a14ee6005c93e6),
+    (28319, 0x9418493fea89f883),
+    (11653, 0x8e634ef3e1b10a56),
+    (11649, 0xb1965fcdf711785f),
+    (23381, 0x89239007c9b58157),
+    (2577, 0x9030bd42c33b3ece),
+    (1752, 0x8cd67a1e926f100d),
+    (5178, 0xca62a655cf67a1e3),
+    (6389, 0xb59a3970af496b5f),
+    (938, 0xef92a1bb7a40410f),
+    (15885, 0x29cc43c8bcf6a344),
+    (6058, 0xbd5e109cacc5fa99),
+    (971, 0x5f02b406e7aa9bc8),
+    (595, 0x7f57eaa2d86170a0),
+    (479, 0x7c1a2b2245c45503),
+    (982, 0x7f28111c011e88ed),
+    (1078, 0x588f616889fa8760),
+    (15238, 0x405be4c7733c8837),
+    (1530, 0xab964601672f5773),
+    (2518, 0x1f22060410115eb2),
+    (1210, 0x18ac0bbfe913f7c1),
+    (4472, 0x4fbced79f2e34ef9),
+    (1512, 0x9348008eda5ac22b),
+    (1883, 0xa94c7f090cb98792),
+    (2280, 0x5cf17e01973512ec),
+    (1042, 0x0c8b9c2e78cade98),
+    (615, 0x78c188b6cdfe9826),
+    (1999, 0x19e8f1993cb61f89),
+    (261, 0xf366fc407ca01c83),
+    (2425, 0x4a821a779659f017),
+    (11111, 0x4ab937f035fe95b1),
+    (919, 0xc5ab43b0b8e6ca0b),
+    (924, 0x518c79b0fbf8e2c1),
+    (3439, 0xf6e28e1fcf257c1e),
+    (11345, 0x0603d0e438989530),
+    (14020, 0x17a20d0873ca9b83),
+    (3199, 0x7f9bd5795eb0e468),
+    (13005, 0xe7188224fbab7506),
+    (13231, 0xb9a57c95ed43367f),
+    (13792, 0x7a2ef47ade5d8416),
+    (3802, 0x8cc778cc7ad45ae3),
+    (2277, 0xc22d5e1dd27534c9),
+    (13958, 0x4af74a9b7c1d6c75),
+    (1367, 0x52171e19e9977f32),
+    (330, 0xc399911f363227e0),
+    (4447, 0xf13677d11f2780ef),
+    (225, 0xcbcca270b4e390c7),
+    (209, 0x5111897a61baad08),
+    (198, 0xb5853b534f24969f),
+    (234, 0xec94d7f9af1faa6c),
+    (544, 0x14a256ec49a4d4a2),
+    (5979, 0xfcc60384e96ed3d5),
+    (245, 0xbf48f4c3e128427c),
+    (806, 0xb90b499265ef5bdb),
+    (9771, 0x1a072ae57b3b95b2),
+    (2781, 0x66d7fc3a4d4e95bb),
+    (17656, 0x3ea472ab40921e97),
+    (626, 0xe33ff7eb6d01043f),
+    (1697, 0x041f187c88eb063d),
+    (2983, 0x214ca4401b82b694),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (17970, 0x17ff8e847da656fc),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (20272, 0x3c0b5c1856097f8f),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (22791, 0x48ba666e95b4cda3),
+    (896, 0x7a0df2e19e6c82d8),
+    (959, 0x3fefa4b56ebe6a32),
+    (12235, 0x787b6d0b5456f25d),
+    (12246, 0x915c0afb89dcf9b5),
+    (12235, 0x787b6d0b5456f25d),
+    (12235, 0x787b6d0b5456f25d),
+    (17337, 0x975114567222e92d),
+    (17382, 0x97612c50535890a7),
+    (17337, 0x975114567222e92d),
+    (17337, 0x975114567222e92d),
+    (538, 0x7f1de28de3a83aa2),
+    (1627, 0x91e0124ce48d8425),
+    (673, 0x33ab1081c612520c),
+    (975, 0x29b46c7ccf8601c6),
+    (632, 0xae1a9e28b118279e),
+    (34674, 0x6a20739025ee8ecf),
+    (34683, 0xd0ee45e06655c215),
+    (34674, 0x6a20739025ee8ecf),
+    (34674, 0x6a20739025ee8ecf),
+    (3446, 0x59037b9a7589e34e),
+    (1872, 0x20b206753f5af103),
+    (1352, 0x0695a50d974b9581),
+    (12253, 0xb2770ff19c304f43),
+    (462, 0xab655e1b8fe74216),
+    (707, 0xbb8c3097d2f54734),
+    (96, 0xb48c13e9f6adb203),
+    (119, 0x1833b1530aa15b0f),
+    (617, 0x19aafed6f6863f76),
+    (947, 0x9fe5e0199d215d2a),
+    (1187, 0xccb627fc35ed9a06),
+    (1034, 0x529118ab4b6af6c8),
+    (700, 0xa9d615a2574a5534),
+    (553, 0x12ae56b48ecc016d),
+    (1419, 0xa8a75a101d370209),
+    (421, 0xe4cb313903d3cc02),
+    (950, 0x8dfbd4090dcbd7fb),
+    (179, 0x15e0ef862200e3e7),
+    (496, 0x0f341b4bc032bf6a),
+    (406, 0xc1a1dcb1ecb69043),
+    (425, 0x32a78c745d511054),
+    (533, 0x85c920ada98ada4c),
+    (443, 0x778e1ca116d3b23e),
+    (442, 0x2e371b8ffc5a8835),
+    (944, 0x5255f8a7155d9f89),
+    (1184, 0x60d30f12273ff678),
+    (1272, 0xba72476604f1138b),
+    (1136, 0x8a4f2ba3e84202b4),
+    (385, 0xb9604b94daf22fd6),
+    (748, 0xa6f05094b5128ee6),
+    (353, 0x04a2f07ae8618157),
+    (1102, 0xe10facaf14e6f27d),
+    (891, 0x24dd4bb933f156c7),
+    (763, 0x677effd20dfa13f3),
+    (1460, 0x1427e834e94aa7a3),
+    (602, 0x2950772b16103b68),
+    (8422, 0xef3847adc58252a9),
+    (849, 0xf80f2a0edafc5c09),
+    (1597, 0x1bc5eec89ff09077),
+    (2330, 0x6bec0a34cd734f51),
+    (4244, 0x63b505dfa90d4a48),
+    (1640, 0xbd592a9599ab2a41),
+    (9093, 0x85fba1d317d62d57),
+    (11409, 0x8f6bf852aa1c69a6),
+    (823, 0x224ac21e84bea375),
+    (522, 0x67225f1296fc22c4),
+    (12506, 0xdee9c3eb761cc6d4),
+    (2002, 0x7ac41567ef1b150f),
+    (3171, 0x6e2ed434f0b602ae),
+    (1996, 0xc941f3868b5673ab),
+    (1424, 0xe98e3067d3a45f39),
+    (2084, 0xd18f1c7130c8ffef),
+    (6132, 0x07898b51ec641668),
+    (24344, 0xe406e56f0039e2cd),
+    (3158, 0xb946ade778212811),
+    (2086, 0xaed06ba7b36a5edc),
+    (1114, 0xe7622011b9396c28),
+    (195, 0x05497be1aeb43c4b),
+    (2741, 0x8bbc60594754e352),
+    (128, 0xf3ba38c0d022b376),
+    (683, 0xf027f4ee06506bab),
+    (296, 0xa3dbf70c00b90838),
+    (6972, 0x27f25aceb6c48a90),
+    (8264, 0xcaad3dadc4882514),
+    (24877, 0x787e920744576b4b),
+    (24962, 0x248f23ea2efbe3d9),
+    (24877, 0x787e920744576b4b),
+    (24877, 0x787e920744576b4b),
+    (26001, 0x1faf145ab890c30c),
+    (26089, 0x9f6000f82438d254),
+    (26001, 0x1faf145ab890c30c),
+    (26001, 0x1faf145ab890c30c),
+    (42098, 0x37c539b4573fe3aa),
+    (42201, 0x516a050e737b7605),
+    (42098, 0x37c539b4573fe3aa),
+    (42098, 0x37c539b4573fe3aa),
+    (14556, 0x24e303352cd18fc2),
+    (2054, 0x264ed641822f09ae),
+    (363, 0x2716b05b65950c68),
+    (11948, 0x82a868c50ba56463),
+    (11824, 0x9970fbef666d2f58),
+    (8929, 0x9d073d1969b8c9fc),
+    (14026, 0x6c66a4db9f26970f),
+    (14115, 0x676202ec3e0c1280),
+    (18012, 0xb5fa5072fbd7e994),
+    (18575, 0x0d20e512a18a7377),
+    (240, 0x13fd379645dcbd53),
+    (582, 0xa86c123a21e07781),
+    (704, 0xfda1c672bfa6fbe2),
+    (981, 0x5c2a7ba7ec13d166),
+    (734, 0xb6f6b0e80b78417b),
+    (6590, 0xc696a256e9867faf),
+    (8694, 0x7c0921a9febcc749),
+    (8338, 0xdb9932c9429c0c07),
+    (4218, 0x66edd671b364f93f),
+    (939, 0x2e53dee5e5319b55),
+    (215, 0xa3bd5c5a8e593af5),
+    (1019, 0x67a1632e6f98eefa),
+    (409, 0x1c5996dea01c9bd9),
+    (808, 0x2d581c04ee8c4366),
+    (2868, 0x7e15db10e3163b6a),
+    (192, 0x3e82e1200ffe292c),
+    (1087, 0xd45f308a2255eb62),
+    (915, 0x620b48d94306c23c),
+    (1150, 0xd3047d5077a69215),
+    (1695, 0x9850dc0d79d209cc),
+    (1228, 0xcd5921145627ae8c),
+    (1305, 0x9272789b3e74e754),
+    (917, 0x5ffa828a5bd310f2),
+    (1295, 0x218bc1ad2c188f18),
+    (1270, 0xa7e96d9fcd36364e),
+    (1668, 0xeea4ffdbab189db7),
+    (1105, 0x520dcd3f6b4a65a1),
+    (1121, 0xcbb1a1ba03b583c3),
+    (1134, 0x7a2406cebc3792ff),
+    (1531, 0x950a055d8eecc650),
+    (865, 0x9cd4ca4ebcc9b8ec),
+    (1128, 0xf3e1770545a982ea),
+    (18272, 0xf2063ec842aa9c4c),
+    (23669, 0xa384c267e5d71992),
+    (16727, 0xe25bbc612384eab6),
+    (16727, 0xe25bbc612384eab6),
+    (2870, 0x7de7b92b4a1b9c5d),
+    (2663, 0x2203777bf611ddfb),
+    (3058, 0x9a7013bd9ceed037),
+    (3239, 0x3e5014d9d2f86fb2),
+    (1240, 0xb171ecded0c730c8),
+    (1144, 0x7e92292ffb9e901f),
+    (1974, 0x5ecf1cc066b86021),
+    (407, 0x16f637b4eef0c05f),
+    (323, 0x3d6a4a310c8c93d1),
+    (2193, 0xdf6607c1afb40004),
+    (888, 0x5d1fffecbe221542),
+    (26398, 0x49bb382d0970b421),
+    (8908, 0xa87aa060e8d7851f),
+    (9234, 0x802d21f4f1796718),
+    (2040, 0xddcd76410c87748c),
+    (1459, 0xcc1ff90c4b3ce069),
+    (3792, 0x9aeacb0c5be19148),
+    (13778, 0xec421c3e667f4633),
+    (5425, 0x1261e6c8ba296afa),
+    (964, 0x4c4f58a0607b425f),
+    (5536, 0x6064fd350e71879a),
+    (1508, 0x67068a4835a7e808),
+    (1700, 0x125c4f27973edcb6),
+    (4703, 0x00af0fa1cf5d98f5),
+    (2977, 0xd12827313c761437),
+    (890, 0x1a5da7a4f9792649),
+    (1644, 0x87eee679679c2c75),
+    (1852, 0xa294311effc3d027),
+    (8712, 0xf89da3bcc90ffcf1),
+    (8810, 0x297dfb22b8f81639),
+    (268, 0x5a155c469d46f14f),
+    (0, 0x0000000000000000),
+    (24490, 0x725076ad7a03c4f2),
+    (21054, 0xd5157d608453a8e4),
+    (28009, 0x7bc128ff5d3ae8ff),
+    (11800, 0xd991d5214cc03aff),
+    (3298, 0x9f988b0a707d0bdd),
+    (714, 0x13fa6a8a8ad3fd2b),
+    (528, 0x4b89832b2e0ec7d9),
+    (1953, 0xb5f09ede4a2831e6),
+    (14237, 0xa092b79326c0a0b7),
+    (1881, 0x061bfcda7fed5719),
+    (510, 0xcc8cd601b665fea9),
+    (21003, 0x0fb672f5fc77224b),
+    (1126, 0xcf89100fabe56490),
+    (1075, 0x3459d9f8b10fd7cd),
+    (3434, 0x317e77465cf7df3a),
+    (577, 0x3387d5d22ab1e84e),
+    (14008, 0x882ede43105af240),
+    (11124, 0xf9d26ee6a4c1fe2c),
+    (19808, 0x6b9e16f5de7f71b8),
+    (25289, 0x0e1e937445b4968b),
+    (1007, 0xe58a19dc03045598),
+    (3858, 0xc6b8616c05f1875b),
+    (3858, 0xc6b8616c05f1875b),
+    (3858, 0xc6b8616c05f1875b),
+    (4016, 0xcdf77f181f5c0400),
+    (4016, 0xcdf77f181f5c0400),
+    (4016, 0xcdf77f181f5c0400),
+    (2179, 0x06aecb6531846db3),
+    (11606, 0xb04ec68abacceb65),
+    (3679, 0x2367f062e68c719f),
+    (6059, 0x5b8a60fb8ad3e41e),
+    (5980, 0x410d1a3bdb36b854),
+    (6220, 0x80f91a2cfa1237a1),
+    (5277, 0xe76870cabf9a8505),
+    (5393, 0x4a33c6e15698352a),
+    (5396, 0x44714797724b8227),
+    (5795, 0x3eacd5a564cb0572),
+    (5873, 0xc95d714a569ddd0c),
+    (1981, 0x624fadf4e2a169a5),
+    (1977, 0xf4fd1ada55b21cab),
+    (2220, 0x3c31cac854ba2232),
+    (2228, 0x967f5284c8f8ecb2),
+    (2241, 0x9328dd117c4c3475),
+    (2267, 0xa6415ad818f65abb),
+    (2265, 0xbd5bc5d734863238),
+    (2124, 0x94d43a839f9d440a),
+    (2171, 0xe742cef77b3a69df),
+    (2109, 0x92c8997fe76ae609),
+    (2197, 0xf85fdc8d73ed12cf),
+    (2195, 0x8d79c1ddc9d45b9c),
+    (11413, 0x02acda5359482688),
+    (1689, 0x8121408efa70b55a),
+    (20309, 0xfe5cc9ee4b6b61f7),
+    (4469, 0x6780a0665a41bfb2),
+    (6148, 0x69ae10e6105b2b76),
+    (19962, 0x84f45b6f572262dc),
+    (8921, 0x2d182f0024065c52),
+    (9663, 0xc8b4304c69f1c362),
+    (9880, 0xdcbb21dc6e3f227b),
+    (9872, 0x25a08a60944abcaa),
+    (10319, 0x26821f9bf0ef1f6f),
+    (3963, 0xcd36f4d6780c8b67),
+    (5309, 0x4d60fdc46c7bf627),
+    (2502, 0x7bfaa4c46df64a0f),
+    (1680, 0xa8f334c6d47b9d0e),
+    (314, 0x1d729f567dce3487),
+    (15485, 0xadb2930d7c3e9971),
+    (2846, 0x921ce7e8238c1890),
+    (2855, 0xde4cae0c5ff0b946),
+    (18880, 0xfd38dc154d0ee347),
+    (2427, 0x087544959b277c62),
+    (1704, 0x3b2c5f8326eff373),
+    (3524, 0x718c862157e6870b),
+    (10039, 0xc58014050fbaae26),
+    (1849, 0x4095cc4c956719c0),
+    (4563, 0x23a05bf1ec835a41),
+    (4389, 0x81edf1c678a5edd6),
+    (1195, 0xa7fd06bbd4e1d0be),
+    (9411, 0xc21babcee852bd1d),
+    (9916, 0xa9221ede70676ee0),
+    (4364, 0x8398b099157d2eb2),
+    (4641, 0x7f3b083841979e32),
+    (1304, 0xeff6edb361190648),
+    (21641, 0x15056bb13949dc05),
+    (33931, 0xd311922406dd2fa6),
+    (32009, 0x378758183a464ebf),
+    (30271, 0x9c1e26ba5859da52),
+    (869, 0xcede38a48e38ee5d),
+    (21050, 0x76ae4a1caf6fc016),
+    (1107, 0xde3d38568392548f),
+    (2137, 0xca08c3d283e90f73),
+    (497, 0x53f4be0413529656),
+    (2315, 0xcd9c020f772acdce),
+    (338, 0xe743ceec1cb873c0),
+    (6638, 0xff8c20c62fdc0bb9),
+    (13553, 0x7edb43b7f998007f),
+    (14556, 0x57d720ee8955f40b),
+    (1503, 0x3e5e974d8fcc373f),
+    (1843, 0x9ce3d55032f5b2a6),
+    (347, 0x5c69b8664fd91ec6),
+    (11386, 0x17b14a16e9935359),
+    (11399, 0x9629ffa3ea698568),
+    (14490, 0x83a54732484b8f79),
+    (12475, 0x26b403545871e555),
+    (275, 0x891cc2f34417a672),
+    (7386, 0xb2ca25380e46a29d),
+    (1122, 0xf73abade29f5f6cc),
+    (3177, 0x08bacdf5f203e30b),
+    (21717, 0xe17548ae621cff9b),
+    (23398, 0xa7da502b442bed93),
+    (3399, 0x70b01647428dfecf),
+    (326, 0xc76be6d0340f384f),
+    (3154, 0x0846d2870313b711),
+    (30609, 0x4faf23b0de37c901),
+    (2129, 0x913977279e4410be),
+    (2133, 0xbf0c0d30b370548c),
+    (290, 0x926d09735b70b6be),
+    (3948, 0x2238f9cf9ff6310e),
+    (4020, 0x9efb9abd31d57b80),
+    (4317, 0x6114d729e87a53c2),
+    (2529, 0x37dea975a553f358),
+    (4995, 0x01a8c7739992c768),
+    (404, 0xe565b0395c22efbb),
+    (628, 0xac35db56d2c11999),
+    (935, 0x69d3a7cc2ed09175),
+    (1376, 0xab4bf8c525092060),
+    (1123, 0xba42edefb618fc24),
+    (22087, 0xd56cd1aadb53e095),
+    (616, 0x626f49d1183fefd4),
+    (614, 0x448ed87b31c5a10e),
+    (246, 0x2b0d32901828581a),
+    (515, 0xceaa1e4bc5a19235),
+    (13932, 0xb6526605822bb4c4),
+    (2251, 0x97ef9159c93a76c0),
+    (648, 0xc9991657181621ad),
+    (10971, 0xd8121d0a8b64624a),
+    (2537, 0x683ed8318cb7066a),
+    (3998, 0xbdd421a3941c1ed5),
+    (2074, 0xe5cf9689655766fe),
+    (233, 0x421ff030b54920fe),
+    (23787, 0x8d01e74d921e42b1),
+    (406, 0xe7e317ea4a342756),
+    (1522, 0x0fc183a9ec1aec99),
+    (678, 0x56f8b8539fd29f55),
+    (1369, 0x99371ea6a96b8e94),
+    (103, 0x5f16c2e845eee8be),
+    (271, 0x88e98338bcfd1396),
+    (2446, 0x5a1264417e08fa18),
+    (1948, 0xd8c217a3bf882137),
+    (10566, 0xd0dda160661d492b),
+    (1164, 0x346e8afcb8b0168b),
+    (1837, 0xcc2b5323d467ef0d),
+    (771, 0xa169196641c71ae5),
+    (4673, 0x780f17b6b319b441),
+    (1995, 0xf2512ccce5ac1e0a),
+    (2873, 0x83ebbb401bd11149),
+    (2858, 0xa26bad46c5651a9f),
+    (6261, 0x0c01c0e5744df1c0),
+    (250, 0x91a8b86140f25d56),
+    (1211, 0x7ea4b86f87e6e2dc),
+    (3058, 0x70c7fa754d7aa7f1),
+    (2065, 0xafd31eab8700e1f8),
+    (4525, 0xb086e881c5bd384e),
+    (2701, 0xe6b821c0f2f0beb9),
+    (3115, 0x57fdf603d6493adc),
+    (1807, 0x5484ca561ff67557),
+    (432, 0x11af72c9fbb05adf),
+    (20972, 0x32d452222d7339c5),
+    (1270, 0x154fcf41761ad073),
+    (529, 0x05888810d2ba8259),
+    (1338, 0xbc137a8dfb27a029),
+    (1314, 0x8a60ebf8fb26fe36),
+    (1312, 0xbe35a0caa656e703),
+    (2398, 0x08be0f79b425f9ef),
+    (535, 0x147c690f566ecdde),
+    (3027, 0xf53b512645070669),
+    (963, 0x725932b8de24c0e5),
+    (510, 0x999f42417da560d4),
+    (1250, 0xd3d3d9698b8cc67d),
+    (9010, 0x349f4b68ef299363),
+    (8988, 0xcf62af192e733e75),
+    (2924, 0x24ba8ea8a0a01af9),
+    (2275, 0xf00fb6a5ac6515f2),
+    (2100, 0xd9605d90d6874ca0),
+    (259, 0x25a5132c871df131),
+    (187, 0x634a17f629da81db),
+    (2490, 0xdae32a4e2ecb5949),
+    (8994, 0x89403cff401360f6),
+    (8947, 0x9961827f017a4c95),
+    (838, 0x151f3c3987fa637a),
+    (93, 0x986fdacf6e2f99c0),
+    (627, 0x79115e7e9f85ce80),
+    (543, 0x8fad03da4d23b3dc),
+    (244, 0xfcfb26b9b101f1f5),
+    (1947, 0x374f81152384828a),
+    (1739, 0x9e175cb866334500),
+    (125, 0x01cdd595f5940fe8),
+    (435, 0x93e9582dbb3817de),
+    (10571, 0x63f7b4906bd870e7),
+    (10634, 0x9104e31027cf1fa6),
+    (1043, 0x5f904547f2d1b9e4),
+    (943, 0x04e3fe8f27e70a4a),
+    (88, 0x7f294082ec0bef01),
+    (685, 0xddf9da0c41f99e30),
+    (210, 0x12be5ecb32201ab6),
+    (1566, 0xee8204835968d9d4),
+    (4235, 0x01beb837974abcae),
+    (8198, 0xa670a0639c531b76),
+    (206, 0x62fb7a2b0893f102),
+    (20362, 0xce1aa9328d55ab02),
+    (779, 0x70788d0f13ac0508),
+    (1407, 0x9f270532bab7fa8e),
+    (309, 0x8cae1abc7ee745a1),
+    (253, 0x91bf3f86bd1762b7),
+    (3473, 0xc046fe8bac36910a),
+    (1551, 0x395c1094b3adfea5),
+    (1034, 0xe6c6b7a849bf5d80),
+    (1139, 0xa509588aba647b48),
+    (915, 0x9f20a13a11624277),
+    (920, 0x89b36ef85e3fa0fc),
+    (216, 0xbd2b919950c01a12),
+    (415, 0x90f7bf57e31bcac7),
+    (629, 0xda610309505dad84),
+    (583, 0xf4a0b086dc13aead),
+    (1424, 0x37b1f6b988f5a02b),
+    (226, 0x365a742e1a474473),
+    (387, 0xd2dafae964e85eb2),
+    (1105, 0xa04adfefd7bbdda7),
+    (480, 0xc2d4da1b99cca87b),
+    (1961, 0xff71974cc15a396e),
+    (550, 0xfbfdb5e3b856e97b),
+    (2188, 0x1d06d6134b8c0f5c),
+    (209, 0x4216902d230e4509),
+    (484, 0x1447c8c26eb7ca3c),
+    (252, 0x480cef80d4ca54ca),
+    (248, 0x386670cc415817f3),
+    (195, 0xea1a521acacaa8e6),
+    (209, 0xb86012d06afca2c8),
+    (568, 0x655b10ceec3e4c8b),
+    (261, 0x60d55062df4ac2e0),
+    (1275, 0xe4270a24769b0299),
+    (2030, 0x58e949f379b0b570),
+    (685, 0xe14dd78b4483e99a),
+    (734, 0x6adb86f65f5b29ef),
+    (2482, 0x0b5c61a089e47249),
+    (538, 0xfb9e6659b56e5b6f),
+    (1133, 0xeaa4cfc4820edb5a),
+];
+
+use crate::ImpreciseName;
+
 /// Pairs an invalid license identifier with its valid SPDX license identifier.
 /// These invalid identifiers are only allowed when using `Lax` parsing.
-pub const IMPRECISE_NAMES: &[(&str, &str)] = &[
-    ("agplv3", "AGPL-3.0"),
-    ("agpl", "AGPL-3.0"),
-    ("apache 2.0", "Apache-2.0"),
-    ("apache-2", "Apache-2.0"),
-    ("apache2", "Apache-2.0"),
-    ("apache", "Apache-2.0"),
-    ("asl2.0", "Apache-2.0"),
-    ("bsd 2-clause", "BSD-2-Clause"),
-    ("bsd-2clause", "BSD-2-Clause"),
-    ("bsd3", "BSD-3-Clause"),
-    ("bsd", "BSD-2-Clause"),
-    ("cc0", "CC0-1.0"),
-    ("gnu gpl v2", "GPL-3.0"),
-    ("gnu gpl v3", "GPL-3.0"),
-    ("gpl v2", "GPL-2.0"),
-    ("gpl v3", "GPL-3.0"),
-    ("gpl-2.0", "GPL-2.0"),
-    ("gpl-3.0", "GPL-3.0"),
-    ("gpl2", "GPL-2.0"),
-    ("gpl3", "GPL-3.0"),
-    ("gplv2", "GPL-2.0"),
-    ("gplv3", "GPL-3.0"),
-    ("gpl", "GPL-2.0"),
-    ("lgpl", "LGPL-2.0"),
-    ("mit", "MIT"),
-    ("mpl2", "MPL-2.0"),
-    ("mpl", "MPL-2.0"),
-    ("simplified bsd license", "BSD-2-Clause"),
-    ("zlib", "Zlib"),
+///
+/// The entries are sorted longest pattern first, so that the first entry that
+/// matches is always the longest match.
+pub const IMPRECISE_NAMES: &[ImpreciseName] = &[
+    ImpreciseName::prefix("simplified bsd license", "BSD-2-Clause"),
+    ImpreciseName::exact("apache license", "Apache-2.0"),
+    ImpreciseName::prefix("bsd 2-clause", "BSD-2-Clause"),
+    ImpreciseName::prefix("bsd-2clause", "BSD-2-Clause"),
+    ImpreciseName::prefix("apache 2.0", "Apache-2.0"),
+    ImpreciseName::prefix("gnu gpl v2", "GPL-3.0"),
+    ImpreciseName::prefix("gnu gpl v3", "GPL-3.0"),
+    ImpreciseName::prefix("apache-2", "Apache-2.0"),
+    ImpreciseName::prefix("apache2", "Apache-2.0"),
+    ImpreciseName::prefix("gpl-2.0", "GPL-2.0"),
+    ImpreciseName::prefix("gpl-3.0", "GPL-3.0"),
+    ImpreciseName::prefix("agplv3", "AGPL-3.0"),
+    ImpreciseName::prefix("apache", "Apache-2.0"),
+    ImpreciseName::prefix("asl2.0", "Apache-2.0"),
+    ImpreciseName::prefix("gpl v2", "GPL-2.0"),
+    ImpreciseName::prefix("gpl v3", "GPL-3.0"),
+    ImpreciseName::prefix("gplv2", "GPL-2.0"),
+    ImpreciseName::prefix("gplv3", "GPL-3.0"),
+    ImpreciseName::exact("agpl", "AGPL-3.0"),
+    ImpreciseName::prefix("bsd3", "BSD-3-Clause"),
+    ImpreciseName::prefix("gpl2", "GPL-2.0"),
+    ImpreciseName::prefix("gpl3", "GPL-3.0"),
+    ImpreciseName::exact("lgpl", "LGPL-2.0"),
+    ImpreciseName::prefix("mpl2", "MPL-2.0"),
+    ImpreciseName::prefix("zlib", "Zlib"),
+    ImpreciseName::exact("bsd", "BSD-2-Clause"),
+    ImpreciseName::prefix("cc0", "CC0-1.0"),
+    ImpreciseName::exact("gpl", "GPL-2.0"),
+    ImpreciseName::prefix("mit", "MIT"),
+    ImpreciseName::exact("mpl", "MPL-2.0"),
 ];
 
-pub const EXCEPTIONS: &[(&str, u8)] = &[
+/// Pairs a deprecated license identifier with the license identifier, and
+/// optional exception identifier, that replaced it in the SPDX license list.
+///
+/// Deprecated identifiers that were not replaced 1:1, eg. `Net-SNMP`, which
+/// was split into several licenses, are not included.
+pub const DEPRECATED_SUCCESSORS: &[(&str, &str, Option<&str>)] = &[
+    ("AGPL-1.0", "AGPL-1.0-only", None),
+    ("AGPL-3.0", "AGPL-3.0-only", None),
+    ("BSD-2-Clause-FreeBSD", "BSD-2-Clause", None),
+    ("BSD-2-Clause-NetBSD", "BSD-2-Clause", None),
+    ("GFDL-1.1", "GFDL-1.1-only", None),
+    ("GFDL-1.2", "GFDL-1.2-only", None),
+    ("GFDL-1.3", "GFDL-1.3-only", None),
+    ("GPL-1.0", "GPL-1.0-only", None),
+    ("GPL-2.0", "GPL-2.0-only", None),
+    (
+        "GPL-2.0-with-GCC-exception",
+        "GPL-2.0-only",
+        Some("GCC-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-autoconf-exception",
+        "GPL-2.0-only",
+        Some("Autoconf-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-bison-exception",
+        "GPL-2.0-only",
+        Some("Bison-exception-2.2"),
+    ),
+    (
+        "GPL-2.0-with-classpath-exception",
+        "GPL-2.0-only",
+        Some("Classpath-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-font-exception",
+        "GPL-2.0-only",
+        Some("Font-exception-2.0"),
+    ),
+    ("GPL-3.0", "GPL-3.0-only", None),
+    (
+        "GPL-3.0-with-GCC-exception",
+        "GPL-3.0-only",
+        Some("GCC-exception-3.1"),
+    ),
+    (
+        "GPL-3.0-with-autoconf-exception",
+        "GPL-3.0-only",
+        Some("Autoconf-exception-3.0"),
+    ),
+    ("LGPL-2.0", "LGPL-2.0-only", None),
+    ("LGPL-2.1", "LGPL-2.1-only", None),
+    ("LGPL-3.0", "LGPL-3.0-only", None),
+    ("Nunit", "zlib-acknowledgement", None),
+    ("StandardML-NJ", "SMLNJ", None),
+    ("bzip2-1.0.5", "bzip2-1.0.6", None),
+    ("eCos-2.0", "GPL-2.0-or-later", Some("eCos-exception-2.0")),
+    (
+        "wxWindows",
+        "LGPL-2.0-or-later",
+        Some("WxWindows-exception-3.1"),
+    ),
+];
+
+pub const EXCEPTIONS: &[(&str, u16)] = &[
     ("389-exception", 0),
     ("Asterisk-exception", 0),
     ("Asterisk-linking-protocols-exception", 0),
@@ -2222,3 +3021,23 @@ pub const EXCEPTIONS: &[(&str, u8)] = &[
     ("vsftpd-openssl-exception", 0),
     ("x11vnc-openssl-exception", 0),
 ];
+
+/// The earliest version of the license list that the update tool had the
+/// history of, the licenses and exceptions that were already in it may have
+/// been added in an earlier version
+pub const EARLIEST_ADDED_IN: &str = "3.26.0";
+
+/// The version of the license list each license and exception was added in,
+/// sorted by id, for the ids that were added after [`EARLIEST_ADDED_IN`]
+pub const ADDED_IN: &[(&str, &str)] = &[];
+
+/// The number of licenses in [`LICENSES`]
+pub const LICENSE_COUNT: usize = 687;
+/// The number of exceptions in [`EXCEPTIONS`]
+pub const EXCEPTION_COUNT: usize = 77;
+/// A hash of the id, name, and flags of every license and exception, which
+/// changes whenever the list does, so that data keyed by
+/// [`LicenseId::index`](crate::LicenseId::index) or
+/// [`ExceptionId::index`](crate::ExceptionId::index) can detect that it was
+/// created with a different version of the list
+pub const CONTENT_HASH: u64 = 0x72c4d134ac70d080;
@@ -2,9 +2,10 @@ use crate::{
     error::{ParseError, Reason},
     ExceptionId, LicenseId,
 };
+use std::ops::Range;
 
 /// Parsing configuration for SPDX expression
-#[derive(Default, Copy, Clone)]
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub struct ParseMode {
     /// The `AND`, `OR`, and `WITH` operators are required to be uppercase in
     /// the SPDX spec, but enabling this option allows them to be lowercased
@@ -20,15 +21,40 @@ pub struct ParseMode {
     /// See [`IMPRECISE_NAMES`](crate::identifiers::IMPRECISE_NAMES) for a list
     /// of the current synonyms. Note that this list is not comprehensive but
     /// can be expanded upon when invalid identifiers are found in the wild.
+    /// Additional synonyms can be supplied with [`Synonyms`].
     pub allow_imprecise_license_names: bool,
+    /// Allows an imprecise license name to match just the start of a word, eg.
+    /// `apache` in `apachesoftware`, rather than requiring the match to end at
+    /// a token boundary, ie. whitespace, `/`, `(`, `)`, `+`, or the end of the
+    /// expression.
+    ///
+    /// Only applies if [`Self::allow_imprecise_license_names`] is enabled.
+    pub allow_imprecise_name_prefix: bool,
     /// The various GPL licenses diverge from every other license in the SPDX
     /// license list by having an `-or-later` variant that is used as a suffix
     /// on a base license (eg. `GPL-3.0-or-later`) rather than the canonical
     /// `GPL-3.0+`.
     ///
     /// This option just allows GPL licenses to be treated similarly to all of
-    /// the other SPDX licenses.
+    /// the other SPDX licenses. A `+` on a license that already has the
+    /// `-or-later` suffix is redundant and ignored, but a `+` on a license
+    /// with the `-only` suffix is still an error.
     pub allow_postfix_plus_on_gpl: bool,
+    /// Allows a leading UTF-8 byte order mark, and a single pair of matching
+    /// quotes (`"`, `'`, or `` ` ``) around the entire expression, which are
+    /// common when expressions are copied from JSON or YAML, eg. `"MIT"`.
+    ///
+    /// The spans of tokens and errors are still relative to the original
+    /// expression, including the characters that were ignored.
+    pub allow_surrounding_quotes: bool,
+    /// Expands deprecated license identifiers that combine a license and an
+    /// exception into the license `WITH` the exception, see
+    /// [`LicenseId::decompose`](crate::LicenseId::decompose), so that eg.
+    /// `GPL-2.0-with-classpath-exception` is parsed as
+    /// `GPL-2.0-only WITH Classpath-exception-2.0`.
+    ///
+    /// An identifier that is followed by its own `WITH` is not expanded.
+    pub expand_composite_ids: bool,
 }
 
 impl ParseMode {
@@ -42,7 +68,10 @@ impl ParseMode {
         allow_lower_case_operators: false,
         allow_slash_as_or_operator: false,
         allow_imprecise_license_names: false,
+        allow_imprecise_name_prefix: false,
         allow_postfix_plus_on_gpl: false,
+        allow_surrounding_quotes: false,
+        expand_composite_ids: false,
     };
 
     /// Allow non-conforming syntax for crates-io compatibility
@@ -51,17 +80,291 @@ impl ParseMode {
     ///     SPDX license identifier.
     ///     See [`IMPRECISE_NAMES`](crate::identifiers::IMPRECISE_NAMES) for the
     ///     list of additionally accepted identifiers and the license they
-    ///     correspond to.
+    ///     correspond to. They must end at a token boundary, so eg.
+    ///     `apachesoftware` is not `Apache-2.0`.
     /// 1. `/` can by used as a synonym for `OR`, and doesn't need to be
     ///     separated by whitespace from the terms it combines
+    /// 1. A leading byte order mark, and quotes around the entire expression,
+    ///     are ignored
     pub const LAX: Self = Self {
         allow_lower_case_operators: true,
         allow_slash_as_or_operator: true,
         allow_imprecise_license_names: true,
+        allow_imprecise_name_prefix: false,
         allow_postfix_plus_on_gpl: true,
+        allow_surrounding_quotes: true,
+        expand_composite_ids: false,
     };
 }
 
+/// The set of non-strict syntax and names that were actually needed to parse
+/// an expression, see [`Expression::leniencies`](crate::Expression::leniencies)
+///
+/// ```
+/// use spdx::{Expression, Leniencies, ParseMode};
+///
+/// let expr = Expression::parse_mode("mit/apache2 AND GPL-3.0+", ParseMode::LAX).unwrap();
+/// let lens = expr.leniencies();
+///
+/// assert!(lens.contains(Leniencies::SLASH_OPERATOR | Leniencies::IMPRECISE_NAME));
+/// assert!(lens.contains(Leniencies::GPL_PLUS));
+/// assert!(!lens.contains(Leniencies::LOWERCASE_OPERATOR));
+///
+/// assert!(Expression::parse("MIT OR Apache-2.0").unwrap().leniencies().is_empty());
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Leniencies(u8);
+
+impl Leniencies {
+    /// `/` was used instead of `OR`
+    pub const SLASH_OPERATOR: Self = Self(0x1);
+    /// An imprecise license name, eg. `apache2`, or a caller supplied
+    /// [`Synonyms`] entry, was replaced with the license(s) it refers to
+    pub const IMPRECISE_NAME: Self = Self(0x2);
+    /// A deprecated license identifier was used. Note that this is the only
+    /// leniency that is also allowed by [`ParseMode::STRICT`]
+    pub const DEPRECATED_ID: Self = Self(0x4);
+    /// A `+` was used on a GNU license instead of the `-or-later` variant
+    pub const GPL_PLUS: Self = Self(0x8);
+    /// One of the `and`, `or`, or `with` operators was not uppercase
+    pub const LOWERCASE_OPERATOR: Self = Self(0x10);
+    /// A byte order mark, or quotes around the expression, were ignored
+    pub const SURROUNDING_QUOTES: Self = Self(0x20);
+
+    const NAMES: &'static [(Self, &'static str)] = &[
+        (Self::SLASH_OPERATOR, "SLASH_OPERATOR"),
+        (Self::IMPRECISE_NAME, "IMPRECISE_NAME"),
+        (Self::DEPRECATED_ID, "DEPRECATED_ID"),
+        (Self::GPL_PLUS, "GPL_PLUS"),
+        (Self::LOWERCASE_OPERATOR, "LOWERCASE_OPERATOR"),
+        (Self::SURROUNDING_QUOTES, "SURROUNDING_QUOTES"),
+    ];
+
+    /// The empty set, the expression was strictly valid and used no
+    /// deprecated license identifiers
+    #[inline]
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// The raw bits of the set
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Creates a set from raw bits, returning `None` if any of the bits don't
+    /// correspond to a leniency
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        const ALL: u8 = 0x3f;
+
+        if bits & !ALL == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if no leniencies were needed
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if all of the leniencies in `other` are in this set
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds all of the leniencies in `other` to this set
+    #[inline]
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Returns true if any of the leniencies require [`ParseMode::LAX`]
+    /// syntax, ie. anything other than [`Self::DEPRECATED_ID`]
+    #[inline]
+    #[must_use]
+    pub const fn requires_lax(self) -> bool {
+        self.0 & !Self::DEPRECATED_ID.0 != 0
+    }
+
+    /// Iterates over the name and value of each leniency in the set
+    pub fn iter(self) -> impl Iterator<Item = (&'static str, Self)> {
+        Self::NAMES
+            .iter()
+            .filter(move |(flag, _)| self.contains(*flag))
+            .map(|(flag, name)| (*name, *flag))
+    }
+}
+
+impl std::ops::BitOr for Leniencies {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, o: Self) -> Self {
+        Self(self.0 | o.0)
+    }
+}
+
+impl std::ops::BitOrAssign for Leniencies {
+    #[inline]
+    fn bitor_assign(&mut self, o: Self) {
+        self.insert(o);
+    }
+}
+
+impl std::fmt::Debug for Leniencies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Leniencies(")?;
+        for (i, (name, _)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            f.write_str(name)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// The license, or licenses, that a [`Synonyms`] entry is replaced with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Synonym {
+    /// A single SPDX license id
+    License(LicenseId),
+    /// A `LicenseRef-` id, with an optional `DocumentRef-`
+    LicenseRef {
+        doc_ref: Option<String>,
+        lic_ref: String,
+    },
+    /// A full, strictly valid, SPDX expression, which is treated as if it
+    /// were surrounded by parentheses
+    Expression(String),
+}
+
+/// Additional, caller supplied, synonyms for licenses that are not in
+/// [`IMPRECISE_NAMES`](crate::identifiers::IMPRECISE_NAMES), such as
+/// organization specific names.
+///
+/// Synonyms are matched ASCII case-insensitively, so any other characters must
+/// match exactly, may contain whitespace, and only match whole words. If more than one synonym matches, the longest one is
+/// used. Synonyms are consulted in any [`ParseMode`], after the built-in
+/// imprecise names, and only replace an actual SPDX identifier if they span
+/// more than it does, unless [`Synonyms::prefer_synonyms`] is set.
+///
+/// ```
+/// let mut synonyms = spdx::Synonyms::new();
+/// synonyms.add_license_ref("Company Standard License", None, "Acme-1.0");
+/// synonyms.add_expression("apache/MIT dual", "Apache-2.0 OR MIT").unwrap();
+///
+/// let expr = spdx::Expression::parse_with_synonyms(
+///     "company standard license AND apache/mit dual",
+///     spdx::ParseMode::STRICT,
+///     &synonyms,
+/// ).unwrap();
+///
+/// assert_eq!(expr.requirements().count(), 3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Synonyms {
+    /// ASCII lowercased names and what they are replaced with, longest name
+    /// first
+    entries: Vec<(String, Synonym)>,
+    /// If true, a synonym that collides with an actual SPDX license or
+    /// exception identifier is used instead of that identifier
+    pub prefer_synonyms: bool,
+}
+
+impl Synonyms {
+    /// Creates an empty set of synonyms
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a synonym for an SPDX license id, replacing any previous synonym
+    /// with the same name
+    pub fn add_license(&mut self, name: &str, id: LicenseId) {
+        self.insert(name, Synonym::License(id));
+    }
+
+    /// Adds a synonym for a `[DocumentRef-<doc_ref>:]LicenseRef-<lic_ref>`,
+    /// replacing any previous synonym with the same name
+    pub fn add_license_ref(&mut self, name: &str, doc_ref: Option<&str>, lic_ref: &str) {
+        self.insert(
+            name,
+            Synonym::LicenseRef {
+                doc_ref: doc_ref.map(String::from),
+                lic_ref: lic_ref.to_owned(),
+            },
+        );
+    }
+
+    /// Adds a synonym for an entire expression, replacing any previous
+    /// synonym with the same name. The expression must be valid with
+    /// [`ParseMode::STRICT`].
+    ///
+    /// # Errors
+    ///
+    /// The expression failed to parse
+    pub fn add_expression(&mut self, name: &str, expression: &str) -> Result<(), ParseError> {
+        crate::Expression::parse(expression)?;
+        self.insert(name, Synonym::Expression(expression.to_owned()));
+        Ok(())
+    }
+
+    /// Adds a synonym, replacing any previous synonym with the same name
+    ///
+    /// # Panics
+    ///
+    /// The name is empty or only whitespace
+    pub fn insert(&mut self, name: &str, synonym: Synonym) {
+        // Only ASCII is folded, the same as when matching the names
+        let name = name.trim().to_ascii_lowercase();
+        assert!(!name.is_empty(), "synonym names must not be empty");
+
+        match self
+            .entries
+            .binary_search_by(|(n, _)| name.len().cmp(&n.len()).then_with(|| n.cmp(&name)))
+        {
+            Ok(i) => self.entries[i].1 = synonym,
+            Err(i) => self.entries.insert(i, (name, synonym)),
+        }
+    }
+
+    /// Returns an iterator over the ASCII lowercased names and what they are
+    /// replaced with, longest name first
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Synonym)> {
+        self.entries.iter().map(|(n, s)| (n.as_str(), s))
+    }
+
+    /// Finds the longest synonym that matches whole words at the start of
+    /// the text, returning it and the length, in bytes, of the match
+    pub fn find(&self, text: &str) -> Option<(&Synonym, usize)> {
+        self.entries.iter().find_map(|(name, syn)| {
+            let len = name.len();
+            let prefix = text.as_bytes().get(..len)?;
+
+            if !name.as_bytes().eq_ignore_ascii_case(prefix) {
+                return None;
+            }
+
+            match text[len..].chars().next() {
+                Some(c) if Lexer::is_ref_char(&c) || c == ':' => None,
+                _ => Some((syn, len)),
+            }
+        })
+    }
+}
+
 /// A single token in an SPDX license expression
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Token<'a> {
@@ -119,11 +422,19 @@ impl Token<'_> {
 ///
 /// Prefer to use `Expression::parse` or `Licensee::parse` rather
 /// than directly using the lexer
+///
+/// The lexer doesn't advance past an error by itself, see [`Lexer::recover`]
+/// to continue lexing after one.
 pub struct Lexer<'a> {
     inner: &'a str,
     original: &'a str,
     offset: usize,
     mode: ParseMode,
+    synonyms: Option<&'a Synonyms>,
+    /// The remaining tokens of an expanded [`Synonym::Expression`], in reverse
+    expansion: Vec<LexerToken<'a>>,
+    /// The non-strict syntax, or names, that have been lexed so far
+    pub(crate) leniencies: Leniencies,
 }
 
 impl<'a> Lexer<'a> {
@@ -135,6 +446,9 @@ impl<'a> Lexer<'a> {
             original: text,
             offset: 0,
             mode: ParseMode::STRICT,
+            synonyms: None,
+            expansion: Vec::new(),
+            leniencies: Leniencies::empty(),
         }
     }
 
@@ -149,6 +463,191 @@ impl<'a> Lexer<'a> {
             original: text,
             offset: 0,
             mode,
+            synonyms: None,
+            expansion: Vec::new(),
+            leniencies: Leniencies::empty(),
+        }
+    }
+
+    /// Also recognizes the specified [`Synonyms`], in addition to the
+    /// built-in imprecise names allowed by the [`ParseMode`]
+    #[must_use]
+    pub fn with_synonyms(mut self, synonyms: &'a Synonyms) -> Self {
+        self.synonyms = Some(synonyms);
+        self
+    }
+
+    /// The byte offset into the original expression of the remaining input
+    #[inline]
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The remaining input that has not been lexed yet
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> &'a str {
+        self.inner
+    }
+
+    /// The original license expression that is being lexed
+    #[inline]
+    #[must_use]
+    pub fn original(&self) -> &'a str {
+        self.original
+    }
+
+    /// The [`ParseMode`] the lexer was created with
+    #[inline]
+    #[must_use]
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Skips past the input that caused the last error, up to the next
+    /// whitespace or parenthesis, so that lexing can resume.
+    ///
+    /// The lexer doesn't advance when it yields an error, so calling `next`
+    /// again would just yield the same error, this needs to be called first,
+    /// eg. for tooling that wants to report every error in an expression
+    /// rather than just the first one.
+    ///
+    /// ```
+    /// use spdx::lexer::Lexer;
+    ///
+    /// let mut lexer = Lexer::new("MIT ?? Apache-2.0");
+    ///
+    /// assert!(lexer.next().unwrap().is_ok());
+    /// assert!(lexer.next().unwrap().is_err());
+    ///
+    /// lexer.recover();
+    /// assert_eq!(lexer.remaining(), " Apache-2.0");
+    /// assert!(lexer.next().unwrap().is_ok());
+    /// ```
+    pub fn recover(&mut self) {
+        let start = self.inner.len() - self.inner.trim_start().len();
+        // Always skip at least one character, so that recovery makes progress
+        // even if the error was at a separator, eg. a separated `+`
+        let first = self.inner[start..].chars().next().map_or(0, char::len_utf8);
+        let rest = &self.inner[start + first..];
+        let skip = start
+            + first
+            + rest
+                .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+                .unwrap_or(rest.len());
+
+        self.inner = &self.inner[skip..];
+        self.offset += skip;
+    }
+
+    /// Converts a synonym into the token(s) it represents
+    fn expand(&mut self, synonym: &'a Synonym, len: usize) -> Token<'a> {
+        self.leniencies |= Leniencies::IMPRECISE_NAME;
+
+        match synonym {
+            Synonym::License(id) => Token::Spdx(*id),
+            Synonym::LicenseRef { doc_ref, lic_ref } => Token::LicenseRef {
+                doc_ref: doc_ref.as_deref(),
+                lic_ref,
+            },
+            Synonym::Expression(expr) => {
+                let span = self.offset..self.offset + len;
+                let text = &self.inner[..len];
+                self.expansion.push(LexerToken {
+                    token: Token::CloseParen,
+                    span: span.clone(),
+                    text,
+                    imprecise: true,
+                });
+
+                let start = self.expansion.len();
+                // The expression was validated when it was added
+                self.expansion
+                    .extend(Lexer::new(expr).map_while(Result::ok).map(|lt| LexerToken {
+                        token: lt.token,
+                        span: span.clone(),
+                        text,
+                        imprecise: true,
+                    }));
+                self.expansion[start..].reverse();
+
+                Token::OpenParen
+            }
+        }
+    }
+
+    /// Converts this lexer into one that also yields the whitespace between
+    /// tokens as [`TriviaToken::Trivia`], allowing the original expression
+    /// to be reconstructed byte-for-byte from the yielded spans
+    ///
+    /// ```
+    /// use spdx::lexer::{Lexer, TriviaToken};
+    ///
+    /// let text = " MIT  OR Apache-2.0 ";
+    /// let rebuilt: String = Lexer::new(text)
+    ///     .tokens_with_trivia()
+    ///     .map(|tt| &text[tt.unwrap().span()])
+    ///     .collect();
+    ///
+    /// assert_eq!(rebuilt, text);
+    /// ```
+    #[must_use]
+    pub fn tokens_with_trivia(self) -> TriviaLexer<'a> {
+        TriviaLexer {
+            end: self.offset,
+            inner: self,
+            pending: None,
+            done: false,
+        }
+    }
+
+    /// Ignores a leading byte order mark, and a pair of matching quotes around
+    /// the remaining input, adjusting the offset so that spans are still
+    /// relative to the original
+    fn strip_surrounding_quotes(&mut self) {
+        let (bom_len, unquoted) = surrounding_quotes(self.inner);
+
+        if bom_len == 0 && unquoted.is_none() {
+            return;
+        }
+
+        let inner = unquoted.unwrap_or(bom_len..self.inner.len());
+        self.offset += inner.start;
+        self.inner = &self.inner[inner];
+        self.leniencies |= Leniencies::SURROUNDING_QUOTES;
+    }
+
+    /// Returns the length, in bytes, of the run of characters at the start of
+    /// the remaining input that can't start a token, or separate tokens
+    fn invalid_run_len(&self) -> usize {
+        self.inner
+            .char_indices()
+            .find(|(_, c)| {
+                c.is_whitespace()
+                    || Self::is_ref_char(c)
+                    || matches!(c, '+' | '(' | ')')
+                    || (*c == '/' && self.mode.allow_slash_as_or_operator)
+            })
+            .map_or(self.inner.len(), |(i, _)| i)
+    }
+
+    /// Returns the length, in bytes, of any characters that directly follow
+    /// the token of the specified length that are not a valid separator,
+    /// up to the next whitespace or parenthesis
+    fn trailing_garbage_len(&self, token_len: usize) -> usize {
+        let rest = &self.inner[token_len..];
+        let is_separator = |c: char| {
+            c.is_whitespace()
+                || matches!(c, '+' | '(' | ')')
+                || (c == '/' && self.mode.allow_slash_as_or_operator)
+        };
+
+        match rest.chars().next() {
+            Some(c) if !is_separator(c) => rest
+                .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+                .unwrap_or(rest.len()),
+            _ => 0,
         }
     }
 
@@ -157,36 +656,81 @@ impl<'a> Lexer<'a> {
         c.is_ascii_alphanumeric() || *c == '-' || *c == '.'
     }
 
-    /// Return a matching text token if found - equivalent to the regex `^[-a-zA-Z0-9.:]+`
+    /// Return a matching text token if found - equivalent to the regex `^[-a-zA-Z0-9.]+`,
+    /// or `^[-a-zA-Z0-9.:]+` for document refs, as they are the only tokens
+    /// that can contain a `:`
     fn find_text_token(text: &'a str) -> Option<&'a str> {
-        let is_token_char = |c: &char| Self::is_ref_char(c) || *c == ':';
+        let allow_colon = text.starts_with("DocumentRef-");
+        let is_token_char = |c: &char| Self::is_ref_char(c) || (allow_colon && *c == ':');
         match text.chars().take_while(is_token_char).count() {
             index if index > 0 => Some(&text[..index]),
             _ => None,
         }
     }
 
-    /// Extract the text after `prefix` if made up of valid ref characters
-    fn find_ref(prefix: &str, text: &'a str) -> Option<&'a str> {
-        text.strip_prefix(prefix).map(|value| {
-            let end = value.chars().take_while(Self::is_ref_char).count();
-            &text[prefix.len()..prefix.len() + end]
-        })
-    }
+    /// Return the id of a `LicenseRef-<id>`, or the reason and span, relative
+    /// to the start of `text`, that it is malformed
+    fn find_license_ref(text: &'a str) -> Result<&'a str, (Reason, Range<usize>)> {
+        const PREFIX: &str = "LicenseRef-";
 
-    /// Return a license ref if found - equivalent to the regex `^LicenseRef-([-a-zA-Z0-9.]+)`
-    #[inline]
-    fn find_license_ref(text: &'a str) -> Option<&'a str> {
-        Self::find_ref("LicenseRef-", text)
+        let id = &text[PREFIX.len()..];
+        if id.is_empty() {
+            return Err((Reason::EmptyRefId, 0..PREFIX.len()));
+        }
+
+        // Only document refs can contain a `:`
+        match id.find(':') {
+            Some(colon) => Err((Reason::InvalidCharacters, PREFIX.len() + colon..text.len())),
+            None => Ok(id),
+        }
     }
 
-    /// Return a document ref and license ref if found,
-    /// equivalent to the regex `^DocumentRef-([-a-zA-Z0-9.]+):LicenseRef-([-a-zA-Z0-9.]+)`
-    fn find_document_and_license_ref(text: &'a str) -> Option<(&'a str, &'a str)> {
-        let split = text.split_once(':');
-        let doc_ref = split.and_then(|(doc, _)| Self::find_ref("DocumentRef-", doc));
-        let lic_ref = split.and_then(|(_, lic)| Self::find_license_ref(lic));
-        Option::zip(doc_ref, lic_ref)
+    /// Return a document ref and license ref if the text is a ref, equivalent
+    /// to the regex `^(?:DocumentRef-([-a-zA-Z0-9.]+):)?LicenseRef-([-a-zA-Z0-9.]+)$`,
+    /// or the reason and span, relative to the start of `text`, that it is malformed.
+    ///
+    /// Returns `None` if the text doesn't start with `DocumentRef-` or
+    /// `LicenseRef-` and so is not a ref at all.
+    #[allow(clippy::type_complexity)]
+    fn find_document_and_license_ref(
+        text: &'a str,
+    ) -> Option<Result<(Option<&'a str>, &'a str), (Reason, Range<usize>)>> {
+        const PREFIX: &str = "DocumentRef-";
+
+        if text.starts_with("LicenseRef-") {
+            return Some(Self::find_license_ref(text).map(|lic_ref| (None, lic_ref)));
+        }
+
+        let rest = text.strip_prefix(PREFIX)?;
+
+        let Some((doc_ref, lic)) = rest.split_once(':') else {
+            return Some(Err((Reason::MalformedDocumentRef, 0..text.len())));
+        };
+
+        if doc_ref.is_empty() {
+            return Some(Err((Reason::EmptyRefId, 0..PREFIX.len())));
+        }
+
+        let lic_start = PREFIX.len() + doc_ref.len() + 1;
+        if !lic.starts_with("LicenseRef-") {
+            // The `:` must be followed by a license ref
+            let span = if lic.is_empty() {
+                lic_start - 1..lic_start
+            } else {
+                lic_start..text.len()
+            };
+            return Some(Err((Reason::MalformedDocumentRef, span)));
+        }
+
+        Some(match Self::find_license_ref(lic) {
+            Ok(lic_ref) => Ok((Some(doc_ref), lic_ref)),
+            // Multiple `:` separated refs
+            Err((Reason::InvalidCharacters, span)) => Err((
+                Reason::MalformedDocumentRef,
+                span.start + lic_start..span.end + lic_start,
+            )),
+            Err((reason, span)) => Err((reason, span.start + lic_start..span.end + lic_start)),
+        })
     }
 }
 
@@ -198,6 +742,125 @@ pub struct LexerToken<'a> {
     pub token: Token<'a>,
     /// The range of the token characters in the original license expression
     pub span: std::ops::Range<usize>,
+    /// The text of the token in the original license expression, ie. the
+    /// text of the [`span`](Self::span), which can differ from the token, eg.
+    /// `or` for [`Token::Or`], or `apache2` for `Apache-2.0`
+    pub text: &'a str,
+    /// True if the token was matched from an imprecise license name, or a
+    /// [`Synonyms`] entry, rather than being exactly what was written, eg.
+    /// `simplified bsd license` for `BSD-2-Clause`. Every token expanded from
+    /// a [`Synonym::Expression`] is imprecise, and has the span and text of
+    /// the entire synonym.
+    pub imprecise: bool,
+}
+
+/// Finds the leading byte order mark, and the pair of matching quotes around the
+/// rest of the input, that [`ParseMode::allow_surrounding_quotes`] ignores,
+/// returning the length of the byte order mark, and the range of the text
+/// between the quotes
+pub(crate) fn surrounding_quotes(original: &str) -> (usize, Option<Range<usize>>) {
+    let bom_len = if original.starts_with('\u{feff}') {
+        '\u{feff}'.len_utf8()
+    } else {
+        0
+    };
+
+    let rest = &original[bom_len..];
+    let trimmed = rest.trim();
+    let start = bom_len + rest.len() - rest.trim_start().len() + 1;
+
+    let unquoted = ['"', '\'', '`'].iter().find_map(|quote| {
+        trimmed
+            .strip_prefix(*quote)
+            .and_then(|rest| rest.strip_suffix(*quote))
+    });
+
+    (
+        bom_len,
+        unquoted.map(|unquoted| start..start + unquoted.len()),
+    )
+}
+
+/// Either a lexed token, or the whitespace that separates tokens, yielded by
+/// [`Lexer::tokens_with_trivia`]
+#[derive(Debug)]
+pub enum TriviaToken<'a> {
+    /// A regular token
+    Token(LexerToken<'a>),
+    /// A run of whitespace that was skipped over by the lexer
+    Trivia {
+        /// The range of the whitespace characters in the original license expression
+        span: std::ops::Range<usize>,
+    },
+}
+
+impl TriviaToken<'_> {
+    /// The range of characters in the original license expression covered
+    /// by this token or trivia
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> std::ops::Range<usize> {
+        match self {
+            Self::Token(lt) => lt.span.clone(),
+            Self::Trivia { span } => span.clone(),
+        }
+    }
+}
+
+/// A wrapper around a [`Lexer`] that also yields the whitespace between each
+/// token, so that the spans of all of the yielded items, in order, exactly
+/// cover the original string.
+///
+/// Iteration stops after the first error.
+pub struct TriviaLexer<'a> {
+    inner: Lexer<'a>,
+    /// The end of the last token or trivia that was yielded
+    end: usize,
+    /// A token that is yielded after the trivia that preceded it
+    pending: Option<LexerToken<'a>>,
+    done: bool,
+}
+
+impl<'a> Iterator for TriviaLexer<'a> {
+    type Item = Result<TriviaToken<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(lt) = self.pending.take() {
+            self.end = lt.span.end;
+            return Some(Ok(TriviaToken::Token(lt)));
+        }
+
+        if self.done {
+            return None;
+        }
+
+        match self.inner.next() {
+            Some(Ok(lt)) => {
+                if lt.span.start > self.end {
+                    let span = self.end..lt.span.start;
+                    self.end = lt.span.start;
+                    self.pending = Some(lt);
+                    Some(Ok(TriviaToken::Trivia { span }))
+                } else {
+                    self.end = lt.span.end;
+                    Some(Ok(TriviaToken::Token(lt)))
+                }
+            }
+            Some(Err(err)) => {
+                self.done = true;
+                Some(Err(err))
+            }
+            None => {
+                self.done = true;
+                let len = self.inner.original.len();
+                (self.end < len).then(|| {
+                    let span = self.end..len;
+                    self.end = len;
+                    Ok(TriviaToken::Trivia { span })
+                })
+            }
+        }
+    }
 }
 
 impl<'a> Iterator for Lexer<'a> {
@@ -205,9 +868,18 @@ impl<'a> Iterator for Lexer<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         #[allow(clippy::unnecessary_wraps)]
-        fn ok_token(token: Token<'_>) -> Option<Result<(Token<'_>, usize), ParseError>> {
+        fn ok_token(token: Token<'_>) -> Option<Result<(Token<'_>, usize, bool), ParseError>> {
             let len = token.len();
-            Some(Ok((token, len)))
+            Some(Ok((token, len, false)))
+        }
+
+        if let Some(lt) = self.expansion.pop() {
+            return Some(Ok(lt));
+        }
+
+        // Nothing has been lexed yet
+        if self.offset == 0 && self.mode.allow_surrounding_quotes {
+            self.strip_surrounding_quotes();
         }
 
         // Jump over any whitespace, updating `self.inner` and `self.offset` appropriately
@@ -228,59 +900,100 @@ impl<'a> Iterator for Lexer<'a> {
                 } else {
                     Some(Err(ParseError {
                         original: self.original.to_owned(),
-                        span: self.offset - non_whitespace_index..self.offset,
+                        mode: self.mode,
+                        span: self.offset..self.offset + 1,
                         reason: Reason::SeparatedPlus,
                     }))
                 }
             }
             Some('(') => ok_token(Token::OpenParen),
             Some(')') => ok_token(Token::CloseParen),
-            Some('/') if self.mode.allow_slash_as_or_operator => Some(Ok((Token::Or, 1))),
+            Some('/') if self.mode.allow_slash_as_or_operator => {
+                self.leniencies |= Leniencies::SLASH_OPERATOR;
+                Some(Ok((Token::Or, 1, false)))
+            }
             Some(_) => match Lexer::find_text_token(self.inner) {
                 None => Some(Err(ParseError {
                     original: self.original.to_owned(),
-                    span: self.offset..self.offset + self.inner.len(),
+                    mode: self.mode,
+                    span: self.offset..self.offset + self.invalid_run_len(),
                     reason: Reason::InvalidCharacters,
                 })),
                 Some(m) => {
-                    if m == "WITH" {
+                    let synonym = self.synonyms.and_then(|syns| syns.find(self.inner));
+
+                    if let Some((syn, len)) = synonym.filter(|(_, len)| {
+                        *len > m.len() || self.synonyms.map_or(false, |s| s.prefer_synonyms)
+                    }) {
+                        // Synonyms that span multiple words, or are preferred, take
+                        // precedence over everything else
+                        Some(Ok((self.expand(syn, len), len, true)))
+                    } else if m == "WITH" {
                         ok_token(Token::With)
                     } else if m == "AND" {
                         ok_token(Token::And)
                     } else if m == "OR" {
                         ok_token(Token::Or)
                     } else if self.mode.allow_lower_case_operators && m == "and" {
+                        self.leniencies |= Leniencies::LOWERCASE_OPERATOR;
                         ok_token(Token::And)
                     } else if self.mode.allow_lower_case_operators && m == "or" {
+                        self.leniencies |= Leniencies::LOWERCASE_OPERATOR;
                         ok_token(Token::Or)
                     } else if self.mode.allow_lower_case_operators && m == "with" {
+                        self.leniencies |= Leniencies::LOWERCASE_OPERATOR;
                         ok_token(Token::With)
                     } else if let Some(lic_id) = crate::license_id(m) {
+                        if lic_id.is_deprecated() {
+                            self.leniencies |= Leniencies::DEPRECATED_ID;
+                        }
                         ok_token(Token::Spdx(lic_id))
                     } else if let Some(exc_id) = crate::exception_id(m) {
                         ok_token(Token::Exception(exc_id))
-                    } else if let Some((doc_ref, lic_ref)) = Lexer::find_document_and_license_ref(m)
-                    {
-                        ok_token(Token::LicenseRef {
-                            doc_ref: Some(doc_ref),
-                            lic_ref,
-                        })
-                    } else if let Some(lic_ref) = Lexer::find_license_ref(m) {
-                        ok_token(Token::LicenseRef {
-                            doc_ref: None,
-                            lic_ref,
-                        })
+                    } else if let Some(res) = Lexer::find_document_and_license_ref(m) {
+                        match res {
+                            Ok((doc_ref, lic_ref)) => match self.trailing_garbage_len(m.len()) {
+                                0 => ok_token(Token::LicenseRef { doc_ref, lic_ref }),
+                                len => Some(Err(ParseError {
+                                    original: self.original.to_owned(),
+                                    mode: self.mode,
+                                    span: self.offset + m.len()..self.offset + m.len() + len,
+                                    reason: Reason::InvalidCharacters,
+                                })),
+                            },
+                            Err((reason, span)) => Some(Err(ParseError {
+                                original: self.original.to_owned(),
+                                mode: self.mode,
+                                span: self.offset + span.start..self.offset + span.end,
+                                reason,
+                            })),
+                        }
                     } else if let Some((lic_id, token_len)) =
                         if self.mode.allow_imprecise_license_names {
-                            crate::imprecise_license_id(self.inner)
+                            crate::find_imprecise_license_id(
+                                self.inner,
+                                !self.mode.allow_imprecise_name_prefix,
+                            )
                         } else {
                             None
                         }
                     {
-                        Some(Ok((Token::Spdx(lic_id), token_len)))
+                        match synonym {
+                            // The longest match wins, with ties going to the built-in names
+                            Some((syn, len)) if len > token_len => {
+                                Some(Ok((self.expand(syn, len), len, true)))
+                            }
+                            _ => {
+                                self.leniencies |= Leniencies::IMPRECISE_NAME;
+                                Some(Ok((Token::Spdx(lic_id), token_len, true)))
+                            }
+                        }
+                    } else if let Some((syn, len)) = synonym {
+                        Some(Ok((self.expand(syn, len), len, true)))
                     } else {
                         Some(Err(ParseError {
                             original: self.original.to_owned(),
+                            mode: self.mode,
                             span: self.offset..self.offset + m.len(),
                             reason: Reason::UnknownTerm,
                         }))
@@ -289,14 +1002,17 @@ impl<'a> Iterator for Lexer<'a> {
             },
         }
         .map(|res| {
-            res.map(|(tok, len)| {
+            res.map(|(tok, len, imprecise)| {
                 let start = self.offset;
+                let text = &self.inner[..len];
                 self.inner = &self.inner[len..];
                 self.offset += len;
 
                 LexerToken {
                     token: tok,
                     span: start..self.offset,
+                    text,
+                    imprecise,
                 }
             })
         })
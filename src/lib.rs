@@ -1,3 +1,8 @@
+/// Helpers for the license fields of `Cargo.toml` manifests
+pub mod cargo;
+/// Detection of licenses from their text
+#[cfg(feature = "text")]
+pub mod detection;
 /// Error types
 pub mod error;
 pub mod expression;
@@ -6,19 +11,23 @@ pub mod identifiers;
 /// Contains types for lexing an SPDX license expression
 pub mod lexer;
 mod licensee;
+/// Conversions of the byte spans in expressions and errors to other units
+pub mod span;
+mod suggest;
 /// Auto-generated full canonical text of each license
 #[cfg(feature = "text")]
 pub mod text;
+mod version;
 
 pub use error::ParseError;
 pub use expression::Expression;
-use identifiers::{IS_COPYLEFT, IS_DEPRECATED, IS_FSF_LIBRE, IS_GNU, IS_OSI_APPROVED};
-pub use lexer::ParseMode;
-pub use licensee::Licensee;
-use std::{
-    cmp::{self, Ordering},
-    fmt,
+use identifiers::{
+    IS_CC, IS_COPYLEFT, IS_DEPRECATED, IS_FSF_LIBRE, IS_GNU, IS_NON_COMMERCIAL, IS_OSI_APPROVED,
+    IS_PUBLIC_DOMAIN_EQUIVALENT,
 };
+pub use lexer::{Leniencies, ParseMode, Synonyms};
+pub use licensee::Licensee;
+use std::{cmp::Ordering, fmt};
 
 /// Unique identifier for a particular license
 ///
@@ -39,7 +48,7 @@ pub struct LicenseId {
     /// The full name of the license
     pub full_name: &'static str,
     index: usize,
-    flags: u8,
+    flags: u16,
 }
 
 impl PartialEq for LicenseId {
@@ -64,6 +73,61 @@ impl PartialOrd for LicenseId {
 }
 
 impl LicenseId {
+    /// Returns the license at the specified position in
+    /// [`LICENSES`](crate::identifiers::LICENSES), or `None` if the index is
+    /// out of bounds, see [`LicenseId::index`]
+    ///
+    /// ```
+    /// let mit = spdx::license_id("MIT").unwrap();
+    /// assert_eq!(spdx::LicenseId::from_index(mit.index()), Some(mit));
+    /// assert!(spdx::LicenseId::from_index(spdx::identifiers::LICENSE_COUNT).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_index(index: usize) -> Option<Self> {
+        identifiers::LICENSES
+            .get(index)
+            .map(|(name, full_name, flags)| Self {
+                name,
+                full_name,
+                index,
+                flags: *flags,
+            })
+    }
+
+    /// The position of the license in [`LICENSES`](crate::identifiers::LICENSES),
+    /// which is always less than [`LICENSE_COUNT`](crate::identifiers::LICENSE_COUNT),
+    /// eg. for using licenses as indices into a bitset.
+    ///
+    /// The index is only stable for the current version of the SPDX license
+    /// list, and will change when licenses are added or removed, so any
+    /// persisted data that uses it should also store the
+    /// [`CONTENT_HASH`](crate::identifiers::CONTENT_HASH) to detect this.
+    #[inline]
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.index
+    }
+
+    /// The version of the SPDX license list the license was added in, or
+    /// [`EARLIEST_ADDED_IN`](crate::identifiers::EARLIEST_ADDED_IN) if it was
+    /// already in the earliest version the list's history was available for,
+    /// in which case it may have been added earlier.
+    ///
+    /// ```
+    /// use spdx::{identifiers::EARLIEST_ADDED_IN, ListVersion};
+    ///
+    /// assert_eq!(spdx::license_id("MIT").unwrap().added_in(), EARLIEST_ADDED_IN);
+    ///
+    /// let added_in: ListVersion = spdx::license_id("Elastic-2.0").unwrap().added_in().parse().unwrap();
+    /// assert!(added_in >= ListVersion { major: 3, minor: 12, patch: 0 });
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn added_in(self) -> &'static str {
+        added_in(self.name)
+    }
+
     /// Returns true if the license is [considered free by the FSF](https://www.gnu.org/licenses/license-list.en.html)
     ///
     /// ```
@@ -120,6 +184,231 @@ impl LicenseId {
         self.flags & IS_GNU != 0
     }
 
+    /// Returns true if the license is a [Creative Commons](https://creativecommons.org/licenses/)
+    /// license, including the `CC0-1.0` public domain dedication
+    ///
+    /// ```
+    /// assert!(spdx::license_id("CC-BY-4.0").unwrap().is_cc());
+    /// assert!(!spdx::license_id("MIT").unwrap().is_cc());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_cc(self) -> bool {
+        self.flags & IS_CC != 0
+    }
+
+    /// Returns true if the license forbids commercial use, eg. `CC-BY-NC-4.0`
+    ///
+    /// ```
+    /// assert!(spdx::license_id("CC-BY-NC-SA-4.0").unwrap().is_non_commercial());
+    /// assert!(!spdx::license_id("CC-BY-SA-4.0").unwrap().is_non_commercial());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_non_commercial(self) -> bool {
+        self.flags & IS_NON_COMMERCIAL != 0
+    }
+
+    /// Returns true if the license dedicates the work to the public domain, or
+    /// is equivalent to doing so by not having any conditions, not even
+    /// attribution, eg. `CC0-1.0`, `Unlicense`, or `0BSD`
+    ///
+    /// ```
+    /// assert!(spdx::license_id("Unlicense").unwrap().is_public_domain_equivalent());
+    /// assert!(!spdx::license_id("MIT").unwrap().is_public_domain_equivalent());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_public_domain_equivalent(self) -> bool {
+        self.flags & IS_PUBLIC_DOMAIN_EQUIVALENT != 0
+    }
+
+    /// Returns how restrictive the license is, eg. to prefer the least
+    /// restrictive choice of an expression, see
+    /// [`Expression::most_permissive_choice`].
+    ///
+    /// This is derived from the [copyleft](Self::is_copyleft) and
+    /// [non-commercial](Self::is_non_commercial) flags, along with the
+    /// families of licenses that are weak, or network, copyleft, so it is
+    /// only a heuristic.
+    ///
+    /// ```
+    /// use spdx::{license_id, Restrictiveness};
+    ///
+    /// assert_eq!(license_id("MIT").unwrap().restrictiveness(), Restrictiveness::Permissive);
+    /// assert_eq!(license_id("MPL-2.0").unwrap().restrictiveness(), Restrictiveness::WeakCopyleft);
+    /// assert!(
+    ///     license_id("AGPL-3.0-only").unwrap().restrictiveness()
+    ///         > license_id("GPL-3.0-only").unwrap().restrictiveness()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn restrictiveness(self) -> Restrictiveness {
+        const WEAK_COPYLEFT: &[&str] = &[
+            "CDDL-", "CECILL-C", "CPL-", "EPL-", "LGPL-", "MPL-", "MS-RL", "NPL-",
+        ];
+        const NETWORK_COPYLEFT: &[&str] = &["AGPL-", "SSPL-"];
+
+        let is = |families: &[&str]| families.iter().any(|fam| self.name.starts_with(fam));
+
+        if self.name == "NOASSERTION" {
+            Restrictiveness::Unknown
+        } else if self.is_non_commercial() || (self.is_cc() && self.name.contains("-ND-")) {
+            Restrictiveness::NonCommercial
+        } else if is(NETWORK_COPYLEFT) {
+            Restrictiveness::NetworkCopyleft
+        } else if is(WEAK_COPYLEFT) {
+            Restrictiveness::WeakCopyleft
+        } else if self.is_copyleft() {
+            Restrictiveness::StrongCopyleft
+        } else {
+            Restrictiveness::Permissive
+        }
+    }
+
+    /// Returns the license, and optional exception, that replaced this license
+    /// if it is deprecated and has a direct successor in the SPDX license list
+    ///
+    /// See [`DEPRECATED_SUCCESSORS`](crate::identifiers::DEPRECATED_SUCCESSORS)
+    /// for the list of successors.
+    ///
+    /// ```
+    /// let (id, exc) = spdx::license_id("StandardML-NJ").unwrap().successor().unwrap();
+    /// assert_eq!(id, spdx::license_id("SMLNJ").unwrap());
+    /// assert!(exc.is_none());
+    ///
+    /// let (id, exc) = spdx::license_id("wxWindows").unwrap().successor().unwrap();
+    /// assert_eq!(id, spdx::license_id("LGPL-2.0-or-later").unwrap());
+    /// assert_eq!(exc, spdx::exception_id("WxWindows-exception-3.1"));
+    ///
+    /// let (id, _) = spdx::license_id("GPL-2.0").unwrap().successor().unwrap();
+    /// assert_eq!(id.name, "GPL-2.0-only");
+    ///
+    /// assert!(spdx::license_id("MIT").unwrap().successor().is_none());
+    /// ```
+    #[must_use]
+    pub fn successor(self) -> Option<(LicenseId, Option<ExceptionId>)> {
+        if !self.is_deprecated() {
+            return None;
+        }
+
+        let index = identifiers::DEPRECATED_SUCCESSORS
+            .binary_search_by(|(dep, ..)| dep.cmp(&self.name))
+            .ok()?;
+        let (_, id, exc) = identifiers::DEPRECATED_SUCCESSORS[index];
+
+        Some((
+            license_id(id).expect("unknown successor license"),
+            exc.map(|exc| exception_id(exc).expect("unknown successor exception")),
+        ))
+    }
+
+    /// Splits a deprecated license identifier that combines a license and an
+    /// exception into the license and exception that replaced it, ie. its
+    /// [successor](Self::successor) if that includes an exception.
+    ///
+    /// ```
+    /// let (id, exc) = spdx::license_id("GPL-2.0-with-classpath-exception")
+    ///     .unwrap()
+    ///     .decompose()
+    ///     .unwrap();
+    /// assert_eq!(id.name, "GPL-2.0-only");
+    /// assert_eq!(exc.name, "Classpath-exception-2.0");
+    ///
+    /// assert!(spdx::license_id("StandardML-NJ").unwrap().decompose().is_none());
+    /// ```
+    #[must_use]
+    pub fn decompose(self) -> Option<(LicenseId, ExceptionId)> {
+        match self.successor()? {
+            (id, Some(exc)) => Some((id, exc)),
+            (_, None) => None,
+        }
+    }
+
+    /// Returns the `-or-later` variant of a GNU license, ie. the license that
+    /// a `+` after it refers to. An `-or-later` license is its own variant.
+    /// Returns `None` for all other licenses, and for GNU licenses that don't
+    /// have an `-or-later` variant, eg. `GPL-2.0-with-GCC-exception`.
+    ///
+    /// ```
+    /// let or_later = |id| spdx::license_id(id).unwrap().to_or_later().map(|id| id.name);
+    ///
+    /// assert_eq!(or_later("GPL-2.0"), Some("GPL-2.0-or-later"));
+    /// assert_eq!(or_later("LGPL-2.1-only"), Some("LGPL-2.1-or-later"));
+    /// assert_eq!(or_later("GFDL-1.2-invariants"), Some("GFDL-1.2-invariants-or-later"));
+    /// assert_eq!(or_later("AGPL-3.0-or-later"), Some("AGPL-3.0-or-later"));
+    /// assert_eq!(or_later("GPL-2.0-with-classpath-exception"), None);
+    /// assert_eq!(or_later("Apache-2.0"), None);
+    /// ```
+    #[must_use]
+    pub fn to_or_later(self) -> Option<LicenseId> {
+        if !self.is_gnu() {
+            return None;
+        }
+
+        if self.name.ends_with("-or-later") {
+            return Some(self);
+        }
+
+        let root = self.name.strip_suffix("-only").unwrap_or(self.name);
+        license_id(&format!("{root}-or-later"))
+    }
+
+    /// Returns the `-only` variant of a root GNU license, eg. `GPL-2.0-only`
+    /// for `GPL-2.0`, or the license itself if it has no such variant
+    fn to_only(self) -> LicenseId {
+        if self.name.ends_with("-only") || self.name.ends_with("-or-later") {
+            return self;
+        }
+
+        license_id(&format!("{}-only", self.name)).unwrap_or(self)
+    }
+
+    /// Splits a [GNU Free Documentation License](https://www.gnu.org/licenses/fdl-1.3.html)
+    /// identifier into its version, invariant sections variant, and whether
+    /// it is an `-or-later` identifier. Returns `None` for all other licenses.
+    ///
+    /// ```
+    /// use spdx::{Gfdl, GfdlInvariants};
+    ///
+    /// assert_eq!(
+    ///     spdx::license_id("GFDL-1.2-no-invariants-or-later").unwrap().gfdl(),
+    ///     Some(Gfdl {
+    ///         version: "1.2",
+    ///         invariants: GfdlInvariants::NoInvariants,
+    ///         or_later: true,
+    ///     })
+    /// );
+    /// assert!(spdx::license_id("GPL-3.0").unwrap().gfdl().is_none());
+    /// ```
+    #[must_use]
+    pub fn gfdl(self) -> Option<Gfdl> {
+        let rest = self.name.strip_prefix("GFDL-")?;
+
+        let (rest, or_later) = rest
+            .strip_suffix("-or-later")
+            .map_or((rest, false), |rest| (rest, true));
+        let rest = rest.strip_suffix("-only").unwrap_or(rest);
+
+        let (version, invariants) = if let Some(version) = rest.strip_suffix("-no-invariants") {
+            (version, GfdlInvariants::NoInvariants)
+        } else if let Some(version) = rest.strip_suffix("-invariants") {
+            (version, GfdlInvariants::Invariants)
+        } else {
+            (rest, GfdlInvariants::Unspecified)
+        };
+
+        let (major, minor) = version.split_once('.')?;
+        major.parse::<u8>().ok()?;
+        minor.parse::<u8>().ok()?;
+
+        Some(Gfdl {
+            version,
+            invariants,
+            or_later,
+        })
+    }
+
     /// Attempts to retrieve the license text
     ///
     /// ```
@@ -130,6 +419,128 @@ impl LicenseId {
     pub fn text(self) -> &'static str {
         text::LICENSE_TEXTS[self.index].1
     }
+
+    /// Returns true if SPDX has an official text for the license, which is
+    /// the case for every license except `NOASSERTION`. Unlike
+    /// `LicenseId::text` this doesn't require the `text` feature.
+    ///
+    /// ```
+    /// assert!(spdx::license_id("MIT").unwrap().has_text());
+    /// assert!(!spdx::license_id("NOASSERTION").unwrap().has_text());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn has_text(self) -> bool {
+        self.text_len() != 0
+    }
+
+    /// The length, in bytes, of the license text, or 0 if the license has no
+    /// text. This doesn't require the `text` feature.
+    #[inline]
+    #[must_use]
+    pub fn text_len(self) -> usize {
+        identifiers::LICENSE_TEXT_INFO[self.index].0 as usize
+    }
+
+    /// The 64-bit FNV-1a hash of the exact bytes of the license text, or 0 if
+    /// the license has no text, eg. to detect that the upstream text has
+    /// changed without including the `text` feature. Note this is not the
+    /// same as the hash used by `detection::exact_match`, which ignores
+    /// whitespace and copyright notices.
+    ///
+    /// ```
+    /// let mit = spdx::license_id("MIT").unwrap();
+    /// let apache = spdx::license_id("Apache-2.0").unwrap();
+    ///
+    /// assert_ne!(mit.text_hash(), apache.text_hash());
+    /// assert_eq!(spdx::license_id("NOASSERTION").unwrap().text_hash(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn text_hash(self) -> u64 {
+        identifiers::LICENSE_TEXT_INFO[self.index].1
+    }
+}
+
+/// How restrictive a license is for the licensee, ordered from least to most
+/// restrictive, see [`LicenseId::restrictiveness`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Restrictiveness {
+    /// The license only has minimal conditions, eg. attribution, `MIT`
+    Permissive,
+    /// Modifications to the licensed files must be shared under the same
+    /// license, but not the larger work they are part of, eg. `MPL-2.0`
+    WeakCopyleft,
+    /// The larger work must also be shared under the same license, eg. `GPL-3.0-only`
+    StrongCopyleft,
+    /// As with [`Self::StrongCopyleft`], but also when the work is only
+    /// accessed over a network, eg. `AGPL-3.0-only`
+    NetworkCopyleft,
+    /// The license forbids commercial use, or derivative works, eg. `CC-BY-NC-4.0`
+    /// or `CC-BY-ND-4.0`
+    NonCommercial,
+    /// The license isn't known, ie. a `LicenseRef-`, or `NOASSERTION`
+    Unknown,
+}
+
+/// Whether a GFDL license has invariant sections
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GfdlInvariants {
+    /// The identifier doesn't specify, eg. `GFDL-1.3`
+    Unspecified,
+    /// The licensed work has invariant sections, eg. `GFDL-1.3-invariants`
+    Invariants,
+    /// The licensed work has no invariant sections, eg. `GFDL-1.3-no-invariants`
+    NoInvariants,
+}
+
+/// The components of a GFDL license identifier, see [`LicenseId::gfdl`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gfdl {
+    /// The version of the license, eg. `1.3`
+    pub version: &'static str,
+    /// Whether the licensed work has invariant sections
+    pub invariants: GfdlInvariants,
+    /// The identifier ends with `-or-later`
+    pub or_later: bool,
+}
+
+impl Gfdl {
+    /// Returns true if a licensee with this GFDL license satisfies a
+    /// requirement for the `req` GFDL license.
+    ///
+    /// A requirement with invariant sections is only satisfied by a licensee
+    /// with invariant sections, and a requirement without them, either
+    /// unspecified or `-no-invariants`, is never satisfied by a licensee with
+    /// them, as the obligations differ. `-no-invariants` is treated the same
+    /// as an unspecified variant, just as [`Licensee::parse`] does. The
+    /// version must match exactly, unless the requirement is `or_later` (or is
+    /// itself an `-or-later` identifier), in which case the licensee's version
+    /// must be the same or newer. The licensee's own `or_later` is ignored.
+    ///
+    /// ```
+    /// let gfdl = |name| spdx::license_id(name).unwrap().gfdl().unwrap();
+    ///
+    /// assert!(gfdl("GFDL-1.3-invariants").satisfies(gfdl("GFDL-1.2-invariants"), true));
+    /// assert!(!gfdl("GFDL-1.3-invariants").satisfies(gfdl("GFDL-1.2-invariants"), false));
+    /// assert!(!gfdl("GFDL-1.3-invariants").satisfies(gfdl("GFDL-1.2-or-later"), false));
+    /// assert!(!gfdl("GFDL-1.3-no-invariants").satisfies(gfdl("GFDL-1.2-invariants"), true));
+    /// assert!(gfdl("GFDL-1.3").satisfies(gfdl("GFDL-1.2-no-invariants"), true));
+    /// ```
+    #[must_use]
+    pub fn satisfies(self, req: Gfdl, or_later: bool) -> bool {
+        let has_invariants = |gfdl: Gfdl| gfdl.invariants == GfdlInvariants::Invariants;
+        if has_invariants(self) != has_invariants(req) {
+            return false;
+        }
+
+        let ord = version::compare_versions(self.version, req.version);
+        if or_later || req.or_later {
+            ord != std::cmp::Ordering::Less
+        } else {
+            ord == std::cmp::Ordering::Equal
+        }
+    }
 }
 
 impl fmt::Debug for LicenseId {
@@ -138,6 +549,41 @@ impl fmt::Debug for LicenseId {
     }
 }
 
+impl fmt::Display for LicenseId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Parses an exact, case-sensitive, SPDX short identifier, suggesting similar
+/// identifiers if it is not found
+///
+/// ```
+/// let id: spdx::LicenseId = "Apache-2.0".parse().unwrap();
+/// assert_eq!(id.to_string(), "Apache-2.0");
+///
+/// let err = "apache-2.0".parse::<spdx::LicenseId>().unwrap_err();
+/// assert_eq!(err.suggestions[0], "Apache-2.0");
+/// ```
+impl std::str::FromStr for LicenseId {
+    type Err = error::UnknownIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        license_id(s).ok_or_else(|| error::UnknownIdError {
+            input: s.to_owned(),
+            suggestions: suggest::licenses(s),
+        })
+    }
+}
+
+impl TryFrom<&str> for LicenseId {
+    type Error = error::UnknownIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 /// Unique identifier for a particular exception
 ///
 /// ```
@@ -149,7 +595,7 @@ pub struct ExceptionId {
     /// The short identifier for the exception
     pub name: &'static str,
     index: usize,
-    flags: u8,
+    flags: u16,
 }
 
 impl PartialEq for ExceptionId {
@@ -174,6 +620,51 @@ impl PartialOrd for ExceptionId {
 }
 
 impl ExceptionId {
+    /// Returns the exception at the specified position in
+    /// [`EXCEPTIONS`](crate::identifiers::EXCEPTIONS), or `None` if the index
+    /// is out of bounds, see [`ExceptionId::index`]
+    ///
+    /// ```
+    /// let llvm = spdx::exception_id("LLVM-exception").unwrap();
+    /// assert_eq!(spdx::ExceptionId::from_index(llvm.index()), Some(llvm));
+    /// assert!(spdx::ExceptionId::from_index(spdx::identifiers::EXCEPTION_COUNT).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_index(index: usize) -> Option<Self> {
+        identifiers::EXCEPTIONS
+            .get(index)
+            .map(|(name, flags)| Self {
+                name,
+                index,
+                flags: *flags,
+            })
+    }
+
+    /// The position of the exception in [`EXCEPTIONS`](crate::identifiers::EXCEPTIONS),
+    /// which is always less than [`EXCEPTION_COUNT`](crate::identifiers::EXCEPTION_COUNT).
+    ///
+    /// As with [`LicenseId::index`], this is only stable for the current
+    /// version of the SPDX license list.
+    #[inline]
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.index
+    }
+
+    /// The version of the SPDX license list the exception was added in, see
+    /// [`LicenseId::added_in`]
+    ///
+    /// ```
+    /// let llvm = spdx::exception_id("LLVM-exception").unwrap();
+    /// assert!(llvm.added_in().parse::<spdx::ListVersion>().is_ok());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn added_in(self) -> &'static str {
+        added_in(self.name)
+    }
+
     /// Returns true if the exception is deprecated
     ///
     /// ```
@@ -203,6 +694,41 @@ impl fmt::Debug for ExceptionId {
     }
 }
 
+impl fmt::Display for ExceptionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Parses an exact, case-sensitive, SPDX short identifier, suggesting similar
+/// identifiers if it is not found
+///
+/// ```
+/// let id: spdx::ExceptionId = "LLVM-exception".parse().unwrap();
+/// assert_eq!(id.to_string(), "LLVM-exception");
+///
+/// let err = "LLVM-exeption".parse::<spdx::ExceptionId>().unwrap_err();
+/// assert_eq!(err.suggestions[0], "LLVM-exception");
+/// ```
+impl std::str::FromStr for ExceptionId {
+    type Err = error::UnknownIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        exception_id(s).ok_or_else(|| error::UnknownIdError {
+            input: s.to_owned(),
+            suggestions: suggest::exceptions(s),
+        })
+    }
+}
+
+impl TryFrom<&str> for ExceptionId {
+    type Error = error::UnknownIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 /// Represents a single license requirement, which must include a valid
 /// [`LicenseItem`], and may allow current and future versions of the license,
 /// and may also allow for a specific exception
@@ -218,6 +744,218 @@ pub struct LicenseReq {
     pub exception: Option<ExceptionId>,
 }
 
+impl LicenseReq {
+    /// Creates a requirement for the license, without an exception. GNU
+    /// licenses are converted to their root license, with `or_later` set if
+    /// it was an `-or-later` license, just as when parsing an [`Expression`].
+    ///
+    /// ```
+    /// let req = spdx::LicenseReq::new(spdx::license_id("GPL-3.0-or-later").unwrap());
+    /// assert_eq!(req.license_id(), spdx::license_id("GPL-3.0"));
+    /// assert!(req.or_later());
+    /// ```
+    #[must_use]
+    pub fn new(id: LicenseId) -> Self {
+        Self::from(id)
+    }
+
+    /// Creates a requirement for a `LicenseRef-<lic_ref>`, with an optional
+    /// `DocumentRef-<doc_ref>`. The ids must not contain their prefixes, and
+    /// may only contain ASCII letters and digits, `-`, and `.`
+    ///
+    /// ```
+    /// let req = spdx::LicenseReq::license_ref(Some("Doc"), "Embark-1.0").unwrap();
+    /// assert_eq!(req.to_string(), "DocumentRef-Doc:LicenseRef-Embark-1.0");
+    ///
+    /// spdx::LicenseReq::license_ref(None, "Not a valid id").unwrap_err();
+    /// ```
+    pub fn license_ref(
+        doc_ref: Option<&str>,
+        lic_ref: &str,
+    ) -> Result<Self, error::LicenseReqError> {
+        Ok(Self {
+            license: LicenseItem::license_ref(doc_ref, lic_ref)?,
+            exception: None,
+        })
+    }
+
+    /// Adds an exception to the requirement, which fails if the requirement
+    /// already has an exception
+    ///
+    /// ```
+    /// let req = spdx::LicenseReq::new(spdx::license_id("Apache-2.0").unwrap())
+    ///     .with_exception(spdx::exception_id("LLVM-exception").unwrap())
+    ///     .unwrap();
+    /// assert_eq!(req.to_string(), "Apache-2.0 WITH LLVM-exception");
+    /// ```
+    pub fn with_exception(
+        mut self,
+        exception: ExceptionId,
+    ) -> Result<Self, error::LicenseReqError> {
+        if let Some(existing) = self.exception {
+            return Err(error::LicenseReqError::ExceptionAlreadySet(existing));
+        }
+
+        self.exception = Some(exception);
+        Ok(self)
+    }
+
+    /// Allows any later version of the license, ie. the same as a `+` in an
+    /// expression, or the `-or-later` variant of a GNU license.
+    ///
+    /// This fails for license refs, which don't have versions, and for the
+    /// deprecated GNU licenses that don't have an `-or-later` variant, eg.
+    /// `GPL-2.0-with-GCC-exception`.
+    ///
+    /// ```
+    /// let req = spdx::LicenseReq::new(spdx::license_id("LGPL-2.1").unwrap())
+    ///     .with_or_later()
+    ///     .unwrap();
+    /// assert_eq!(req.to_string(), "LGPL-2.1-or-later");
+    /// ```
+    pub fn with_or_later(mut self) -> Result<Self, error::LicenseReqError> {
+        match &mut self.license {
+            LicenseItem::Spdx { id, or_later } => {
+                if id.is_gnu() && (id.name.ends_with("-only") || id.to_or_later().is_none()) {
+                    return Err(error::LicenseReqError::OrLaterNotAllowed);
+                }
+
+                *or_later = true;
+                Ok(self)
+            }
+            LicenseItem::Other { .. } => Err(error::LicenseReqError::OrLaterNotAllowed),
+        }
+    }
+
+    /// Parses a single license requirement, ie. the same as [`Licensee::parse_mode`],
+    /// but also allowing the `+`, and GNU licenses with the `-only` and
+    /// `-or-later` suffixes, that a license holder can use.
+    ///
+    /// ```
+    /// use spdx::{LicenseReq, ParseMode};
+    ///
+    /// let req = LicenseReq::parse("Apache-2.0+ WITH LLVM-exception", ParseMode::STRICT).unwrap();
+    /// assert!(req.or_later());
+    ///
+    /// let req = LicenseReq::parse("gpl-2.0+", ParseMode::LAX).unwrap();
+    /// assert_eq!(req.to_string(), "GPL-2.0-or-later");
+    ///
+    /// LicenseReq::parse("MIT OR Apache-2.0", ParseMode::STRICT).unwrap_err();
+    /// ```
+    pub fn parse(original: &str, mode: ParseMode) -> Result<Self, ParseError> {
+        use error::Reason;
+        use lexer::{Lexer, Token};
+
+        let err = |span, reason| {
+            Err(ParseError {
+                original: original.to_owned(),
+                mode,
+                span,
+                reason,
+            })
+        };
+
+        let mut last = None;
+        let mut req: Option<Self> = None;
+
+        for lt in Lexer::new_mode(original, mode) {
+            let lt = lt?;
+
+            match (&lt.token, &last) {
+                (Token::Spdx(id), None) => req = Some(Self::from(*id)),
+                (Token::LicenseRef { doc_ref, lic_ref }, None) => {
+                    req = Some(Self {
+                        license: LicenseItem::Other {
+                            doc_ref: doc_ref.map(String::from),
+                            lic_ref: (*lic_ref).to_owned(),
+                        },
+                        exception: None,
+                    });
+                }
+                (Token::Plus, Some(Token::Spdx(written))) => {
+                    if written.is_gnu() {
+                        if !mode.allow_postfix_plus_on_gpl {
+                            return err(lt.span, Reason::GnuNoPlus);
+                        } else if written.name.ends_with("-only") {
+                            return err(lt.span, Reason::GnuPlusWithSuffix);
+                        }
+                    }
+
+                    if let Some(LicenseItem::Spdx { or_later, .. }) =
+                        req.as_mut().map(|req| &mut req.license)
+                    {
+                        *or_later = true;
+                    }
+                }
+                (Token::With, Some(Token::Spdx(_) | Token::LicenseRef { .. } | Token::Plus)) => {}
+                (Token::Exception(exc), Some(Token::With)) => {
+                    if let Some(req) = &mut req {
+                        req.exception = Some(*exc);
+                    }
+                }
+                (Token::And | Token::Or, Some(_)) => {
+                    return err(lt.span, Reason::ExpressionNotAllowed);
+                }
+                (_, None) => return err(lt.span, Reason::Unexpected(&["<license>"])),
+                (_, Some(Token::Spdx(_))) => {
+                    return err(lt.span, Reason::Unexpected(&["+", "WITH"]));
+                }
+                (_, Some(Token::LicenseRef { .. } | Token::Plus)) => {
+                    return err(lt.span, Reason::Unexpected(&["WITH"]));
+                }
+                (_, Some(Token::With)) => {
+                    return err(lt.span, Reason::Unexpected(&["<exception>"]));
+                }
+                (_, Some(_)) => return err(lt.span, Reason::Unexpected(&[])),
+            }
+
+            last = Some(lt.token);
+        }
+
+        match (req, last) {
+            (None, _) => err(0..original.len(), Reason::Empty),
+            (Some(_), Some(Token::With)) => err(
+                original.len()..original.len(),
+                Reason::Unexpected(&["<exception>"]),
+            ),
+            (Some(req), _) => Ok(req),
+        }
+    }
+
+    /// Returns true if the license is an SPDX license that allows any later
+    /// version, ie. it had a `+`, or is a GNU `-or-later` license. Always
+    /// false for license refs.
+    ///
+    /// ```
+    /// let expr = spdx::Expression::parse("GPL-3.0-or-later AND Apache-2.0+ AND MIT").unwrap();
+    /// let or_later: Vec<_> = expr.requirements().map(|er| er.req.or_later()).collect();
+    /// assert_eq!(or_later, [true, true, false]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn or_later(&self) -> bool {
+        match self.license {
+            LicenseItem::Spdx { or_later, .. } => or_later,
+            LicenseItem::Other { .. } => false,
+        }
+    }
+
+    /// Returns the license identifier, if it is a recognized SPDX license and
+    /// not a license ref
+    #[inline]
+    #[must_use]
+    pub fn license_id(&self) -> Option<LicenseId> {
+        self.license.id()
+    }
+
+    /// Returns the exception identifier, if any
+    #[inline]
+    #[must_use]
+    pub fn exception_id(&self) -> Option<ExceptionId> {
+        self.exception
+    }
+}
+
 impl From<LicenseId> for LicenseReq {
     fn from(id: LicenseId) -> Self {
         // We need to special case GNU licenses because reasons
@@ -248,8 +986,37 @@ impl From<LicenseId> for LicenseReq {
     }
 }
 
+/// Displays the license and its exception. With the alternate flag, `{:#}`,
+/// GNU licenses are written with their `-only` or `-or-later` suffix, and a
+/// deprecated GNU license that combines a license and an exception is written
+/// as the `-or-later` variant of the license `WITH` the exception if it has a
+/// `+`, the same as [`Expression::canonicalize`](crate::Expression::canonicalize)
+///
+/// ```
+/// let req = spdx::LicenseReq::parse("GPL-2.0-only", spdx::ParseMode::STRICT).unwrap();
+/// assert_eq!(req.to_string(), "GPL-2.0");
+/// assert_eq!(format!("{req:#}"), "GPL-2.0-only");
+///
+/// let req = spdx::LicenseReq::parse("GPL-2.0-with-classpath-exception+", spdx::ParseMode::LAX)
+///     .unwrap();
+/// assert_eq!(format!("{req:#}"), "GPL-2.0-or-later WITH Classpath-exception-2.0");
+/// ```
 impl fmt::Display for LicenseReq {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        if let (true, LicenseItem::Spdx { id, or_later: true }, None) =
+            (f.alternate(), &self.license, self.exception)
+        {
+            if id.is_gnu() && id.to_or_later().is_none() {
+                if let Some((base, exc)) = id.decompose() {
+                    let license = LicenseItem::Spdx {
+                        id: base,
+                        or_later: true,
+                    };
+                    return write!(f, "{license:#} WITH {}", exc.name);
+                }
+            }
+        }
+
         self.license.fmt(f)?;
 
         if let Some(ref exe) = self.exception {
@@ -260,6 +1027,22 @@ impl fmt::Display for LicenseReq {
     }
 }
 
+/// Checks that the id of a `DocumentRef-` or `LicenseRef-`, without its prefix,
+/// is a valid SPDX idstring, ie. `[-a-zA-Z0-9.]+`
+fn validate_ref_id(id: &str) -> Result<(), error::LicenseReqError> {
+    if id.is_empty() {
+        return Err(error::LicenseReqError::EmptyRefId);
+    }
+
+    match id
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '.')
+    {
+        Some(c) => Err(error::LicenseReqError::InvalidRefCharacter(c)),
+        None => Ok(()),
+    }
+}
+
 /// A single license term in a license expression, according to the SPDX spec.
 /// This can be either an SPDX license, which is mapped to a [`LicenseId`] from
 /// a valid SPDX short identifier, or else a document AND/OR license ref
@@ -285,6 +1068,60 @@ pub enum LicenseItem {
 }
 
 impl LicenseItem {
+    /// Creates a `LicenseRef-<lic_ref>`, with an optional
+    /// `DocumentRef-<doc_ref>`. The ids must not contain their prefixes, and,
+    /// as with all SPDX idstrings, must not be empty and may only contain
+    /// ASCII letters and digits, `-`, and `.`
+    ///
+    /// ```
+    /// let item = spdx::LicenseItem::license_ref(None, "Embark-1.0").unwrap();
+    /// assert_eq!(item.to_string(), "LicenseRef-Embark-1.0");
+    ///
+    /// spdx::LicenseItem::license_ref(Some("My Doc"), "Embark-1.0").unwrap_err();
+    /// ```
+    pub fn license_ref(
+        doc_ref: Option<&str>,
+        lic_ref: &str,
+    ) -> Result<Self, error::LicenseReqError> {
+        if let Some(doc_ref) = doc_ref {
+            validate_ref_id(doc_ref)?;
+        }
+        validate_ref_id(lic_ref)?;
+
+        Ok(Self::Other {
+            doc_ref: doc_ref.map(String::from),
+            lic_ref: lic_ref.to_owned(),
+        })
+    }
+
+    /// Checks that the ids of a license ref are valid SPDX idstrings, as the
+    /// fields of [`LicenseItem::Other`] can be set to anything, which would
+    /// otherwise be displayed as an invalid expression. SPDX licenses are
+    /// always valid.
+    ///
+    /// ```
+    /// let item = spdx::LicenseItem::Other {
+    ///     doc_ref: None,
+    ///     lic_ref: "has spaces!".to_owned(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     item.validate(),
+    ///     Err(spdx::error::LicenseReqError::InvalidRefCharacter(' '))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), error::LicenseReqError> {
+        match self {
+            Self::Spdx { .. } => Ok(()),
+            Self::Other { doc_ref, lic_ref } => {
+                if let Some(doc_ref) = doc_ref {
+                    validate_ref_id(doc_ref)?;
+                }
+                validate_ref_id(lic_ref)
+            }
+        }
+    }
+
     /// Returns the license identifier, if it is a recognized SPDX license and not
     /// a license referencer
     #[must_use]
@@ -332,32 +1169,31 @@ impl Ord for LicenseItem {
 }
 
 impl PartialOrd for LicenseItem {
-    #[allow(clippy::non_canonical_partial_ord_impl)]
     fn partial_cmp(&self, o: &Self) -> Option<Ordering> {
-        match (self, o) {
-            (Self::Spdx { id: a, .. }, Self::Spdx { id: b, .. }) => a.partial_cmp(b),
-            (
-                Self::Other {
-                    doc_ref: ad,
-                    lic_ref: al,
-                },
-                Self::Other {
-                    doc_ref: bd,
-                    lic_ref: bl,
-                },
-            ) => match ad.cmp(bd) {
-                Ordering::Equal => al.partial_cmp(bl),
-                o => Some(o),
-            },
-            (Self::Spdx { .. }, Self::Other { .. }) => Some(cmp::Ordering::Less),
-            (Self::Other { .. }, Self::Spdx { .. }) => Some(cmp::Ordering::Greater),
-        }
+        Some(self.cmp(o))
     }
 }
 
+/// Two items are only equal if they are the same license, with the same
+/// `or_later`, so `Apache-2.0` and `Apache-2.0+` are not equal, and
+/// `Apache-2.0` is ordered before `Apache-2.0+`
+///
+/// ```
+/// let apache = spdx::LicenseItem::Spdx {
+///     id: spdx::license_id("Apache-2.0").unwrap(),
+///     or_later: false,
+/// };
+/// let apache_later = spdx::LicenseItem::Spdx {
+///     id: spdx::license_id("Apache-2.0").unwrap(),
+///     or_later: true,
+/// };
+///
+/// assert_ne!(apache, apache_later);
+/// assert!(apache < apache_later);
+/// ```
 impl PartialEq for LicenseItem {
     fn eq(&self, o: &Self) -> bool {
-        matches!(self.partial_cmp(o), Some(cmp::Ordering::Equal))
+        self.cmp(o) == Ordering::Equal
     }
 }
 
@@ -365,17 +1201,28 @@ impl fmt::Display for LicenseItem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match self {
             LicenseItem::Spdx { id, or_later } => {
-                id.name.fmt(f)?;
-
-                if *or_later {
-                    if id.is_gnu() && id.is_deprecated() {
-                        f.write_str("-or-later")?;
-                    } else if !id.is_gnu() {
+                if !id.is_gnu() {
+                    id.name.fmt(f)?;
+                    if *or_later {
                         f.write_str("+")?;
                     }
+
+                    return Ok(());
                 }
 
-                Ok(())
+                // The root GNU licenses, eg. `GPL-2.0`, or `GFDL-1.1-invariants`,
+                // don't have a suffix yet, and the ones that combine a license
+                // and an exception have no `-or-later` variant
+                if *or_later {
+                    match id.to_or_later() {
+                        Some(or_later) => or_later.name.fmt(f),
+                        None => write!(f, "{}+", id.name),
+                    }
+                } else if f.alternate() {
+                    id.to_only().name.fmt(f)
+                } else {
+                    id.name.fmt(f)
+                }
             }
             LicenseItem::Other {
                 doc_ref: Some(d),
@@ -414,28 +1261,153 @@ pub fn license_id(name: &str) -> Option<LicenseId> {
         .ok()
 }
 
+/// How the pattern of an [`ImpreciseName`] is matched
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MatchKind {
+    /// The pattern matches the start of the input, regardless of what follows
+    Prefix,
+    /// The pattern only matches if it is followed by the end of the input or
+    /// a character that can't be part of a license identifier, eg. whitespace,
+    /// `/` or `)`
+    Exact,
+}
+
+/// An invalid/imprecise license name that is accepted in place of a valid
+/// SPDX license identifier when using [`ParseMode::LAX`].
+///
+/// Patterns are matched case-insensitively.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ImpreciseName {
+    /// The imprecise name, in lowercase
+    pub pattern: &'static str,
+    /// How the pattern is matched
+    pub kind: MatchKind,
+    /// The valid SPDX license identifier the name is a synonym for
+    pub id: &'static str,
+}
+
+impl ImpreciseName {
+    /// Creates an imprecise name that matches the start of the input
+    #[must_use]
+    pub const fn prefix(pattern: &'static str, id: &'static str) -> Self {
+        Self {
+            pattern,
+            kind: MatchKind::Prefix,
+            id,
+        }
+    }
+
+    /// Creates an imprecise name that only matches a whole word
+    #[must_use]
+    pub const fn exact(pattern: &'static str, id: &'static str) -> Self {
+        Self {
+            pattern,
+            kind: MatchKind::Exact,
+            id,
+        }
+    }
+
+    /// Returns the length, in bytes, of the match if this name matches the
+    /// start of the input
+    fn matches(&self, name: &str) -> Option<usize> {
+        let len = self.pattern.len();
+        let name_prefix = name.as_bytes().get(0..len)?;
+
+        if !self.pattern.as_bytes().eq_ignore_ascii_case(name_prefix) {
+            return None;
+        }
+
+        match self.kind {
+            MatchKind::Prefix => Some(len),
+            MatchKind::Exact => match name.as_bytes().get(len) {
+                Some(c) if c.is_ascii_alphanumeric() || *c == b'-' || *c == b'.' => None,
+                _ => Some(len),
+            },
+        }
+    }
+}
+
+/// Returns each of the imprecise license names that are accepted with
+/// [`ParseMode::LAX`], longest pattern first
+///
+/// ```
+/// assert!(spdx::imprecise_names().any(|name| name.pattern == "apache2" && name.id == "Apache-2.0"));
+/// ```
+pub fn imprecise_names() -> impl Iterator<Item = &'static ImpreciseName> {
+    identifiers::IMPRECISE_NAMES.iter()
+}
+
 /// Find license partially matching the name, e.g. "apache" => "Apache-2.0"
 ///
 /// Returns length (in bytes) of the string matched. Garbage at the end is
-/// ignored. See
+/// ignored, unless the name is [`MatchKind::Exact`]. If more than one name
+/// matches, the longest one is used. See
 /// [`identifiers::IMPRECISE_NAMES`](identifiers/constant.IMPRECISE_NAMES.html)
 /// for the list of invalid names, and the valid license identifiers they are
 /// paired with.
 ///
 /// ```
 /// assert!(spdx::imprecise_license_id("simplified bsd license").unwrap().0 == spdx::license_id("BSD-2-Clause").unwrap());
+/// assert_eq!(spdx::imprecise_license_id("gpl-3.0").unwrap(), (spdx::license_id("GPL-3.0").unwrap(), 7));
+/// assert!(spdx::imprecise_license_id("gplfoo").is_none());
 /// ```
 #[inline]
 #[must_use]
 pub fn imprecise_license_id(name: &str) -> Option<(LicenseId, usize)> {
-    for (prefix, correct_name) in identifiers::IMPRECISE_NAMES {
-        if let Some(name_prefix) = name.as_bytes().get(0..prefix.len()) {
-            if prefix.as_bytes().eq_ignore_ascii_case(name_prefix) {
-                return license_id(correct_name).map(|lic| (lic, prefix.len()));
+    find_imprecise_license_id(name, false)
+}
+
+/// Find the license whose imprecise name matches the whole name, ignoring
+/// leading and trailing whitespace, unlike [`imprecise_license_id`] which
+/// ignores anything after the match
+///
+/// ```
+/// let apache = spdx::license_id("Apache-2.0").unwrap();
+///
+/// assert_eq!(spdx::imprecise_license_id_exact(" Apache License "), Some(apache));
+/// assert_eq!(spdx::imprecise_license_id_exact("apache2"), Some(apache));
+///
+/// assert_eq!(spdx::imprecise_license_id("apache software").unwrap().0, apache);
+/// assert!(spdx::imprecise_license_id_exact("apache software").is_none());
+/// ```
+#[must_use]
+pub fn imprecise_license_id_exact(name: &str) -> Option<LicenseId> {
+    let name = name.trim();
+    identifiers::IMPRECISE_NAMES
+        .iter()
+        .find(|imprecise| imprecise.pattern.eq_ignore_ascii_case(name))
+        .and_then(|imprecise| license_id(imprecise.id))
+}
+
+/// Looks up the version of the license list a license or exception was added in
+fn added_in(id: &str) -> &'static str {
+    identifiers::ADDED_IN
+        .binary_search_by(|(added, _)| (*added).cmp(id))
+        .map_or(identifiers::EARLIEST_ADDED_IN, |i| {
+            identifiers::ADDED_IN[i].1
+        })
+}
+
+/// Finds the longest imprecise name matching the start of the name, which
+/// optionally must be followed by a token boundary
+pub(crate) fn find_imprecise_license_id(
+    name: &str,
+    at_boundary: bool,
+) -> Option<(LicenseId, usize)> {
+    // The names are sorted longest first, so the first match is the longest
+    identifiers::IMPRECISE_NAMES.iter().find_map(|imprecise| {
+        let len = imprecise.matches(name)?;
+
+        if at_boundary {
+            match name.as_bytes().get(len) {
+                None | Some(b'/' | b'(' | b')' | b'+') => {}
+                Some(c) if c.is_ascii_whitespace() => {}
+                Some(_) => return None,
             }
         }
-    }
-    None
+
+        license_id(imprecise.id).map(|lic| (lic, len))
+    })
 }
 
 /// Attempts to find an [`ExceptionId`] for the string
@@ -467,6 +1439,140 @@ pub fn license_version() -> &'static str {
     identifiers::VERSION
 }
 
+/// The version of the SPDX license list from which the license and exception
+/// identifiers are sourced from, see [`license_list_version`]
+///
+/// ```
+/// let version: spdx::ListVersion = "3.19".parse().unwrap();
+/// assert_eq!(version, spdx::ListVersion { major: 3, minor: 19, patch: 0 });
+/// assert_eq!(version.to_string(), "3.19.0");
+///
+/// // Elastic-2.0 was added in 3.19
+/// assert!(spdx::license_list_version() >= version);
+/// assert!(spdx::license_id("Elastic-2.0").is_some());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ListVersion {
+    /// The major version, only incremented for breaking changes to the list
+    pub major: u16,
+    /// The minor version, incremented for each regular release of the list
+    pub minor: u16,
+    /// Older versions of the list, eg. `3.6`, don't have a patch version, in
+    /// which case it is `0`
+    pub patch: u16,
+}
+
+impl ListVersion {
+    /// Parses a `<major>.<minor>[.<patch>]` version, returning `None` if it
+    /// is not a valid version
+    #[must_use]
+    pub const fn parse(version: &str) -> Option<Self> {
+        let bytes = version.as_bytes();
+        let mut parts = [0u16; 3];
+        let mut part = 0;
+        let mut digits = 0;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            if b == b'.' {
+                if digits == 0 || part == 2 {
+                    return None;
+                }
+                part += 1;
+                digits = 0;
+            } else if b.is_ascii_digit() {
+                parts[part] = match parts[part].checked_mul(10) {
+                    Some(n) => match n.checked_add((b - b'0') as u16) {
+                        Some(n) => n,
+                        None => return None,
+                    },
+                    None => return None,
+                };
+                digits += 1;
+            } else {
+                return None;
+            }
+
+            i += 1;
+        }
+
+        if digits == 0 || part == 0 {
+            return None;
+        }
+
+        Some(Self {
+            major: parts[0],
+            minor: parts[1],
+            patch: parts[2],
+        })
+    }
+}
+
+impl fmt::Display for ListVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The error returned when a [`ListVersion`] can't be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListVersionError {
+    /// The string that failed to parse
+    pub original: String,
+}
+
+impl fmt::Display for ListVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not a valid license list version, expected <major>.<minor>[.<patch>]",
+            self.original
+        )
+    }
+}
+
+impl std::error::Error for ListVersionError {}
+
+impl std::str::FromStr for ListVersion {
+    type Err = ListVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| ListVersionError {
+            original: s.to_owned(),
+        })
+    }
+}
+
+const LIST_VERSION: ListVersion = match ListVersion::parse(identifiers::VERSION) {
+    Some(version) => version,
+    None => panic!("the license list version is invalid"),
+};
+
+/// Returns the version of the SPDX list from which the license and exception
+/// identifiers are sourced from, in a form that can be compared
+///
+/// ```
+/// assert_eq!(spdx::license_list_version().to_string(), spdx::license_version());
+/// ```
+#[inline]
+#[must_use]
+pub fn license_list_version() -> ListVersion {
+    LIST_VERSION
+}
+
+/// Returns the date, formatted as `YYYY-MM-DD`, that the SPDX list from which
+/// the license and exception identifiers are sourced from was released
+///
+/// ```
+/// assert_eq!(spdx::license_list_release_date(), "2024-12-30");
+/// ```
+#[inline]
+#[must_use]
+pub fn license_list_release_date() -> &'static str {
+    identifiers::RELEASE_DATE
+}
+
 #[cfg(test)]
 mod test {
     use super::LicenseItem;
@@ -1,9 +1,10 @@
 use crate::{
     error::{ParseError, Reason},
     lexer::{Lexer, Token},
-    ExceptionId, LicenseItem, LicenseReq,
+    version::compare_versions,
+    ExceptionId, LicenseId, LicenseItem, LicenseReq, ParseMode,
 };
-use std::fmt;
+use std::{cmp::Ordering, fmt};
 
 /// A convenience wrapper for a license and optional exception that can be
 /// checked against a license requirement to see if it satisfies the requirement
@@ -33,15 +34,25 @@ impl std::str::FromStr for Licensee {
     }
 }
 
+impl TryFrom<&str> for Licensee {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
 impl Licensee {
     /// Creates a licensee from its component parts. Note that use of SPDX's
     /// `or_later` is completely ignored for licensees as it only applies
-    /// to the license holder(s), not the licensee
+    /// to the license holder(s), not the licensee. License refs should be
+    /// created with [`LicenseItem::license_ref`] so that they are valid.
     #[must_use]
     pub fn new(license: LicenseItem, exception: Option<ExceptionId>) -> Self {
         if let LicenseItem::Spdx { or_later, .. } = &license {
             debug_assert!(!or_later);
         }
+        debug_assert!(license.validate().is_ok(), "{license:?}");
 
         Self {
             inner: LicenseReq { license, exception },
@@ -77,14 +88,36 @@ impl Licensee {
     /// Licensee::parse("GFDL-1.3-invariants").unwrap();
     /// ```
     pub fn parse(original: &str) -> Result<Self, ParseError> {
-        let mut lexer = Lexer::new(original);
+        Self::parse_mode(original, ParseMode::STRICT)
+    }
+
+    /// Parses a licensee with the specified [`ParseMode`], see
+    /// [`Licensee::parse`] for details.
+    ///
+    /// ```
+    /// use spdx::{Licensee, ParseMode};
+    ///
+    /// assert_eq!(
+    ///     Licensee::parse_mode("apache2 with LLVM-exception", ParseMode::LAX).unwrap(),
+    ///     Licensee::parse("Apache-2.0 WITH LLVM-exception").unwrap(),
+    /// );
+    ///
+    /// // Licensees are a single license, not an expression
+    /// Licensee::parse_mode("MIT/Apache-2.0", ParseMode::LAX).unwrap_err();
+    /// ```
+    pub fn parse_mode(original: &str, mode: ParseMode) -> Result<Self, ParseError> {
+        let mut lexer = Lexer::new_mode(original, mode);
 
         let license = {
-            let lt = lexer.next().ok_or_else(|| ParseError {
-                original: original.to_owned(),
-                span: 0..original.len(),
-                reason: Reason::Empty,
-            })??;
+            let lt = lexer
+                .next()
+                .ok_or_else(|| ParseError {
+                    original: original.to_owned(),
+                    mode,
+                    span: 0..original.len(),
+                    reason: Reason::Empty,
+                })?
+                .map_err(|err| err.unknown_as(Reason::UnknownLicense))?;
 
             match lt.token {
                 Token::Spdx(id) => {
@@ -94,18 +127,19 @@ impl Licensee {
                     // are for the license holder(s) to specify what license(s)
                     // they can be licensed under, not for the licensee,
                     // similarly to the `+`
+                    //
+                    // The checks use the text of the token rather than the
+                    // whole input, which can eg. be quoted, or have an exception
                     if id.is_gnu() {
-                        let is_only = original.ends_with("-only");
-                        let or_later = original.ends_with("-or-later");
+                        let suffix = ["-only", "-or-later"]
+                            .iter()
+                            .find(|suffix| lt.text.ends_with(*suffix));
 
-                        if is_only || or_later {
+                        if let Some(suffix) = suffix {
                             return Err(ParseError {
                                 original: original.to_owned(),
-                                span: if is_only {
-                                    original.len() - 5..original.len()
-                                } else {
-                                    original.len() - 9..original.len()
-                                },
+                                mode,
+                                span: lt.span.end - suffix.len()..lt.span.end,
                                 reason: Reason::Unexpected(&["<bare-gnu-license>"]),
                             });
                         }
@@ -116,10 +150,11 @@ impl Licensee {
                         // However, the `invariants`...variant we do allow since
                         // it is a modifier on the license...and should therefore
                         // by a WITH exception but GNU licenses are the worst
-                        if original.starts_with("GFDL") && original.contains("-no-invariants") {
+                        if lt.text.starts_with("GFDL") && lt.text.contains("-no-invariants") {
                             return Err(ParseError {
                                 original: original.to_owned(),
-                                span: 8..original.len(),
+                                mode,
+                                span: lt.span.start + 8..lt.span.end,
                                 reason: Reason::Unexpected(&["<bare-gfdl-license>"]),
                             });
                         }
@@ -134,9 +169,18 @@ impl Licensee {
                     doc_ref: doc_ref.map(String::from),
                     lic_ref: lic_ref.to_owned(),
                 },
+                Token::OpenParen => {
+                    return Err(ParseError {
+                        original: original.to_owned(),
+                        mode,
+                        span: lt.span,
+                        reason: Reason::ExpressionNotAllowed,
+                    })
+                }
                 _ => {
                     return Err(ParseError {
                         original: original.to_owned(),
+                        mode,
                         span: lt.span,
                         reason: Reason::Unexpected(&["<license>"]),
                     })
@@ -150,26 +194,40 @@ impl Licensee {
                 let lt = lt?;
                 match lt.token {
                     Token::With => {
-                        let lt = lexer.next().ok_or(ParseError {
-                            original: original.to_owned(),
-                            span: lt.span,
-                            reason: Reason::Empty,
-                        })??;
+                        let lt = lexer
+                            .next()
+                            .ok_or(ParseError {
+                                original: original.to_owned(),
+                                mode,
+                                span: lt.span,
+                                reason: Reason::Empty,
+                            })?
+                            .map_err(|err| err.unknown_as(Reason::UnknownException))?;
 
                         match lt.token {
                             Token::Exception(exc) => Some(exc),
                             _ => {
                                 return Err(ParseError {
                                     original: original.to_owned(),
+                                    mode,
                                     span: lt.span,
                                     reason: Reason::Unexpected(&["<exception>"]),
                                 })
                             }
                         }
                     }
+                    Token::And | Token::Or | Token::OpenParen => {
+                        return Err(ParseError {
+                            original: original.to_owned(),
+                            mode,
+                            span: lt.span,
+                            reason: Reason::ExpressionNotAllowed,
+                        })
+                    }
                     _ => {
                         return Err(ParseError {
                             original: original.to_owned(),
+                            mode,
                             span: lt.span,
                             reason: Reason::Unexpected(&["WITH"]),
                         })
@@ -178,14 +236,86 @@ impl Licensee {
             }
         };
 
+        // Nothing is allowed after the exception
+        if let Some(lt) = lexer.next() {
+            let lt = lt?;
+            return Err(ParseError {
+                original: original.to_owned(),
+                mode,
+                span: lt.span,
+                reason: if matches!(lt.token, Token::And | Token::Or | Token::OpenParen) {
+                    Reason::ExpressionNotAllowed
+                } else {
+                    Reason::Unexpected(&[])
+                },
+            });
+        }
+
         Ok(Licensee {
             inner: LicenseReq { license, exception },
         })
     }
 
+    /// Parses a list of licensees separated by commas and/or newlines, eg.
+    /// `MIT, Apache-2.0 WITH LLVM-exception`, using the specified [`ParseMode`]
+    /// for each licensee.
+    ///
+    /// Whitespace around each licensee is ignored, as are empty entries, eg.
+    /// a trailing comma. If a licensee fails to parse, the error's span is
+    /// relative to the entire list.
+    ///
+    /// ```
+    /// use spdx::{Licensee, ParseMode};
+    ///
+    /// let licensees = Licensee::parse_list(
+    ///     "MIT, Apache-2.0 WITH LLVM-exception,\nUnicode-DFS-2016,",
+    ///     ParseMode::STRICT,
+    /// ).unwrap();
+    /// assert_eq!(licensees.len(), 3);
+    ///
+    /// let err = Licensee::parse_list("MIT, Apache-2.0 OR ISC", ParseMode::STRICT).unwrap_err();
+    /// assert_eq!(err.span, 16..18);
+    /// assert_eq!(err.reason, spdx::error::Reason::ExpressionNotAllowed);
+    /// ```
+    pub fn parse_list(original: &str, mode: ParseMode) -> Result<Vec<Self>, ParseError> {
+        let mut licensees = Vec::new();
+        let mut offset = 0;
+
+        for entry in original.split([',', '\n']) {
+            let start = offset;
+            offset += entry.len() + 1;
+
+            // Keep track of the leading whitespace so that error spans are
+            // correct for the entire list
+            let trimmed = entry.trim_start();
+            let start = start + entry.len() - trimmed.len();
+            let trimmed = trimmed.trim_end();
+
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            let licensee = Self::parse_mode(trimmed, mode).map_err(|err| ParseError {
+                original: original.to_owned(),
+                mode,
+                span: err.span.start + start..err.span.end + start,
+                reason: err.reason,
+            })?;
+
+            licensees.push(licensee);
+        }
+
+        Ok(licensees)
+    }
+
     /// Determines whether the specified license requirement is satisfied by
     /// this license (+exception)
     ///
+    /// GFDL licenses are matched according to [`Gfdl::satisfies`](crate::Gfdl::satisfies),
+    /// so eg. a `GFDL-1.2-invariants-or-later` requirement is satisfied by a
+    /// `GFDL-1.3-invariants` licensee, but not by `GFDL-1.3` or
+    /// `GFDL-1.3-no-invariants`.
+    ///
     /// ```
     /// let licensee = spdx::Licensee::parse("Apache-2.0 WITH LLVM-exception").unwrap();
     ///
@@ -203,39 +333,35 @@ impl Licensee {
         match (&self.inner.license, &req.license) {
             (LicenseItem::Spdx { id: a, .. }, LicenseItem::Spdx { id: b, or_later }) => {
                 if a.index != b.index {
-                    if *or_later {
-                        let (a_name, a_gfdl_invariants) = if a.name.starts_with("GFDL") {
-                            a.name
-                                .strip_suffix("-invariants")
-                                .map_or((a.name, false), |name| (name, true))
-                        } else {
-                            (a.name, false)
-                        };
-
-                        let (b_name, b_gfdl_invariants) = if b.name.starts_with("GFDL") {
-                            b.name
-                                .strip_suffix("-invariants")
-                                .map_or((b.name, false), |name| (name, true))
-                        } else {
-                            (b.name, false)
-                        };
-
-                        if a_gfdl_invariants != b_gfdl_invariants {
-                            return false;
+                    // The GFDL identifiers encode more than just a version,
+                    // so they have their own rules
+                    match (a.gfdl(), b.gfdl()) {
+                        (Some(a), Some(b)) => {
+                            if !a.satisfies(b, *or_later) {
+                                return false;
+                            }
                         }
+                        (Some(_), None) | (None, Some(_)) => return false,
+                        (None, None) => {
+                            if !*or_later {
+                                return false;
+                            }
 
-                        // Many of the SPDX identifiers end with `-<version number>`,
-                        // so chop that off and ensure the base strings match, and if so,
-                        // just a do a lexical compare, if this "allowed license" is >,
-                        // then we satisfed the license requirement
-                        let a_test_name = &a_name[..a_name.rfind('-').unwrap_or(a_name.len())];
-                        let b_test_name = &b_name[..b_name.rfind('-').unwrap_or(b_name.len())];
-
-                        if a_test_name != b_test_name || a_name < b_name {
-                            return false;
+                            // Many of the SPDX identifiers end with `-<version number>`,
+                            // so chop that off and ensure the base strings match, and if so,
+                            // compare the versions, if this "allowed license" is >=,
+                            // then we satisfed the license requirement
+                            let (a_base, a_version) =
+                                a.name.rsplit_once('-').unwrap_or((a.name, ""));
+                            let (b_base, b_version) =
+                                b.name.rsplit_once('-').unwrap_or((b.name, ""));
+
+                            if a_base != b_base
+                                || compare_versions(a_version, b_version) == Ordering::Less
+                            {
+                                return false;
+                            }
                         }
-                    } else {
-                        return false;
                     }
                 }
             }
@@ -259,23 +385,111 @@ impl Licensee {
         req.exception == self.inner.exception
     }
 
+    /// Returns true if the license or exception of this licensee is deprecated.
+    ///
+    /// Note that the bare GNU license identifiers, eg. `GPL-2.0`, are deprecated
+    /// in the SPDX license list, but are the only form allowed for licensees
+    /// (see [`Licensee::parse`]), so they are not considered deprecated here.
+    ///
+    /// ```
+    /// assert!(spdx::Licensee::parse("StandardML-NJ").unwrap().is_deprecated());
+    /// assert!(!spdx::Licensee::parse("GPL-2.0").unwrap().is_deprecated());
+    /// assert!(!spdx::Licensee::parse("MIT").unwrap().is_deprecated());
+    /// ```
+    #[must_use]
+    pub fn is_deprecated(&self) -> bool {
+        let license = match &self.inner.license {
+            LicenseItem::Spdx { id, .. } => id.is_deprecated() && !is_bare_gnu(*id),
+            LicenseItem::Other { .. } => false,
+        };
+
+        license
+            || self
+                .inner
+                .exception
+                .map_or(false, ExceptionId::is_deprecated)
+    }
+
+    /// Returns the canonical string for this licensee, which is guaranteed to
+    /// be parsable by [`Licensee::parse`].
+    ///
+    /// Deprecated licenses are replaced with their
+    /// [successor](crate::LicenseId::successor) if they have one. If the
+    /// successor is a GNU license, the bare form of the license is used, and if
+    /// the successor includes an exception, it is only used if this licensee
+    /// does not already have an exception.
+    ///
+    /// ```
+    /// let licensee = spdx::Licensee::parse("StandardML-NJ").unwrap();
+    /// assert_eq!(licensee.canonical(), "SMLNJ");
+    ///
+    /// let licensee = spdx::Licensee::parse("wxWindows").unwrap();
+    /// assert_eq!(licensee.canonical(), "LGPL-2.0 WITH WxWindows-exception-3.1");
+    ///
+    /// let licensee = spdx::Licensee::parse("GPL-2.0").unwrap();
+    /// assert_eq!(licensee.canonical(), "GPL-2.0");
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> String {
+        let successor = match &self.inner.license {
+            LicenseItem::Spdx { id, .. } if !is_bare_gnu(*id) => id.successor(),
+            _ => None,
+        };
+
+        match successor {
+            Some((succ, exc)) if exc.is_none() || self.inner.exception.is_none() => {
+                // Licensees always use the bare form of GNU licenses
+                let id = LicenseReq::from(succ).license.id().unwrap_or(succ);
+
+                Licensee::new(
+                    LicenseItem::Spdx {
+                        id,
+                        or_later: false,
+                    },
+                    exc.or(self.inner.exception),
+                )
+                .to_string()
+            }
+            _ => self.to_string(),
+        }
+    }
+
     #[must_use]
     pub fn into_req(self) -> LicenseReq {
         self.inner
     }
 }
 
+/// GNU licenses have a deprecated bare form, eg. `GPL-2.0`, that is used
+/// in addition to the `-only` and `-or-later` forms, and is the only form
+/// allowed for licensees
+fn is_bare_gnu(id: LicenseId) -> bool {
+    id.is_gnu()
+        && !id.name.ends_with("-only")
+        && !id.name.ends_with("-or-later")
+        && crate::license_id(&format!("{}-only", id.name)).is_some()
+}
+
+/// Compares a licensee to a requirement ignoring the requirement's `or_later`,
+/// as licensees can't be `or_later`, which allows eg. a sorted list of
+/// licensees to be binary searched for the license of a requirement
 impl PartialOrd<LicenseReq> for Licensee {
     #[inline]
-    fn partial_cmp(&self, o: &LicenseReq) -> Option<std::cmp::Ordering> {
-        self.inner.partial_cmp(o)
+    fn partial_cmp(&self, o: &LicenseReq) -> Option<Ordering> {
+        let license = match (&self.inner.license, &o.license) {
+            (LicenseItem::Spdx { id: a, .. }, LicenseItem::Spdx { id: b, .. }) => a.cmp(b),
+            (a, b) => a.cmp(b),
+        };
+
+        Some(license.then_with(|| self.inner.exception.cmp(&o.exception)))
     }
 }
 
+/// See the [`PartialOrd`] implementation, the requirement's `or_later` is ignored
 impl PartialEq<LicenseReq> for Licensee {
     #[inline]
     fn eq(&self, o: &LicenseReq) -> bool {
-        self.inner.eq(o)
+        self.partial_cmp(o) == Some(Ordering::Equal)
     }
 }
 
@@ -288,7 +502,7 @@ impl AsRef<LicenseReq> for Licensee {
 
 #[cfg(test)]
 mod test {
-    use crate::{exception_id, license_id, LicenseItem, LicenseReq, Licensee};
+    use crate::{exception_id, license_id, LicenseItem, LicenseReq, Licensee, ParseMode};
 
     const LICENSEES: &[&str] = &[
         "LicenseRef-Embark-Proprietary",
@@ -423,4 +637,178 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn parses_list() {
+        let list = Licensee::parse_list(
+            " MIT,Apache-2.0 WITH LLVM-exception\n\n ISC , \r\nLicenseRef-Embark,",
+            ParseMode::STRICT,
+        )
+        .unwrap();
+
+        assert_eq!(
+            list,
+            [
+                "MIT",
+                "Apache-2.0 WITH LLVM-exception",
+                "ISC",
+                "LicenseRef-Embark"
+            ]
+            .iter()
+            .map(|l| Licensee::parse(l).unwrap())
+            .collect::<Vec<_>>()
+        );
+
+        assert!(Licensee::parse_list(" , \n", ParseMode::STRICT)
+            .unwrap()
+            .is_empty());
+
+        let lax = Licensee::parse_list("mit, apache2 with LLVM-exception", ParseMode::LAX).unwrap();
+        assert_eq!(lax[0], Licensee::parse("MIT").unwrap());
+        assert_eq!(
+            lax[1],
+            Licensee::parse("Apache-2.0 WITH LLVM-exception").unwrap()
+        );
+    }
+
+    #[test]
+    fn expression_not_allowed() {
+        use crate::{error::Reason, ParseError};
+
+        for (input, mode, span) in [
+            ("MIT OR Apache-2.0", ParseMode::STRICT, 4..6),
+            ("MIT AND Apache-2.0", ParseMode::STRICT, 4..7),
+            ("MIT/Apache-2.0", ParseMode::LAX, 3..4),
+            ("mit or apache-2.0", ParseMode::LAX, 4..6),
+            (
+                "Apache-2.0 WITH LLVM-exception OR MIT",
+                ParseMode::STRICT,
+                31..33,
+            ),
+            ("(MIT OR Apache-2.0)", ParseMode::STRICT, 0..1),
+            ("(MIT)", ParseMode::STRICT, 0..1),
+            ("MIT (Apache-2.0)", ParseMode::STRICT, 4..5),
+        ] {
+            let err = Licensee::parse_mode(input, mode).unwrap_err();
+            assert_eq!(
+                err,
+                ParseError {
+                    original: input.to_owned(),
+                    mode,
+                    span,
+                    reason: Reason::ExpressionNotAllowed,
+                },
+                "{input}"
+            );
+        }
+
+        // Other unexpected tokens are still reported as such
+        assert_eq!(
+            Licensee::parse("MIT)").unwrap_err().reason,
+            Reason::Unexpected(&["WITH"])
+        );
+    }
+
+    #[test]
+    fn list_errors_span_entire_input() {
+        use crate::{error::Reason, ParseError};
+
+        let input = "MIT,\n  Apache-2.0 WITH LLVM-exceptoin";
+        assert_eq!(
+            Licensee::parse_list(input, ParseMode::STRICT).unwrap_err(),
+            ParseError {
+                original: input.to_owned(),
+                mode: ParseMode::STRICT,
+                span: 23..37,
+                reason: Reason::UnknownException,
+            }
+        );
+
+        let input = "MIT, ISC AND Zlib";
+        assert_eq!(
+            Licensee::parse_list(input, ParseMode::STRICT).unwrap_err(),
+            ParseError {
+                original: input.to_owned(),
+                mode: ParseMode::STRICT,
+                span: 9..12,
+                reason: Reason::ExpressionNotAllowed,
+            }
+        );
+
+        let input = "MIT, Apache-2.0 WITH LLVM-exception OR ISC";
+        assert_eq!(
+            Licensee::parse_list(input, ParseMode::STRICT).unwrap_err(),
+            ParseError {
+                original: input.to_owned(),
+                mode: ParseMode::STRICT,
+                span: 36..38,
+                reason: Reason::ExpressionNotAllowed,
+            }
+        );
+
+        let input = "GPL-2.0, GPL-3.0-only";
+        assert_eq!(
+            Licensee::parse_list(input, ParseMode::STRICT).unwrap_err(),
+            ParseError {
+                original: input.to_owned(),
+                mode: ParseMode::STRICT,
+                span: 16..21,
+                reason: Reason::Unexpected(&["<bare-gnu-license>"]),
+            }
+        );
+    }
+
+    #[test]
+    fn gnu_suffix_in_token() {
+        use crate::error::Reason;
+
+        // The suffix is found in the license token, not the end of the input
+        for (input, span) in [
+            ("\"GPL-3.0-only\"", 8..13),
+            ("\u{feff}'GPL-3.0-or-later'", 11..20),
+            ("GPL-3.0-only WITH Classpath-exception-2.0", 7..12),
+        ] {
+            let err = Licensee::parse_mode(input, ParseMode::LAX).unwrap_err();
+            assert_eq!(
+                (err.span, err.reason),
+                (span, Reason::Unexpected(&["<bare-gnu-license>"])),
+                "{input}"
+            );
+        }
+
+        let err = Licensee::parse_mode("`GFDL-1.3-no-invariants`", ParseMode::LAX).unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (9..23, Reason::Unexpected(&["<bare-gfdl-license>"]))
+        );
+
+        assert_eq!(
+            Licensee::parse_mode("\"GPL-3.0\"", ParseMode::LAX).unwrap(),
+            Licensee::parse("GPL-3.0").unwrap()
+        );
+        assert_eq!(
+            Licensee::parse_mode("'GFDL-1.3-invariants'", ParseMode::LAX).unwrap(),
+            Licensee::parse("GFDL-1.3-invariants").unwrap()
+        );
+    }
+
+    #[test]
+    fn satisfies_numeric_versions() {
+        let satisfies = |licensee: &str, req: &str| {
+            let req = LicenseReq::parse(req, ParseMode::STRICT).unwrap();
+            Licensee::parse(licensee).unwrap().satisfies(&req)
+        };
+
+        assert!(satisfies("OLDAP-2.1", "OLDAP-2.0.1+"));
+        assert!(!satisfies("OLDAP-2.0.1", "OLDAP-2.1+"));
+        assert!(satisfies("OLDAP-2.2.2", "OLDAP-2.2.1+"));
+        assert!(!satisfies("OLDAP-2.2.1", "OLDAP-2.2.2+"));
+        assert!(satisfies("LPPL-1.3c", "LPPL-1.3a+"));
+        assert!(satisfies("LPPL-1.3c", "LPPL-1.0+"));
+        assert!(!satisfies("LPPL-1.3a", "LPPL-1.3c+"));
+        assert!(satisfies("copyleft-next-0.3.1", "copyleft-next-0.3.0+"));
+        assert!(!satisfies("copyleft-next-0.3.0", "copyleft-next-0.3.1+"));
+        assert!(!satisfies("OLDAP-2.1", "OLDAP-2.0.1"));
+        assert!(!satisfies("Apache-2.0", "MPL-1.0+"));
+    }
 }
@@ -0,0 +1,54 @@
+use std::ops::Range;
+
+/// Converts a byte span in `original`, eg. [`ParseError::span`](crate::ParseError::span)
+/// or [`ExpressionReq::span`](crate::expression::ExpressionReq::span), into a
+/// span of UTF-16 code units, as used by eg. the Language Server Protocol
+///
+/// ```
+/// // The byte order mark is 3 bytes in UTF-8, but a single UTF-16 code unit
+/// let text = "\u{feff}MIT OR Apache-2.0";
+/// let expr = spdx::Expression::parse_mode(text, spdx::ParseMode::LAX).unwrap();
+///
+/// let span = expr.requirements().next().unwrap().span.clone();
+/// assert_eq!(span, 3..6);
+/// assert_eq!(spdx::span::to_utf16(text, span.start as usize..span.end as usize), 1..4);
+/// ```
+#[must_use]
+pub fn to_utf16(original: &str, byte_span: Range<usize>) -> Range<usize> {
+    convert(original, byte_span, char::len_utf16)
+}
+
+/// Converts a byte span in `original` into a span of `char`s, ie. Unicode
+/// scalar values
+///
+/// ```
+/// let err = spdx::Expression::parse("MIT OR 😀").unwrap_err();
+///
+/// assert_eq!(err.span, 7..11);
+/// assert_eq!(spdx::span::to_char_indices(&err.original, err.span), 7..8);
+/// ```
+#[must_use]
+pub fn to_char_indices(original: &str, byte_span: Range<usize>) -> Range<usize> {
+    convert(original, byte_span, |_c| 1)
+}
+
+/// Offsets that are past the end of `original` are clamped to its length, and
+/// offsets inside of a character are rounded up to the end of the character
+fn convert(original: &str, byte_span: Range<usize>, len: impl Fn(char) -> usize) -> Range<usize> {
+    let mut start = None;
+    let mut offset = 0;
+
+    for (i, c) in original.char_indices() {
+        if start.is_none() && i >= byte_span.start {
+            start = Some(offset);
+        }
+
+        if i >= byte_span.end {
+            break;
+        }
+
+        offset += len(c);
+    }
+
+    start.unwrap_or(offset)..offset
+}
@@ -0,0 +1,89 @@
+//! "Did you mean" suggestions for unknown license and exception identifiers
+
+use crate::identifiers;
+
+/// The maximum number of suggestions returned
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Returns the license identifiers most similar to `input`, most similar first
+pub(crate) fn licenses(input: &str) -> Vec<&'static str> {
+    similar(input, identifiers::LICENSES.iter().map(|(name, ..)| *name))
+}
+
+/// Returns the exception identifiers most similar to `input`, most similar first
+pub(crate) fn exceptions(input: &str) -> Vec<&'static str> {
+    similar(input, identifiers::EXCEPTIONS.iter().map(|(name, _)| *name))
+}
+
+/// Returns the license and exception identifiers most similar to `input`, for
+/// when either could have been meant
+pub(crate) fn ids(input: &str) -> Vec<&'static str> {
+    similar(
+        input,
+        identifiers::LICENSES
+            .iter()
+            .map(|(name, ..)| *name)
+            .chain(identifiers::EXCEPTIONS.iter().map(|(name, _)| *name)),
+    )
+}
+
+/// Returns the candidates within a small edit distance of the input, ignoring
+/// case, so that typos and mis-capitalizations, eg. `apache-2.0` or `MTI`, are
+/// suggested, but unrelated identifiers are not
+fn similar(input: &str, candidates: impl Iterator<Item = &'static str>) -> Vec<&'static str> {
+    let input = input.to_ascii_lowercase();
+    // Allow roughly 1 edit for every 4 characters, but always at least 1
+    let max_distance = (input.len() / 4).max(1);
+
+    let mut scored: Vec<_> = candidates
+        .filter_map(|candidate| {
+            let distance = distance(&input, &candidate.to_ascii_lowercase());
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect();
+
+    scored.sort_unstable();
+
+    // If the input only differs in case, that's almost certainly what was meant
+    if let Some((0, _)) = scored.first() {
+        scored.retain(|(distance, _)| *distance == 0);
+    }
+
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// The edit distance between two ASCII strings, where an insertion, deletion,
+/// substitution, or transposition of adjacent characters are each one edit
+fn distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+
+    if a.len().abs_diff(b.len()) > a.len().max(b.len()) / 2 {
+        return usize::MAX;
+    }
+
+    let mut prev_prev = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut cur = vec![0; b.len() + 1];
+
+    for i in 0..a.len() {
+        cur[0] = i + 1;
+
+        for j in 0..b.len() {
+            let sub = prev[j] + usize::from(a[i] != b[j]);
+            cur[j + 1] = sub.min(prev[j + 1] + 1).min(cur[j] + 1);
+
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                cur[j + 1] = cur[j + 1].min(prev_prev[j - 1] + 1);
+            }
+        }
+
+        std::mem::swap(&mut prev_prev, &mut prev);
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
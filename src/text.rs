@@ -1087,6 +1087,695 @@ pub const LICENSE_TEXTS: &[(&str, &str)] = &[
     ),
 ];
 
+pub const LICENSE_TEXT_HASHES: &[(u64, usize)] = &[
+    (0x0024a91a360e279c, 122),
+    (0x00c80cc3bfc756ee, 33),
+    (0x010e90488547b1b7, 603),
+    (0x0120cdf3842fc9ae, 182),
+    (0x01ccd8b2593cb66d, 470),
+    (0x026d905e092bdb25, 142),
+    (0x027e85e392bbb09f, 684),
+    (0x02be737924032571, 329),
+    (0x0321ec80c2596709, 26),
+    (0x032d8e7b256bf9a1, 568),
+    (0x035d94bcc62bbd66, 517),
+    (0x03d259a9287fb49c, 598),
+    (0x05ea3ef412bb61f5, 680),
+    (0x06030b34602ce9ba, 575),
+    (0x071192c4c62e9468, 46),
+    (0x075d0b295682138a, 186),
+    (0x07de004dd468bed6, 499),
+    (0x08123b37fb9e3dba, 533),
+    (0x083045f3893b4d4c, 453),
+    (0x0837f499ae607d7b, 147),
+    (0x0894c6bb5ae4a132, 332),
+    (0x08d19819220a5738, 249),
+    (0x08d19819220a5738, 250),
+    (0x08d19819220a5738, 251),
+    (0x08d19819220a5738, 252),
+    (0x08d19819220a5738, 253),
+    (0x08d19819220a5738, 254),
+    (0x08d19819220a5738, 255),
+    (0x08d19819220a5738, 256),
+    (0x08d19819220a5738, 257),
+    (0x099bfbc4455a0ac3, 106),
+    (0x09d15deba4764632, 662),
+    (0x09d86e9d45a923d2, 160),
+    (0x0b03bc0d07b5e5b0, 359),
+    (0x0bb599c6bb263aa7, 237),
+    (0x0bda8d28eb5f3031, 335),
+    (0x0c0674923a9868d9, 80),
+    (0x0c5b41e3ef71ba68, 68),
+    (0x0c7d67220405c538, 496),
+    (0x0c880a656794498d, 375),
+    (0x0c9f2ac6cbc5f0cb, 675),
+    (0x0d074ec91bf5143b, 567),
+    (0x0d39194e54c789cb, 538),
+    (0x0d5a7e886940702f, 342),
+    (0x0d69bdb16ea77c3a, 535),
+    (0x0d6cd84a87fe604f, 21),
+    (0x0d8e3a09263860d8, 104),
+    (0x0e80f6f50f8ad69b, 381),
+    (0x0f152ab04cb68877, 369),
+    (0x0f77d27a14cc59c9, 210),
+    (0x0f92da4842eb01dd, 127),
+    (0x1039a277dab32e58, 391),
+    (0x10a94aa1afdbdb8c, 269),
+    (0x10a94aa1afdbdb8c, 270),
+    (0x10a94aa1afdbdb8c, 271),
+    (0x10a94aa1afdbdb8c, 272),
+    (0x10bd8772e3cb173f, 306),
+    (0x11700d697bd8d823, 492),
+    (0x11d52997c422b491, 504),
+    (0x11dddd523d6d7f37, 222),
+    (0x1208a8535c828ec4, 537),
+    (0x1222de59b2d0489c, 174),
+    (0x1282f0da991a1fc0, 560),
+    (0x12fa71d3abfba749, 112),
+    (0x1301c2505bbd937e, 565),
+    (0x131f1f92c527a13f, 482),
+    (0x1381d4d3dff521bb, 183),
+    (0x13a1774962672c0c, 541),
+    (0x13a4033cc4f01a34, 216),
+    (0x144bd0be94712ff0, 522),
+    (0x14c2d699e8c79265, 678),
+    (0x150f47b5ee773f81, 173),
+    (0x15fa54883867ed81, 157),
+    (0x170902cc21c26a51, 155),
+    (0x175e5838171e0176, 8),
+    (0x1789b9c70aa0b78c, 621),
+    (0x17c84eb259bf42d3, 638),
+    (0x1823f203901e6710, 180),
+    (0x183cb2538345a234, 577),
+    (0x1847c241814331d4, 643),
+    (0x18bfb6f611483938, 647),
+    (0x18e38b73ab9bcd6d, 450),
+    (0x18f0627d4e9409a1, 114),
+    (0x193e07d5a1698c07, 89),
+    (0x19aca253d6526e5f, 107),
+    (0x19da9c3d32c3948e, 79),
+    (0x1a3a76627b5c00c7, 397),
+    (0x1ae874aad22d3cf5, 536),
+    (0x1c6e2c4aad0eb23a, 143),
+    (0x1c8a74c4acbb701f, 339),
+    (0x1d1e2973b75dc870, 61),
+    (0x1d36185e8705d83e, 669),
+    (0x1d793709b4fb0128, 604),
+    (0x1ea3143a2f9b371f, 416),
+    (0x1fed46fe7c2ba206, 18),
+    (0x20959d07ce753b2a, 166),
+    (0x20fcb626eddb5da4, 232),
+    (0x213820e64a5a3b18, 60),
+    (0x2139839c9690436a, 130),
+    (0x222227167dce742b, 137),
+    (0x22631d6eeeea2775, 36),
+    (0x228eb191d3358e00, 578),
+    (0x22ae16127908223e, 169),
+    (0x22b351bc246c73b3, 327),
+    (0x22fbec05ac481507, 442),
+    (0x240305a60ec5f53f, 206),
+    (0x240cfc8bbca0bf30, 570),
+    (0x242fb392688f7a7c, 636),
+    (0x2438198691264cdd, 154),
+    (0x244576cf99893704, 610),
+    (0x2458fbcb09e3921a, 52),
+    (0x2514b64621f34d89, 420),
+    (0x253599e9cd381785, 328),
+    (0x25f0c0fe4858e4c3, 12),
+    (0x25f0c0fe4858e4c3, 13),
+    (0x25f0c0fe4858e4c3, 14),
+    (0x25f7ae26bcfd8dc8, 164),
+    (0x26e93fd5ed47bc7c, 493),
+    (0x2773bbaca5fb0d9e, 136),
+    (0x280061e8f1ca1b61, 202),
+    (0x291df3543d937f77, 551),
+    (0x291f4cf7f817400f, 109),
+    (0x2a1ab37b2b3a37d3, 633),
+    (0x2a4e600b32b03f8b, 303),
+    (0x2a8e4343b5665ee5, 387),
+    (0x2a9414b3d9e9b051, 302),
+    (0x2b56ae3f2db76926, 66),
+    (0x2c4038c7c8e3bd38, 602),
+    (0x2e040f9ca79300a1, 653),
+    (0x2e0f74c98ea6e1d0, 471),
+    (0x2e13011460f990c6, 15),
+    (0x2e6baddf58d7a070, 558),
+    (0x2eb227432662cbeb, 671),
+    (0x2f45e3c639c3bf1a, 22),
+    (0x2f808d094f5dfda9, 292),
+    (0x3015644c3f894b71, 145),
+    (0x307ee20497b45d7a, 150),
+    (0x30842bb4ac9570a5, 101),
+    (0x3133e0de9c6ffe6b, 468),
+    (0x319b4204b30269cb, 655),
+    (0x31a3bd341f57a88a, 44),
+    (0x31c7208d583e2063, 409),
+    (0x327ac9aa35da4f99, 512),
+    (0x32b68ea3e72761e6, 129),
+    (0x32e5b7dc52ff0133, 411),
+    (0x338efc9007eae444, 64),
+    (0x339a8f54ada367ab, 158),
+    (0x33ea9c5bce503c8b, 273),
+    (0x33ea9c5bce503c8b, 275),
+    (0x33ea9c5bce503c8b, 276),
+    (0x341e94c9e0350111, 459),
+    (0x342bac7a6d1170be, 651),
+    (0x34458bf22b30a886, 240),
+    (0x34458bf22b30a886, 241),
+    (0x34458bf22b30a886, 242),
+    (0x34458bf22b30a886, 243),
+    (0x34458bf22b30a886, 244),
+    (0x34458bf22b30a886, 245),
+    (0x34458bf22b30a886, 246),
+    (0x34458bf22b30a886, 247),
+    (0x34458bf22b30a886, 248),
+    (0x34cea0291975dcb0, 224),
+    (0x359560af2396f3d2, 519),
+    (0x35d0cf52726662b6, 525),
+    (0x368742afabe0e245, 435),
+    (0x3687fbe7cd222533, 289),
+    (0x368fcadbb35f9c97, 521),
+    (0x3708b729ed12ade7, 236),
+    (0x37226a7ce277b810, 295),
+    (0x377abd50c663529f, 314),
+    (0x37962416811d466e, 37),
+    (0x37e6410a05cade17, 103),
+    (0x380070e74c9c2f31, 624),
+    (0x383559a10cf160f7, 126),
+    (0x384511de7d30ebee, 454),
+    (0x3887b9ef6346cf23, 508),
+    (0x38fbd88496da14db, 118),
+    (0x39ae745a4662f2d8, 424),
+    (0x3a137a3a5dcc1bb8, 445),
+    (0x3aea57ca0800443a, 286),
+    (0x3b2a4012101fbf06, 572),
+    (0x3b800fcc1c432b0b, 349),
+    (0x3bca5f3dc1968eb2, 455),
+    (0x3c4901a8c5a1c5db, 7),
+    (0x3c552e800fd6b675, 123),
+    (0x3d5bf13319cfedf3, 113),
+    (0x3e44f6ea8b35f921, 667),
+    (0x3ec65f3cb7b68161, 25),
+    (0x3fb155f8705035b1, 311),
+    (0x40ce155831fdf660, 613),
+    (0x40d163be19b41b2c, 217),
+    (0x40e3694acee6c1c1, 2),
+    (0x41344079ba1af7d1, 144),
+    (0x4167947b2b181690, 556),
+    (0x41f7a3ec28cfd064, 198),
+    (0x420c658725c4737e, 23),
+    (0x4280827634d38471, 583),
+    (0x4292024e13b7870b, 448),
+    (0x42d972671cc8048e, 417),
+    (0x43074f0163eaf2b5, 373),
+    (0x4324f3fffac825fc, 552),
+    (0x44566bf63695957d, 389),
+    (0x44b54e697b5cd192, 139),
+    (0x45046c4bc6f42e16, 630),
+    (0x4554c28072de0190, 614),
+    (0x4647fb9ee2bc0b2a, 133),
+    (0x4649cb912910fab7, 188),
+    (0x46fc8b92e32da79a, 402),
+    (0x476052ac55676aab, 588),
+    (0x47f92bf2fb85707f, 282),
+    (0x47f92bf2fb85707f, 284),
+    (0x47f92bf2fb85707f, 285),
+    (0x4885c13f06e09bfb, 488),
+    (0x492586d92553eeca, 350),
+    (0x492586d92553eeca, 351),
+    (0x492586d92553eeca, 352),
+    (0x492586d92553eeca, 353),
+    (0x498f412464712759, 487),
+    (0x499e27061ca109fd, 116),
+    (0x49ab44cdcb78ef23, 346),
+    (0x49d17274ec428d5f, 665),
+    (0x4a21d9c6360e5a4f, 298),
+    (0x4ac0a2329931e623, 600),
+    (0x4b09d103464ba1b7, 320),
+    (0x4b1860b9b9753d5f, 211),
+    (0x4b534c4274bac369, 102),
+    (0x4b6ff18c835d5345, 563),
+    (0x4c07b72d5b82c8e6, 682),
+    (0x4cbbe1f11c427bb7, 310),
+    (0x4d4906e27d01c944, 405),
+    (0x4d54459e5f2e85b2, 190),
+    (0x4d7351e159938ad3, 110),
+    (0x4d769c2d31cb5402, 358),
+    (0x4d769c2d31cb5402, 360),
+    (0x4d769c2d31cb5402, 361),
+    (0x4d8e87b3ae11eda1, 230),
+    (0x4da9dbdb9062fa0a, 43),
+    (0x4e3871a136a4ca8e, 401),
+    (0x4e629f54e467dc71, 460),
+    (0x4faa09ffe4057564, 83),
+    (0x4fe5066574f5204d, 480),
+    (0x50dc7c13b1508c5a, 368),
+    (0x51327e28e836d4e2, 277),
+    (0x513da73d104ef31b, 71),
+    (0x5169f87fae0d331b, 609),
+    (0x51804ee68f3638f3, 607),
+    (0x51e69970f6118962, 546),
+    (0x51e69970f6118962, 566),
+    (0x5229fd51535ca721, 189),
+    (0x52ac52884033c0e9, 542),
+    (0x534f082fe81a1447, 70),
+    (0x536442b2f6fc8ea1, 596),
+    (0x547855b95224a8b7, 187),
+    (0x54ea622caca459d8, 545),
+    (0x55336b6279d6dcbd, 383),
+    (0x556963e90001f0c6, 576),
+    (0x5625c84241184bd3, 479),
+    (0x56cd394a0c1747bd, 20),
+    (0x56f4569faf0871c9, 672),
+    (0x5727494b99bdb7f4, 326),
+    (0x57805278e8a7112b, 278),
+    (0x57a195f6449ee95e, 456),
+    (0x57cd204a3b3b93d3, 197),
+    (0x57dd7cd4506fd1f6, 406),
+    (0x57dd7cd4506fd1f6, 407),
+    (0x583d142013a28291, 341),
+    (0x58486b13b20cdbc9, 148),
+    (0x58e4c63802cd1335, 221),
+    (0x596156a9f54a7ba3, 212),
+    (0x5a119aeb2e43ec52, 76),
+    (0x5a18ce6c05245a8d, 467),
+    (0x5a313b0da4ba8de7, 642),
+    (0x5aecfba58a458a1c, 623),
+    (0x5af98454421d885d, 238),
+    (0x5baf03e3f3e05c56, 399),
+    (0x5c9d2275fc216286, 99),
+    (0x5c9d2275fc216286, 100),
+    (0x5da7e3087071955a, 138),
+    (0x5e4397a3bbeb5a5c, 294),
+    (0x5e4d7a0f4a76df40, 660),
+    (0x5e83bdfcadecfbcc, 635),
+    (0x5f311b203f866324, 3),
+    (0x5f5f9b8b297c70aa, 31),
+    (0x5f8a8b3ad6ee794e, 330),
+    (0x5fb42353662c190a, 58),
+    (0x5fbcf19ae165a4e2, 268),
+    (0x60228fccb73953a1, 673),
+    (0x6140887ff535ffbc, 87),
+    (0x617434ab5c7a892e, 362),
+    (0x6178df86a3aec54f, 412),
+    (0x6316a11e7ec4817b, 354),
+    (0x6316a11e7ec4817b, 356),
+    (0x6316a11e7ec4817b, 357),
+    (0x643a98a497578d4e, 97),
+    (0x64cc0a93a17ca58b, 571),
+    (0x64de08829711bb19, 194),
+    (0x64f83fef15b7407c, 159),
+    (0x650d4b3a006e5cd1, 532),
+    (0x6642e3be7ca0f5b8, 321),
+    (0x6757fda54f6a470e, 520),
+    (0x6758ec2af2bd014e, 57),
+    (0x675e5b5903efb5bb, 305),
+    (0x679bd986ed151a0d, 355),
+    (0x67d6c983c10818b6, 9),
+    (0x67d6c983c10818b6, 10),
+    (0x67d6c983c10818b6, 11),
+    (0x6814a4ed0b4ae1eb, 540),
+    (0x68164d4077cc4faf, 72),
+    (0x694e6e60071ea5d3, 179),
+    (0x695929402fe299d9, 431),
+    (0x6a70a198a87f0728, 539),
+    (0x6ae74fb5270d27fb, 628),
+    (0x6b1cd457d840ac35, 323),
+    (0x6b967414ea089c3b, 168),
+    (0x6cc52f2256d77a35, 218),
+    (0x6e3e98338f802754, 648),
+    (0x6eb956a82a2dabbe, 414),
+    (0x6eed5d49d0fd8c0c, 185),
+    (0x6fcd7f7cfb0b4df3, 55),
+    (0x71692d683a5f3987, 457),
+    (0x718700e7b08dade4, 19),
+    (0x71f3156b552b7151, 501),
+    (0x72768954ae77dd9a, 475),
+    (0x72a21ec81345f036, 484),
+    (0x730bba95a49ca9e1, 340),
+    (0x737a4c4ca5a08390, 444),
+    (0x7427f31c136f9da4, 597),
+    (0x747569cf330cc10b, 547),
+    (0x748a5b73ab0f86b8, 491),
+    (0x756acd4b7f4dd333, 434),
+    (0x7579862edd416f4c, 170),
+    (0x757d76e2f2edb675, 125),
+    (0x7641099d3730d5aa, 117),
+    (0x7646902b59484323, 220),
+    (0x76cf63c15c71ba74, 392),
+    (0x76e3f445d130d7e1, 49),
+    (0x76f92839c0fc4669, 451),
+    (0x778a91e4a1b0d7e0, 363),
+    (0x778b78deaf90b8a7, 39),
+    (0x792cb8d422001400, 676),
+    (0x799710ea5245d39a, 587),
+    (0x799c7d017a171600, 111),
+    (0x79db6d9406c939b0, 510),
+    (0x7a0069e0f4f0585b, 518),
+    (0x7a5f8408604452e9, 654),
+    (0x7b0f3b0f0e6f14ac, 54),
+    (0x7b11b92010b009ed, 415),
+    (0x7b1b9c70c0e7a5ea, 32),
+    (0x7bbd29b494b5264b, 527),
+    (0x7c3293d1ad3c21b1, 81),
+    (0x7d44562645dce10b, 534),
+    (0x7d712f9a6ed80f4c, 612),
+    (0x7e8184d19dc096a1, 461),
+    (0x7e8184d19dc096a1, 462),
+    (0x7e8184d19dc096a1, 463),
+    (0x7f6ed19a466ce5a9, 620),
+    (0x7ffa965df454d5e3, 640),
+    (0x80c833ddd74a8025, 580),
+    (0x80dcb422f29e55f6, 632),
+    (0x80f5815706ac074f, 283),
+    (0x8142395774dbd300, 40),
+    (0x832f837e9cd7c1c8, 514),
+    (0x8354f61225b076ea, 449),
+    (0x838efcdf8b8cb395, 108),
+    (0x8404d639a2575b9c, 429),
+    (0x844cf84011a4007d, 200),
+    (0x84ed6d787260a2f2, 153),
+    (0x8519a5e8379475cc, 679),
+    (0x851f630fd9074aaa, 394),
+    (0x853100b011cf95f4, 196),
+    (0x8565fee71fdcc532, 645),
+    (0x858cd422dc9f79d2, 191),
+    (0x85d9705025321ec5, 50),
+    (0x8687276879c4e2d6, 334),
+    (0x869de3fa39bbf0d1, 403),
+    (0x86c579c00d08f2ca, 611),
+    (0x86d3d99ca5adfb45, 423),
+    (0x86d850837fd46816, 617),
+    (0x877f12d5b129774d, 28),
+    (0x87869f144a4e846b, 322),
+    (0x8833c2124ddbf38f, 225),
+    (0x88ee3ceb2f0efba9, 120),
+    (0x8ae3f121a547d2f3, 498),
+    (0x8c1692110ad48617, 495),
+    (0x8d3d2204cc3a2d13, 215),
+    (0x8d67e9fbacdd2b42, 464),
+    (0x8d67e9fbacdd2b42, 465),
+    (0x8d67e9fbacdd2b42, 466),
+    (0x8ee36859f6ae6c7c, 92),
+    (0x90c614ddc958928e, 348),
+    (0x918fa75cbc4cb817, 181),
+    (0x925a0b904598aebf, 605),
+    (0x92930112d394ec85, 500),
+    (0x93fd4ea44bf9a762, 446),
+    (0x9403d75b2ebb3218, 204),
+    (0x943f91d755459af4, 105),
+    (0x944de13323cfcc5b, 301),
+    (0x94e1b99c89c1c93d, 625),
+    (0x95eab5e88567e98c, 175),
+    (0x962dc9e47a9a2416, 441),
+    (0x96397b42c60cd13d, 371),
+    (0x965611bcc7a22ecd, 156),
+    (0x976ef72554a957f9, 419),
+    (0x97d64c129c2ef588, 131),
+    (0x98710f6119ef1d23, 184),
+    (0x990de827f9086ad1, 69),
+    (0x99181a831fcaeb7b, 592),
+    (0x9b2f3e00d78ba04d, 390),
+    (0x9b33f18762cb6474, 646),
+    (0x9b3c90f12843bb3d, 618),
+    (0x9c2bfc4dd5bd1389, 418),
+    (0x9c2f6a2343807e47, 227),
+    (0x9c7e46598cfd2c87, 53),
+    (0x9ce972dc88693f9d, 398),
+    (0x9d254c9c51b2b499, 659),
+    (0x9d491499093a3293, 88),
+    (0x9d49ce89bb6f2810, 561),
+    (0x9ddeb0f569adc417, 208),
+    (0x9e906811d3a4cd33, 195),
+    (0x9eee17f91b69fed4, 214),
+    (0xa035080ab1b3ef8e, 650),
+    (0xa06562721192738c, 485),
+    (0xa087c16272706feb, 619),
+    (0xa0ab6315c349949c, 422),
+    (0xa0c7aadfc689b19c, 91),
+    (0xa0cf66668c8e969d, 336),
+    (0xa0fb5017ba1c61de, 193),
+    (0xa18ac4c47650ea95, 233),
+    (0xa1a24d682387526b, 378),
+    (0xa1a79640d12464aa, 626),
+    (0xa206a2c9448335c2, 65),
+    (0xa272a7db6886c4a4, 599),
+    (0xa324c69d6dd3d045, 523),
+    (0xa32676ef2d503f9c, 385),
+    (0xa350566d6a8d0968, 516),
+    (0xa3bf076d65395818, 388),
+    (0xa3c978d7365f181d, 231),
+    (0xa407dea52f60e8e5, 226),
+    (0xa54b41cdbb8c00f5, 427),
+    (0xa5a085927a62f3ad, 325),
+    (0xa5b3416d2e58bd45, 582),
+    (0xa5c02c2cd6eb53f5, 593),
+    (0xa5e606acb0d02ea3, 548),
+    (0xa64bdb6bddc93902, 146),
+    (0xa6e17d6052a9fd07, 377),
+    (0xa7337ac42b76be9d, 677),
+    (0xa7b0c010d5f698c3, 82),
+    (0xa7cbf9e423b0a5a3, 595),
+    (0xa81e3bf41d751613, 432),
+    (0xa8c6c6eb45f05f1d, 337),
+    (0xa91ac1213f3b3682, 502),
+    (0xa91ca9a30a6cfa3b, 16),
+    (0xa9449235af1a9b23, 38),
+    (0xa9e884d641176e90, 601),
+    (0xaa5c1b710e560de2, 59),
+    (0xab228b3dd4c03715, 515),
+    (0xac3bc3a64f3bd257, 98),
+    (0xac3dd29e10f0c6df, 393),
+    (0xac9bf0da531e8bbe, 228),
+    (0xacaa4bf05c2c1d4f, 344),
+    (0xad37d99ff400ad2a, 315),
+    (0xad8b435102a70194, 452),
+    (0xada7fd5d2537720a, 281),
+    (0xadcf242f9b100de1, 324),
+    (0xae5899c565f85487, 192),
+    (0xaf48c77ceff8e441, 670),
+    (0xafcdcbf4e2a563c4, 318),
+    (0xafd300354ad0b8b5, 299),
+    (0xafdcedf7da550f2a, 42),
+    (0xb00ce0df5179464b, 589),
+    (0xb020032aeb1735e1, 550),
+    (0xb0f5580a159b2c9d, 267),
+    (0xb1ca65d955177e19, 410),
+    (0xb1f1ccb8c396ca87, 425),
+    (0xb21f52f20cc15e74, 4),
+    (0xb28c0bef40c22a27, 472),
+    (0xb2a2d38d2fd46940, 585),
+    (0xb2c12d947b7406b1, 345),
+    (0xb326f3fcf1e2490f, 657),
+    (0xb34816ffbaead9c0, 686),
+    (0xb35a66bd800e799a, 526),
+    (0xb370e26cb0d648f0, 85),
+    (0xb3aa23ea8d3f3487, 584),
+    (0xb3f6e1ee486be446, 507),
+    (0xb5236d11a0641936, 234),
+    (0xb5c2578c44e10ece, 581),
+    (0xb5c3bc4138554c2c, 300),
+    (0xb60c56b3ce0c8963, 430),
+    (0xb76a2f05d2ae0efb, 333),
+    (0xb7fd586e2d7c28b2, 476),
+    (0xb89c89becb5fd973, 205),
+    (0xb928d086c121fbab, 367),
+    (0xb97283e2005a3270, 45),
+    (0xb99c9c1a0ed186fd, 380),
+    (0xba49bf0973abf433, 343),
+    (0xbaa08536dbae0186, 553),
+    (0xbb5e57703ec54047, 6),
+    (0xbba230e1a8e3f7bb, 506),
+    (0xbc224b6824814246, 641),
+    (0xbceab00a6ff41736, 78),
+    (0xbd014bd56af6f736, 171),
+    (0xbd39b14fd1be1613, 35),
+    (0xbd59a5ec262fb74d, 165),
+    (0xbd640677497359b5, 549),
+    (0xbd801ffeefec2a8e, 149),
+    (0xbe51e8b4b097222a, 683),
+    (0xbe96521cf08c0f2d, 84),
+    (0xbec34ed21d106b0c, 579),
+    (0xbff0d5bea85c2303, 486),
+    (0xc008349e5dda30c4, 649),
+    (0xc0600547b0005212, 554),
+    (0xc08b7d8534dac910, 569),
+    (0xc0b3c3ebe50c3568, 490),
+    (0xc0b7b28ccd8c6495, 474),
+    (0xc0f52480b82bdd1e, 279),
+    (0xc1231275816646f0, 207),
+    (0xc2171f396d64700d, 478),
+    (0xc29698dae9faba47, 209),
+    (0xc2bf5c1eeb963f8d, 616),
+    (0xc2c32ecf80fc8b71, 483),
+    (0xc38aba6cb623baa0, 48),
+    (0xc3f13e6ef5370ff7, 27),
+    (0xc41a08ef3331c61f, 606),
+    (0xc479ed46605ea9c5, 543),
+    (0xc4ba2a9f3ff6d980, 280),
+    (0xc4ba858f28c98216, 513),
+    (0xc4f9e7b4bec587ea, 481),
+    (0xc561d23cca26a7af, 317),
+    (0xc6b0244c8248df2b, 443),
+    (0xc6b7050baaf86fc8, 77),
+    (0xc6be124ef4754f29, 365),
+    (0xc6e897f9da451fa4, 627),
+    (0xc724c0861363d03a, 172),
+    (0xc77857cf61b7cccb, 201),
+    (0xc96a90efbca94f3d, 56),
+    (0xc9ad4d43f0d36e18, 531),
+    (0xca08bbed63fb368a, 75),
+    (0xcb26c857a4c2cabd, 591),
+    (0xcb46e4300771f046, 62),
+    (0xcb5039f5fe20af39, 90),
+    (0xcc5036dda4fe5a35, 152),
+    (0xcd1f6c3e35a0939f, 382),
+    (0xcd3d517344f5bae3, 304),
+    (0xcd8c7fa8185872d1, 51),
+    (0xce267398f265a0ed, 529),
+    (0xcee0f1689a0a70d5, 372),
+    (0xcefb209608c4f2dc, 95),
+    (0xcf22139c72d2f1f9, 386),
+    (0xcf34f2c0ba388eff, 384),
+    (0xd02302f04f670d3c, 622),
+    (0xd053ddda6371a4d5, 96),
+    (0xd05fcd170ca9ad40, 674),
+    (0xd0cb55853b7d3d9a, 5),
+    (0xd0ea2447367a7c06, 41),
+    (0xd16aeef68568085b, 30),
+    (0xd1aae7556d5ec0a6, 258),
+    (0xd1aae7556d5ec0a6, 259),
+    (0xd1aae7556d5ec0a6, 260),
+    (0xd1aae7556d5ec0a6, 261),
+    (0xd1aae7556d5ec0a6, 262),
+    (0xd1aae7556d5ec0a6, 263),
+    (0xd1aae7556d5ec0a6, 264),
+    (0xd1aae7556d5ec0a6, 265),
+    (0xd1aae7556d5ec0a6, 266),
+    (0xd1d5323e77996697, 615),
+    (0xd1fa56844319498e, 347),
+    (0xd21a97005e59cebd, 681),
+    (0xd26bc1ab0cce9ac7, 288),
+    (0xd281694163aaf467, 119),
+    (0xd2c1a2f4578a5f39, 229),
+    (0xd34d9114c345c457, 17),
+    (0xd3592a5e8fab97a9, 658),
+    (0xd42fa1fbe649c1dd, 178),
+    (0xd4e7615c943c6b7f, 319),
+    (0xd5b4037e79dda6e6, 115),
+    (0xd5f20a0668059628, 307),
+    (0xd60b980e3754de87, 34),
+    (0xd64965b9e3970285, 121),
+    (0xd7b6c670c89c70a5, 530),
+    (0xd7db7803c646a318, 376),
+    (0xd82cdaf6a7e17c2a, 544),
+    (0xd8ea2ed3dd344ae9, 316),
+    (0xd8ec156f37ecc8c4, 594),
+    (0xd8f001d24a4d6d22, 395),
+    (0xd9fa4b41f73af37a, 428),
+    (0xda6a89dbc3076196, 629),
+    (0xda75c6084cd7dc00, 639),
+    (0xdaa545756dddd872, 366),
+    (0xdb1e46137d858e8a, 494),
+    (0xdc3c5a5752108d38, 309),
+    (0xdc6a818c51a1b074, 458),
+    (0xdc92832c9acbb123, 661),
+    (0xdc996d731f393911, 586),
+    (0xdce937f5233ab680, 503),
+    (0xdd84b8a10915ba3c, 219),
+    (0xddc097b537b5580e, 128),
+    (0xde791b2d731c4d56, 124),
+    (0xdebe05cb096693e4, 338),
+    (0xe0583d394489c772, 290),
+    (0xe05b5f70f56ce6d5, 421),
+    (0xe10f57145e526a62, 74),
+    (0xe1461a774a9cd36c, 573),
+    (0xe15612d79c5bdced, 162),
+    (0xe15d15ff89bbb002, 132),
+    (0xe308a9c2b2e17798, 297),
+    (0xe34287155701cf32, 528),
+    (0xe352b9c4cb1e5374, 374),
+    (0xe36e0fc3b2ebb259, 663),
+    (0xe37213914d347b7c, 413),
+    (0xe39006a8283937c5, 86),
+    (0xe3b1e8dc6dda7e6c, 652),
+    (0xe3c9ef7a166be78a, 213),
+    (0xe3e9d50751e56b9d, 408),
+    (0xe3f2cedb4c1cbe3f, 93),
+    (0xe4091f30eefd70ea, 634),
+    (0xe4773fe35734445b, 505),
+    (0xe485c4052ee8f9b1, 469),
+    (0xe51daf271db15773, 574),
+    (0xe5286bcb6657c4e1, 0),
+    (0xe6632a00f2b38db4, 24),
+    (0xe6a3259856bd27ce, 426),
+    (0xe6ce6bf6c7251876, 135),
+    (0xe7094dc6d8a0851b, 308),
+    (0xe7c352e1ae8bcead, 177),
+    (0xe84d09d7c479b55c, 291),
+    (0xe85bef78eb049a9e, 564),
+    (0xe8a492c60d89e174, 509),
+    (0xe91bf504f16e3010, 447),
+    (0xe97320b181f30af4, 63),
+    (0xea2f408590cf17b8, 555),
+    (0xeb08372df740b414, 637),
+    (0xeb3d282560a2a346, 631),
+    (0xec641a2f79081553, 73),
+    (0xed775ea2c06689e0, 161),
+    (0xedcd5bc3e4727860, 440),
+    (0xee0f3dc97fd61b56, 664),
+    (0xee30337261cc022a, 312),
+    (0xefb85f1558f4322e, 223),
+    (0xefdbb3debd901388, 364),
+    (0xf01dabbdb618b0e9, 370),
+    (0xf035461f4c616061, 141),
+    (0xf054cd96983ad69c, 287),
+    (0xf15be6b68a141b4d, 296),
+    (0xf21496a50141a7b3, 29),
+    (0xf21b0561c732cf5c, 176),
+    (0xf242f0680cac2f28, 562),
+    (0xf2851315d979bfca, 235),
+    (0xf2abb75ce72b3be7, 668),
+    (0xf345d2bf24e302e0, 47),
+    (0xf35ad4c1feaaff96, 199),
+    (0xf37bef03c2276164, 239),
+    (0xf390ad81c16ee37a, 331),
+    (0xf3d708aca7f5ddc7, 511),
+    (0xf3e35e5a92d30edc, 151),
+    (0xf4020da36dc271f7, 685),
+    (0xf44c442ba2058d29, 590),
+    (0xf4c8645d24bd75e8, 656),
+    (0xf4d65378098a2d7c, 477),
+    (0xf53c74850f952f88, 313),
+    (0xf574b30724d6baaf, 1),
+    (0xf62dce65a6b284da, 94),
+    (0xf67dc5f568d5a2ce, 559),
+    (0xf68e1b6e1eda4ce0, 608),
+    (0xf6985da02f52201f, 274),
+    (0xf704d54bc96fa86d, 400),
+    (0xf7205d47bc77ea17, 437),
+    (0xf7653c6fdac3f8a4, 557),
+    (0xf839f2601fe095bd, 293),
+    (0xf90fe0c3d9dcc378, 644),
+    (0xf95f76e023d7ef98, 404),
+    (0xf991d5d11f549bdb, 436),
+    (0xf9a3c3689b5e4aa9, 473),
+    (0xf9e07caacf6f38aa, 497),
+    (0xfa858e64fb909f18, 489),
+    (0xfb47fefab3671994, 140),
+    (0xfb6094be4e0985a9, 379),
+    (0xfb6ba28df7434c58, 666),
+    (0xfbc8e490b45dacba, 163),
+    (0xfbcac0f9744efd23, 67),
+    (0xfc1ad0d367511002, 524),
+    (0xfce7fca1b9c64ce6, 203),
+    (0xfd68a1928d236ee7, 167),
+    (0xfda28a19efc8658b, 438),
+    (0xfddb450f7bd3d9ce, 396),
+    (0xfe5b49b663eef97f, 134),
+    (0xfe726b70bbb09e11, 433),
+];
+
 pub const EXCEPTION_TEXTS: &[(&str, &str)] = &[
     ("389-exception", include!("text/exceptions/389-exception")),
     (
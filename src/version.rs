@@ -0,0 +1,71 @@
+//! Comparisons of the versions in license identifiers
+
+use std::cmp::Ordering;
+
+/// Compares two versions, eg. `2.0.1` and `2.10`, one `.` separated component
+/// at a time. The leading digits of each component are compared as integers,
+/// followed by any remaining characters, eg. `3a` < `3c` < `10`, and components
+/// that aren't numeric are compared as strings. A version that is a prefix of
+/// the other, eg. `2.0` of `2.0.1`, is the lesser one.
+pub(crate) fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn split_digits(comp: &str) -> (Result<u64, std::num::ParseIntError>, &str) {
+        let digits = comp
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(comp.len());
+        (comp[..digits].parse(), &comp[digits..])
+    }
+
+    let mut a_comps = a.split('.');
+    let mut b_comps = b.split('.');
+
+    loop {
+        let (a, b) = match (a_comps.next(), b_comps.next()) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return Ordering::Equal,
+        };
+
+        let ord = match (split_digits(a), split_digits(b)) {
+            ((Ok(a_num), a_rest), (Ok(b_num), b_rest)) => {
+                a_num.cmp(&b_num).then_with(|| a_rest.cmp(b_rest))
+            }
+            _ => a.cmp(b),
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::compare_versions;
+
+    #[test]
+    fn compares_versions() {
+        use std::cmp::Ordering::{Equal, Greater, Less};
+
+        for (a, b, expected) in [
+            ("1.0", "1.0", Equal),
+            ("2.0", "1.0", Greater),
+            ("10.0", "9.0", Greater),
+            ("2.10", "2.9", Greater),
+            ("2.0.1", "2.1", Less),
+            ("2.0", "2.0.1", Less),
+            ("2.2.2", "2.2.10", Less),
+            ("1.02", "1.1", Greater),
+            ("1.3a", "1.3c", Less),
+            ("1.3c", "1.10", Less),
+            ("1.3", "1.3a", Less),
+            ("0.3.1", "0.3.0", Greater),
+            ("", "1.0", Less),
+            ("beta", "alpha", Greater),
+            ("1.0", "beta", Less),
+        ] {
+            assert_eq!(compare_versions(a, b), expected, "{a} vs {b}");
+            assert_eq!(compare_versions(b, a), expected.reverse(), "{b} vs {a}");
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Counts the heap allocations made while parsing, which needs its own test
+//! binary since the counting allocator is global
+
+// Implementing `GlobalAlloc` requires unsafe
+#![allow(unsafe_code)]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+struct Counting;
+
+thread_local! {
+    // Per thread, so that allocations made by the test harness on other
+    // threads aren't counted
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Hides the text from the optimizer, as `std::hint::black_box` is newer than
+/// the MSRV
+#[inline(never)]
+fn opaque(s: &str) -> &str {
+    s
+}
+
+#[test]
+fn single_id_doesnt_allocate() {
+    for text in ["MIT", "Apache-2.0", "Unicode-DFS-2016"] {
+        let before = allocations();
+        for _ in 0..1_000_000 {
+            let expr = spdx::Expression::parse(opaque(text)).unwrap();
+            assert_eq!(expr.as_ref(), text);
+        }
+        let allocations = allocations() - before;
+
+        assert_eq!(allocations, 0, "{text}");
+    }
+}
@@ -0,0 +1,78 @@
+use spdx::cargo::{parse_license_field, parse_license_field_opt, CargoLicense};
+
+#[test]
+fn license_fields() {
+    // Real values of crates.io `license` fields, and their canonical forms
+    let fields = [
+        ("MIT", "MIT"),
+        ("MIT OR Apache-2.0", "MIT OR Apache-2.0"),
+        ("MIT/Apache-2.0", "MIT OR Apache-2.0"),
+        ("MIT / Apache-2.0", "MIT OR Apache-2.0"),
+        ("Apache-2.0/MIT", "Apache-2.0 OR MIT"),
+        ("MIT or Apache-2.0", "MIT OR Apache-2.0"),
+        (
+            "Apache-2.0 WITH LLVM-exception",
+            "Apache-2.0 WITH LLVM-exception",
+        ),
+        (
+            "Apache-2.0 WITH LLVM-exception OR Apache-2.0 OR MIT",
+            "Apache-2.0 WITH LLVM-exception OR Apache-2.0 OR MIT",
+        ),
+        ("Unlicense/MIT", "Unlicense OR MIT"),
+        ("GPL-3.0+", "GPL-3.0-or-later"),
+        ("BSD-2-Clause-FreeBSD", "BSD-2-Clause"),
+        ("GPL-3.0", "GPL-3.0-only"),
+        ("GPL-2.0", "GPL-2.0-only"),
+        ("LGPL-2.1", "LGPL-2.1-only"),
+        ("MIT OR GPL-3.0", "MIT OR GPL-3.0-only"),
+        (
+            "(MIT OR Apache-2.0) AND Unicode-DFS-2016",
+            "(MIT OR Apache-2.0) AND Unicode-DFS-2016",
+        ),
+    ];
+
+    for (field, canonical) in fields {
+        let expr = parse_license_field(field).unwrap();
+        assert_eq!(expr.to_string(), canonical, "{field}");
+        assert!(
+            expr.lint()
+                .iter()
+                .all(|lint| lint.kind != spdx::expression::LintKind::DeprecatedId),
+            "{field}"
+        );
+
+        assert_eq!(
+            parse_license_field_opt(Some(field), Some("LICENSE")).unwrap(),
+            CargoLicense::Expression(expr),
+        );
+    }
+
+    parse_license_field("Make it free, but not too free").unwrap_err();
+    parse_license_field("MIT OR").unwrap_err();
+
+    // Errors point into the field as written, even if it needed fixing
+    let err = parse_license_field("mit/apache2 AND (ISC").unwrap_err();
+    assert_eq!(err.original, "mit/apache2 AND (ISC");
+    assert_eq!(&err.original[err.span], "(");
+    parse_license_field_opt(Some("nope"), None).unwrap_err();
+}
+
+#[test]
+fn license_file_only() {
+    assert_eq!(
+        parse_license_field_opt(None, Some("LICENSE.md")).unwrap(),
+        CargoLicense::FileOnly("LICENSE.md".to_owned())
+    );
+    assert_eq!(
+        parse_license_field_opt(Some(""), Some("LICENSE.md")).unwrap(),
+        CargoLicense::FileOnly("LICENSE.md".to_owned())
+    );
+    assert_eq!(
+        parse_license_field_opt(None, None).unwrap(),
+        CargoLicense::Missing
+    );
+    assert_eq!(
+        parse_license_field_opt(Some("  "), Some("")).unwrap(),
+        CargoLicense::Missing
+    );
+}
@@ -150,6 +150,302 @@ fn allow_osi_fsf() {
     ]);
 }
 
+#[test]
+fn evaluate_flags() {
+    use spdx::identifiers::{IS_COPYLEFT, IS_FSF_LIBRE, IS_OSI_APPROVED};
+
+    // Borceux is neither OSI or FSF
+    // MIT is both
+    // BitTorrent-1.1 is only FSF
+    let expr = spdx::Expression::parse("Borceux OR MIT AND BitTorrent-1.1").unwrap();
+
+    assert!(expr.evaluate_flags(0, 0, false));
+    assert!(!expr.evaluate_flags(IS_OSI_APPROVED, 0, false));
+    assert!(expr.evaluate_flags(IS_FSF_LIBRE, 0, false));
+    assert!(!expr.evaluate_flags(IS_FSF_LIBRE | IS_OSI_APPROVED, 0, false));
+    assert!(expr.evaluate_flags(0, IS_COPYLEFT, false));
+    assert!(!expr.is_osi_compatible());
+    assert!(expr.is_fsf_free());
+    assert!(!expr.contains_copyleft());
+
+    let expr = spdx::Expression::parse("LicenseRef-Embark AND (MPL-2.0 OR Borceux)").unwrap();
+
+    assert!(!expr.evaluate_flags(0, 0, false));
+    assert!(expr.evaluate_flags(0, 0, true));
+    assert!(expr.evaluate_flags(IS_OSI_APPROVED, 0, true));
+    assert!(!expr.evaluate_flags(IS_OSI_APPROVED, IS_COPYLEFT, true));
+    assert!(!expr.is_osi_compatible());
+    assert!(expr.contains_copyleft());
+}
+
+#[test]
+fn satisfied_by() {
+    use spdx::{Expression, Licensee};
+
+    let accepted: Vec<_> = [
+        "MIT",
+        "Apache-2.0 WITH LLVM-exception",
+        "LicenseRef-Embark-Proprietary",
+    ]
+    .iter()
+    .map(|l| Licensee::parse(l).unwrap())
+    .collect();
+
+    for (expr, failures) in [
+        ("MIT", &[][..]),
+        ("MIT OR ISC", &[][..]),
+        ("ISC OR Zlib", &["ISC", "Zlib"][..]),
+        ("MIT AND ISC", &["ISC"][..]),
+        (
+            "(MIT AND ISC) OR Apache-2.0 WITH LLVM-exception",
+            &["ISC"][..],
+        ),
+        ("(MIT AND ISC) OR Apache-2.0", &["ISC", "Apache-2.0"][..]),
+        ("Apache-2.0+ WITH LLVM-exception AND MIT", &[][..]),
+        ("LicenseRef-Embark-Proprietary AND MIT", &[][..]),
+        (
+            "DocumentRef-Other:LicenseRef-Embark-Proprietary AND MIT",
+            &["DocumentRef-Other:LicenseRef-Embark-Proprietary"][..],
+        ),
+    ] {
+        let expr = Expression::parse(expr).unwrap();
+        let res = expr.satisfied_by(&accepted);
+
+        assert_eq!(expr.is_satisfied_by(&accepted), res.is_ok(), "{expr}");
+        assert_eq!(
+            res.is_ok(),
+            expr.evaluate(|req| accepted.iter().any(|l| l.satisfies(req))),
+            "{expr}"
+        );
+
+        match res {
+            Ok(()) => {}
+            Err(f) => {
+                let f: Vec<_> = f.into_iter().map(|er| er.req.to_string()).collect();
+                assert_eq!(f, failures, "{expr}");
+            }
+        }
+    }
+}
+
+#[test]
+fn evaluate_map() {
+    use spdx::{Expression, Licensee};
+
+    // Each accepted licensee is labelled with the reason it is in the list
+    let accepted: Vec<_> = [
+        ("MIT", "permissive"),
+        ("ISC", "permissive"),
+        ("Apache-2.0", "patent grant"),
+        ("LicenseRef-Embark-Proprietary", "ours"),
+    ]
+    .iter()
+    .map(|(l, reason)| (Licensee::parse(l).unwrap(), *reason))
+    .collect();
+
+    let label = |req: &spdx::LicenseReq| {
+        accepted
+            .iter()
+            .find_map(|(l, reason)| l.satisfies(req).then_some(*reason))
+    };
+
+    for (expr, outcome) in [
+        ("MIT", Some((&["MIT: permissive"][..], &[][..]))),
+        ("Zlib", None),
+        (
+            "(MIT AND Zlib) OR (Apache-2.0 AND (Zlib OR LicenseRef-Embark-Proprietary))",
+            Some((
+                &[
+                    "Apache-2.0: patent grant",
+                    "LicenseRef-Embark-Proprietary: ours",
+                ][..],
+                &["MIT: permissive"][..],
+            )),
+        ),
+        (
+            "(ISC OR Apache-2.0) AND (Zlib OR MIT) AND (MIT OR ISC OR Zlib)",
+            Some((
+                &["ISC: permissive", "MIT: permissive", "MIT: permissive"][..],
+                &["Apache-2.0: patent grant", "ISC: permissive"][..],
+            )),
+        ),
+        ("(MIT OR ISC) AND (Zlib OR BSD-3-Clause)", None),
+    ] {
+        let expr = Expression::parse(expr).unwrap();
+        let actual = expr.evaluate_map(label);
+
+        assert_eq!(
+            actual.is_some(),
+            expr.evaluate(|req| label(req).is_some()),
+            "{expr}"
+        );
+
+        let labelled = |reqs: &[(&spdx::expression::ExpressionReq, &str)]| {
+            reqs.iter()
+                .map(|(er, reason)| format!("{}: {reason}", er.req))
+                .collect::<Vec<_>>()
+        };
+
+        match (actual, outcome) {
+            (Some(actual), Some((accepted, unneeded))) => {
+                assert_eq!(labelled(&actual.accepted), accepted, "{expr}");
+                assert_eq!(labelled(&actual.unneeded), unneeded, "{expr}");
+            }
+            (None, None) => {}
+            (actual, expected) => panic!("{expr}: {actual:?} != {expected:?}"),
+        }
+    }
+}
+
+#[test]
+fn satisfying_offer() {
+    use spdx::Expression;
+
+    // The offered requirement, and the acceptable requirement that satisfied it
+    let offer = |dep: &str, acceptable: &str| {
+        let dep = Expression::parse(dep).unwrap();
+        let acceptable = Expression::parse_mode(acceptable, spdx::ParseMode::LAX).unwrap();
+
+        let offer = dep.satisfying_offer(&acceptable).map(|outcome| {
+            outcome
+                .accepted
+                .iter()
+                .map(|(er, by)| {
+                    let by = &acceptable.as_ref()[by.span.start as usize..by.span.end as usize];
+                    format!("{} by {by}", er.req)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(dep.offers_satisfying(&acceptable), offer.is_some());
+        offer
+    };
+
+    // AND on the acceptable side, every requirement is acceptable
+    assert_eq!(
+        offer("Apache-2.0 OR GPL-3.0-only", "MIT AND Apache-2.0").unwrap(),
+        ["Apache-2.0 by Apache-2.0"]
+    );
+    assert_eq!(offer("GPL-3.0-only AND MIT", "MIT AND Apache-2.0"), None);
+    assert_eq!(
+        offer("MIT AND Apache-2.0", "Apache-2.0 AND MIT").unwrap(),
+        ["MIT by MIT", "Apache-2.0 by Apache-2.0"]
+    );
+
+    // OR on both sides, the first satisfied branch of the offer is chosen
+    assert_eq!(
+        offer("MIT OR Apache-2.0", "Apache-2.0 OR MIT").unwrap(),
+        ["MIT by MIT"]
+    );
+    assert_eq!(
+        offer(
+            "(MIT OR ISC) AND (Zlib OR BSD-3-Clause)",
+            "ISC OR (BSD-3-Clause AND Apache-2.0)"
+        )
+        .unwrap(),
+        ["ISC by ISC", "BSD-3-Clause by BSD-3-Clause"]
+    );
+    assert_eq!(offer("MIT OR ISC", "Zlib OR Apache-2.0"), None);
+
+    // Exceptions must match, and refs are matched exactly
+    assert_eq!(offer("Apache-2.0", "Apache-2.0 WITH LLVM-exception"), None);
+    assert_eq!(
+        offer(
+            "Apache-2.0 WITH LLVM-exception OR MIT",
+            "Apache-2.0 WITH LLVM-exception"
+        )
+        .unwrap(),
+        ["Apache-2.0 WITH LLVM-exception by Apache-2.0 WITH LLVM-exception"]
+    );
+    assert_eq!(
+        offer("GPL-3.0-only OR LicenseRef-Internal", "LicenseRef-Internal").unwrap(),
+        ["LicenseRef-Internal by LicenseRef-Internal"]
+    );
+
+    // A `+` on the acceptable side only accepts the version that is named
+    assert_eq!(
+        offer("Apache-1.0+", "Apache-2.0+").unwrap(),
+        ["Apache-1.0+ by Apache-2.0+"]
+    );
+    assert_eq!(offer("Apache-1.1", "Apache-2.0+"), None);
+    assert_eq!(
+        offer("GPL-2.0-only OR GPL-3.0-only", "GPL-2.0-or-later").unwrap(),
+        ["GPL-2.0 by GPL-2.0-or-later"]
+    );
+    assert_eq!(offer("GPL-3.0-only", "gpl-2.0+"), None);
+}
+#[test]
+fn restrictiveness() {
+    use spdx::{license_id, Restrictiveness as R};
+
+    for (name, expected) in [
+        ("MIT", R::Permissive),
+        ("0BSD", R::Permissive),
+        ("Apache-2.0", R::Permissive),
+        ("CC-BY-4.0", R::Permissive),
+        ("MPL-2.0", R::WeakCopyleft),
+        ("LGPL-2.1-or-later", R::WeakCopyleft),
+        ("EPL-2.0", R::WeakCopyleft),
+        ("GPL-3.0-only", R::StrongCopyleft),
+        ("CC-BY-SA-4.0", R::StrongCopyleft),
+        ("AGPL-3.0-or-later", R::NetworkCopyleft),
+        ("SSPL-1.0", R::NetworkCopyleft),
+        ("CC-BY-NC-SA-4.0", R::NonCommercial),
+        ("CC-BY-ND-4.0", R::NonCommercial),
+        ("NOASSERTION", R::Unknown),
+    ] {
+        assert_eq!(
+            license_id(name).unwrap().restrictiveness(),
+            expected,
+            "{name}"
+        );
+    }
+
+    assert!(R::Permissive < R::WeakCopyleft);
+    assert!(R::NetworkCopyleft < R::NonCommercial);
+    assert!(R::NonCommercial < R::Unknown);
+}
+
+#[test]
+fn most_permissive_choice() {
+    use spdx::Expression;
+
+    let choice = |text| {
+        Expression::parse(text)
+            .unwrap()
+            .most_permissive_choice()
+            .map(|reqs| reqs.iter().map(|req| req.to_string()).collect::<Vec<_>>())
+    };
+
+    assert_eq!(choice("GPL-3.0-only OR MIT").unwrap(), ["MIT"]);
+    assert_eq!(choice("Apache-2.0 OR MPL-2.0").unwrap(), ["Apache-2.0"]);
+    assert_eq!(choice("MPL-2.0 OR Apache-2.0").unwrap(), ["Apache-2.0"]);
+
+    // The branch with the least restrictive most restrictive license wins, even
+    // if it has more licenses
+    assert_eq!(
+        choice("(MIT AND GPL-2.0-only) OR (ISC AND Zlib AND MPL-2.0)").unwrap(),
+        ["ISC", "Zlib", "MPL-2.0"]
+    );
+    assert_eq!(
+        choice("(MIT OR AGPL-3.0-only) AND (LGPL-2.1-only OR GPL-2.0-only) AND MIT").unwrap(),
+        ["MIT", "LGPL-2.1"]
+    );
+
+    // Ties are broken by license index, regardless of the order in the expression
+    assert_eq!(choice("MIT OR Apache-2.0").unwrap(), ["Apache-2.0"]);
+    assert_eq!(choice("Apache-2.0 OR MIT").unwrap(), ["Apache-2.0"]);
+
+    // Refs are only chosen when there is no other choice
+    assert_eq!(
+        choice("LicenseRef-Embark OR GPL-3.0-only").unwrap(),
+        ["GPL-3.0"]
+    );
+    assert_eq!(choice("LicenseRef-Embark"), None);
+    assert_eq!(choice("LicenseRef-a OR DocumentRef-b:LicenseRef-c"), None);
+    assert_eq!(choice("MIT AND LicenseRef-Embark"), None);
+}
+
 #[test]
 fn or_later() {
     check!("CC-BY-NC-ND-2.5+" => [
@@ -249,6 +545,128 @@ fn gfdl() {
     ]);
 }
 
+#[test]
+fn gfdl_invariant_variants() {
+    check!("GFDL-1.1-or-later" => [
+        false => |req| exact!(req, "GFDL-1.2-invariants"),
+        false => |req| exact!(req, "GFDL-1.3-invariants"),
+    ]);
+
+    // `-no-invariants` licensees are not allowed, so the bare license is used
+    check!("GFDL-1.2-no-invariants-or-later" => [
+        false => |req| exact!(req, "GFDL-1.1"),
+        true => |req| exact!(req, "GFDL-1.2"),
+        true => |req| exact!(req, "GFDL-1.3"),
+        false => |req| exact!(req, "GFDL-1.1-invariants"),
+        false => |req| exact!(req, "GFDL-1.2-invariants"),
+        false => |req| exact!(req, "GFDL-1.3-invariants"),
+    ]);
+
+    check!("GFDL-1.3-no-invariants-only" => [
+        false => |req| exact!(req, "GFDL-1.2"),
+        true => |req| exact!(req, "GFDL-1.3"),
+        false => |req| exact!(req, "GFDL-1.3-invariants"),
+    ]);
+
+    check!("GFDL-1.3-invariants-or-later" => [
+        false => |req| exact!(req, "GFDL-1.1-invariants"),
+        false => |req| exact!(req, "GFDL-1.2-invariants"),
+        true => |req| exact!(req, "GFDL-1.3-invariants"),
+        false => |req| exact!(req, "GFDL-1.3"),
+    ]);
+
+    check_lax!("GFDL-1.2-no-invariants+" => [
+        true => |req| exact!(req, "GFDL-1.3"),
+        false => |req| exact!(req, "GFDL-1.3-invariants"),
+    ]);
+
+    // GFDL never satisfies, or is satisfied by, another license family
+    check!("GFDL-1.3-or-later" => [
+        false => |req| exact!(req, "GPL-3.0"),
+        false => |req| exact!(req, "FDK-AAC"),
+    ]);
+    check!("GPL-2.0-or-later" => [
+        false => |req| exact!(req, "GFDL-1.3"),
+    ]);
+}
+
+#[test]
+fn gfdl_components() {
+    use spdx::{Gfdl, GfdlInvariants};
+
+    for (name, version, invariants, or_later) in [
+        ("GFDL-1.1", "1.1", GfdlInvariants::Unspecified, false),
+        ("GFDL-1.1-only", "1.1", GfdlInvariants::Unspecified, false),
+        (
+            "GFDL-1.2-or-later",
+            "1.2",
+            GfdlInvariants::Unspecified,
+            true,
+        ),
+        (
+            "GFDL-1.2-invariants",
+            "1.2",
+            GfdlInvariants::Invariants,
+            false,
+        ),
+        (
+            "GFDL-1.3-invariants-only",
+            "1.3",
+            GfdlInvariants::Invariants,
+            false,
+        ),
+        (
+            "GFDL-1.3-no-invariants",
+            "1.3",
+            GfdlInvariants::NoInvariants,
+            false,
+        ),
+        (
+            "GFDL-1.1-no-invariants-or-later",
+            "1.1",
+            GfdlInvariants::NoInvariants,
+            true,
+        ),
+    ] {
+        assert_eq!(
+            spdx::license_id(name).unwrap().gfdl(),
+            Some(Gfdl {
+                version,
+                invariants,
+                or_later
+            }),
+            "{name}"
+        );
+    }
+
+    assert!(spdx::license_id("LGPL-2.1").unwrap().gfdl().is_none());
+}
+
+#[test]
+fn gfdl_versions_compare_numerically() {
+    use spdx::{Gfdl, GfdlInvariants};
+
+    let gfdl = |version| Gfdl {
+        version,
+        invariants: GfdlInvariants::Unspecified,
+        or_later: false,
+    };
+
+    for (licensee, req, or_later, expected) in [
+        ("1.10", "1.9", true, true),
+        ("1.9", "1.10", true, false),
+        ("10.0", "9.0", true, true),
+        ("1.3", "1.3", false, true),
+        ("1.10", "1.9", false, false),
+    ] {
+        assert_eq!(
+            gfdl(licensee).satisfies(gfdl(req), or_later),
+            expected,
+            "{licensee} {req} {or_later}"
+        );
+    }
+}
+
 #[test]
 fn noassertion() {
     check!("NOASSERTION AND OpenSSL" => [
@@ -453,3 +871,442 @@ fn too_many_to_minimize() {
         spdx::expression::MinimizeError::TooManyRequirements(65)
     );
 }
+
+#[test]
+fn licensee_canonical_round_trips() {
+    use spdx::Licensee;
+
+    let ids = spdx::identifiers::LICENSES.iter().map(|(id, ..)| *id);
+    let with_exc = spdx::identifiers::LICENSES
+        .iter()
+        .map(|(id, ..)| format!("{id} WITH LLVM-exception"));
+
+    let mut deprecated = 0;
+    for id in ids.map(String::from).chain(with_exc) {
+        // Not every license id is a valid licensee, eg. GNU licenses with
+        // the -only suffix
+        let Ok(licensee) = Licensee::parse(&id) else {
+            continue;
+        };
+
+        let canonical = licensee.canonical();
+        let reparsed = Licensee::parse(&canonical)
+            .unwrap_or_else(|err| panic!("{id} => {canonical} failed to parse: {err}"));
+
+        assert_eq!(reparsed.canonical(), canonical);
+        assert_eq!(reparsed.to_string(), canonical);
+
+        if licensee.is_deprecated() {
+            deprecated += 1;
+
+            let lic = &licensee.as_ref().license;
+            if lic.id().unwrap().successor().is_some() && !id.contains(" WITH ") {
+                assert!(!reparsed.is_deprecated(), "{id} => {canonical}");
+            }
+        } else {
+            assert_eq!(canonical, id);
+        }
+    }
+
+    assert!(deprecated > 0);
+
+    for (id, canonical) in [
+        ("StandardML-NJ", "SMLNJ"),
+        ("BSD-2-Clause-FreeBSD", "BSD-2-Clause"),
+        ("eCos-2.0", "GPL-2.0 WITH eCos-exception-2.0"),
+        (
+            "eCos-2.0 WITH LLVM-exception",
+            "eCos-2.0 WITH LLVM-exception",
+        ),
+        ("GPL-2.0", "GPL-2.0"),
+        ("Net-SNMP", "Net-SNMP"),
+        ("LicenseRef-Embark", "LicenseRef-Embark"),
+    ] {
+        assert_eq!(Licensee::parse(id).unwrap().canonical(), canonical);
+    }
+}
+
+#[test]
+fn or_later_accessors_and_equality() {
+    let expr =
+        spdx::Expression::parse("Apache-2.0 OR Apache-2.0+ OR GPL-2.0-or-later WITH Classpath-exception-2.0 OR LicenseRef-Embark")
+            .unwrap();
+    let reqs: Vec<_> = expr.requirements().collect();
+
+    assert_eq!(
+        reqs.iter()
+            .map(|er| (er.license_id(), er.or_later(), er.exception_id()))
+            .collect::<Vec<_>>(),
+        [
+            (spdx::license_id("Apache-2.0"), false, None),
+            (spdx::license_id("Apache-2.0"), true, None),
+            (
+                spdx::license_id("GPL-2.0"),
+                true,
+                spdx::exception_id("Classpath-exception-2.0")
+            ),
+            (None, false, None),
+        ]
+    );
+
+    // `or_later` is part of equality and ordering, for items, requirements,
+    // and expression requirements
+    assert_ne!(reqs[0].req.license, reqs[1].req.license);
+    assert!(reqs[0].req.license < reqs[1].req.license);
+    assert_ne!(reqs[0].req, reqs[1].req);
+    assert_ne!(reqs[0], reqs[1]);
+    assert_eq!(
+        reqs[0].req.partial_cmp(&reqs[1].req),
+        Some(reqs[0].req.cmp(&reqs[1].req))
+    );
+
+    // Licensees can't be `or_later`, so comparisons against requirements ignore it
+    let apache = spdx::Licensee::parse("Apache-2.0").unwrap();
+    assert!(apache == reqs[0].req);
+    assert!(apache == reqs[1].req);
+    assert!(apache != reqs[2].req);
+}
+
+#[test]
+fn license_item_order_is_consistent() {
+    let mut items = Vec::new();
+    for id in ["Apache-2.0", "GPL-2.0", "MIT"] {
+        for or_later in [true, false] {
+            items.push(LicenseItem::Spdx {
+                id: spdx::license_id(id).unwrap(),
+                or_later,
+            });
+        }
+    }
+    for (doc_ref, lic_ref) in [(None, "B"), (Some("Doc"), "A"), (None, "A")] {
+        items.push(LicenseItem::Other {
+            doc_ref: doc_ref.map(String::from),
+            lic_ref: lic_ref.to_owned(),
+        });
+    }
+
+    for a in &items {
+        for b in &items {
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)), "{a} vs {b}");
+            assert_eq!(a == b, a.cmp(b) == std::cmp::Ordering::Equal, "{a} vs {b}");
+        }
+    }
+
+    items.sort();
+    for (i, item) in items.iter().enumerate() {
+        assert_eq!(items.binary_search(item), Ok(i));
+    }
+
+    assert_eq!(
+        items.iter().map(|i| i.to_string()).collect::<Vec<_>>(),
+        [
+            "Apache-2.0",
+            "Apache-2.0+",
+            "GPL-2.0",
+            "GPL-2.0-or-later",
+            "MIT",
+            "MIT+",
+            "LicenseRef-A",
+            "LicenseRef-B",
+            "DocumentRef-Doc:LicenseRef-A",
+        ]
+    );
+}
+
+#[test]
+fn minimizes_or_later() {
+    let apache = spdx::Licensee::parse("Apache-2.0").unwrap();
+    let mit = spdx::Licensee::parse("MIT").unwrap();
+
+    let expr = spdx::Expression::parse("Apache-2.0+ AND (Apache-2.0 OR MIT)").unwrap();
+    assert_eq!(
+        expr.minimized_requirements([&mit, &apache]).unwrap(),
+        [apache.clone().into_req()]
+    );
+
+    let expr = spdx::Expression::parse("MIT+ OR Apache-2.0").unwrap();
+    assert_eq!(
+        expr.minimized_requirements([&mit, &apache]).unwrap(),
+        [mit.into_req()]
+    );
+}
+
+#[test]
+fn minimizes_deterministically() {
+    let lic = |s| spdx::Licensee::parse(s).unwrap();
+    let minimize = |expr: &str, accepted: &[spdx::Licensee]| {
+        spdx::Expression::parse(expr)
+            .unwrap()
+            .minimized_requirements(accepted)
+            .unwrap()
+            .into_iter()
+            .map(|req| req.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    // A smaller set always wins, even if it only contains the lowest priority licensee
+    assert_eq!(
+        minimize(
+            "(MIT OR Apache-2.0) AND (Apache-2.0 OR ISC)",
+            &[lic("MIT"), lic("ISC"), lic("Apache-2.0")]
+        ),
+        ["Apache-2.0"]
+    );
+
+    // [MIT, Zlib], [Apache-2.0, ISC], and [Apache-2.0, Zlib] are all minimal,
+    // so the one with the highest priority licensees wins
+    let expr = "(MIT OR Apache-2.0) AND (ISC OR Zlib) AND (Zlib OR Apache-2.0)";
+    assert_eq!(
+        minimize(
+            expr,
+            &[lic("MIT"), lic("Apache-2.0"), lic("ISC"), lic("Zlib")]
+        ),
+        ["MIT", "Zlib"]
+    );
+    assert_eq!(
+        minimize(
+            expr,
+            &[lic("Apache-2.0"), lic("MIT"), lic("ISC"), lic("Zlib")]
+        ),
+        ["Apache-2.0", "ISC"]
+    );
+
+    // Licensees that don't appear in the expression don't affect the result
+    assert_eq!(
+        minimize(
+            expr,
+            &[
+                lic("BSD-3-Clause"),
+                lic("MIT"),
+                lic("0BSD"),
+                lic("Apache-2.0"),
+                lic("ISC"),
+                lic("Zlib")
+            ]
+        ),
+        ["MIT", "Zlib"]
+    );
+}
+
+#[test]
+fn conjuncts() {
+    let conjuncts = |expr: &str| {
+        spdx::Expression::parse(expr)
+            .unwrap()
+            .conjuncts(16)
+            .unwrap()
+            .into_iter()
+            .map(|group| group.into_iter().map(|req| req.to_string()).collect())
+            .collect::<Vec<Vec<_>>>()
+    };
+
+    assert_eq!(conjuncts("Apache-2.0 OR MIT"), [vec!["Apache-2.0", "MIT"]]);
+    assert_eq!(
+        conjuncts("Apache-2.0 AND MIT"),
+        [vec!["Apache-2.0"], vec!["MIT"]]
+    );
+    assert_eq!(
+        conjuncts("MIT OR Apache-2.0 AND BSD-2-Clause"),
+        [vec!["MIT", "Apache-2.0"], vec!["MIT", "BSD-2-Clause"]]
+    );
+    assert_eq!(
+        conjuncts("(MIT AND (LGPL-2.1-or-later OR BSD-3-Clause))"),
+        [vec!["MIT"], vec!["LGPL-2.1-or-later", "BSD-3-Clause"]]
+    );
+
+    let leading = [
+        vec!["Apache-2.0 WITH LLVM-exception", "Apache-2.0", "MIT"],
+        vec!["OpenSSL", "MIT"],
+    ];
+    assert_eq!(
+        conjuncts("((Apache-2.0 WITH LLVM-exception) OR Apache-2.0) AND OpenSSL OR MIT"),
+        leading
+    );
+    assert_eq!(
+        conjuncts("((((Apache-2.0 WITH LLVM-exception) OR (Apache-2.0)) AND (OpenSSL)) OR (MIT))"),
+        leading
+    );
+
+    assert_eq!(
+        conjuncts("Apache-2.0 WITH LLVM-exception OR Apache-2.0 AND (OpenSSL OR MIT)"),
+        [
+            vec!["Apache-2.0 WITH LLVM-exception", "Apache-2.0"],
+            vec!["Apache-2.0 WITH LLVM-exception", "OpenSSL", "MIT"],
+        ]
+    );
+    assert_eq!(
+        conjuncts("Apache-2.0 WITH LLVM-exception OR (Apache-2.0 AND OpenSSL) OR MIT"),
+        [
+            vec!["Apache-2.0 WITH LLVM-exception", "Apache-2.0", "MIT"],
+            vec!["Apache-2.0 WITH LLVM-exception", "OpenSSL", "MIT"],
+        ]
+    );
+    assert_eq!(
+        conjuncts("Borceux OR MIT AND BitTorrent-1.1"),
+        [vec!["Borceux", "MIT"], vec!["Borceux", "BitTorrent-1.1"]]
+    );
+    assert_eq!(
+        conjuncts("ISC AND OpenSSL AND (MIT OR Apache-2.0)"),
+        [vec!["ISC"], vec!["OpenSSL"], vec!["MIT", "Apache-2.0"]]
+    );
+    assert_eq!(
+        conjuncts("(MIT OR Apache-2.0) AND (Zlib OR BSD-3-Clause)"),
+        [vec!["MIT", "Apache-2.0"], vec!["Zlib", "BSD-3-Clause"]]
+    );
+
+    // Duplicates are removed
+    assert_eq!(
+        conjuncts("(MIT OR Apache-2.0) AND (Apache-2.0 OR MIT OR MIT) AND ISC"),
+        [
+            vec!["MIT", "Apache-2.0"],
+            vec!["Apache-2.0", "MIT"],
+            vec!["ISC"]
+        ]
+    );
+    assert_eq!(
+        conjuncts("MIT AND ISC OR MIT AND ISC"),
+        [
+            vec!["MIT"],
+            vec!["MIT", "ISC"],
+            vec!["ISC", "MIT"],
+            vec!["ISC"]
+        ]
+    );
+
+    // Distributing ORs of ANDs can result in exponentially more conjuncts
+    let expr = spdx::Expression::parse(
+        "(MIT AND ISC) OR (Zlib AND 0BSD) OR (Apache-2.0 AND BSD-3-Clause)",
+    )
+    .unwrap();
+    assert_eq!(expr.conjuncts(8).unwrap().len(), 8);
+    assert_eq!(
+        expr.conjuncts(7).unwrap_err(),
+        spdx::expression::ConjunctsError::TooManyConjuncts(7)
+    );
+}
+
+#[test]
+fn evaluate_dyn_parity() {
+    let accepted = [
+        spdx::Licensee::parse("MIT").unwrap(),
+        spdx::Licensee::parse("Apache-2.0 WITH LLVM-exception").unwrap(),
+    ];
+    let allow = |req: &spdx::LicenseReq| accepted.iter().any(|lic| lic.satisfies(req));
+
+    for expr in [
+        "MIT",
+        "ISC",
+        "Apache-2.0 OR MIT",
+        "Apache-2.0 AND MIT",
+        "MIT OR Apache-2.0 AND BSD-2-Clause",
+        "((Apache-2.0 WITH LLVM-exception) OR Apache-2.0) AND OpenSSL OR MIT",
+        "Apache-2.0 WITH LLVM-exception AND (ISC OR Zlib)",
+    ] {
+        let expr = spdx::Expression::parse(expr).unwrap();
+
+        let mut dyn_allow: Box<dyn FnMut(&spdx::LicenseReq) -> bool> = Box::new(allow);
+        assert_eq!(
+            expr.evaluate(allow),
+            expr.evaluate_dyn(&mut *dyn_allow),
+            "{expr}"
+        );
+        assert_eq!(
+            expr.evaluate_with_failures(allow),
+            expr.evaluate_with_failures_dyn(&mut *dyn_allow),
+            "{expr}"
+        );
+    }
+}
+
+#[test]
+fn from_nodes() {
+    use spdx::{
+        error::NodesError,
+        expression::{ExprNode, ExpressionReq, Operator},
+        Expression,
+    };
+
+    let reqs: Vec<_> = [
+        "MIT",
+        "Apache-2.0 WITH LLVM-exception",
+        "GPL-2.0-or-later",
+        "LicenseRef-Embark",
+        "DocumentRef-spdx:LicenseRef-Embark",
+    ]
+    .iter()
+    .map(|text| {
+        let expr = Expression::parse(text).unwrap();
+        ExprNode::Req(ExpressionReq {
+            req: expr.requirements().next().unwrap().req.clone(),
+            span: 100..200,
+        })
+    })
+    .collect();
+    let and = ExprNode::Op(Operator::And);
+    let or = ExprNode::Op(Operator::Or);
+
+    assert_eq!(Expression::from_nodes(vec![]), Err(NodesError::Empty));
+    assert_eq!(
+        Expression::from_nodes(vec![reqs[0].clone(), or.clone(), reqs[1].clone()]),
+        Err(NodesError::MissingOperand { index: 1 })
+    );
+    assert_eq!(
+        Expression::from_nodes(vec![
+            reqs[0].clone(),
+            reqs[1].clone(),
+            reqs[2].clone(),
+            and.clone()
+        ]),
+        Err(NodesError::MissingOperator { terms: 2 })
+    );
+
+    // A simple xorshift so that the random node lists are reproducible
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = |max: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % max as u64) as usize
+    };
+
+    let mut valid = 0;
+    for _ in 0..5000 {
+        let nodes: Vec<_> = (0..next(12))
+            .map(|_| match next(7) {
+                0 | 1 => and.clone(),
+                2 | 3 => or.clone(),
+                i => reqs[(i + next(reqs.len())) % reqs.len()].clone(),
+            })
+            .collect();
+
+        let Ok(expr) = Expression::from_nodes(nodes.clone()) else {
+            continue;
+        };
+        valid += 1;
+
+        assert!(expr.iter().eq(nodes.iter()));
+        assert_eq!(expr.as_ref(), format!("{expr:#}"));
+
+        // The spans point at each requirement in the rendered expression, so
+        // that parsing it again gives the same expression
+        for er in expr.requirements() {
+            let span = er.span.start as usize..er.span.end as usize;
+            assert_eq!(&expr.as_ref()[span], er.req.to_string());
+        }
+        let parsed = Expression::parse(expr.as_ref()).unwrap();
+        assert_eq!(parsed, expr);
+        assert!(parsed
+            .requirements()
+            .zip(expr.requirements())
+            .all(|(a, b)| a.span == b.span));
+
+        let allow = |req: &spdx::LicenseReq| req.license.id().is_some();
+        assert_eq!(expr.evaluate(allow), parsed.evaluate(allow));
+        assert_eq!(
+            expr.evaluate_with_failures(allow),
+            parsed.evaluate_with_failures(allow)
+        );
+    }
+
+    assert!(valid > 100, "only {valid} node lists were valid");
+}
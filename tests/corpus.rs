@@ -0,0 +1,191 @@
+//! Parses a corpus of real world license strings, see `tests/fixtures/crates-io-licenses.txt`
+//!
+//! The corpus is a sample of a few hundred distinct strings rather than every
+//! license string on crates.io, so it catches regressions in the forms it
+//! covers, not in every form in use
+
+use spdx::{Expression, ParseMode};
+
+const CORPUS: &str = include_str!("fixtures/crates-io-licenses.txt");
+const EXPECTED_PATH: &str = "tests/fixtures/crates-io-licenses.expected";
+
+fn corpus() -> impl Iterator<Item = &'static str> {
+    CORPUS
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+}
+
+#[test]
+fn parses_corpus() {
+    let mut strict = 0;
+    let mut lax = 0;
+    let mut failed = Vec::new();
+
+    for text in corpus() {
+        if Expression::parse(text).is_ok() {
+            strict += 1;
+        } else if Expression::parse_mode(text, ParseMode::LAX).is_ok() {
+            lax += 1;
+        } else {
+            failed.push(text);
+        }
+    }
+
+    // Catches changes in what is accepted, if these change intentionally,
+    // update the counts
+    assert_eq!(
+        (strict, lax, failed.len()),
+        (147, 54, 65),
+        "failed to parse: {failed:#?}"
+    );
+}
+
+#[test]
+fn validate_agrees_with_parse() {
+    for text in corpus() {
+        for mode in [ParseMode::STRICT, ParseMode::LAX] {
+            assert_eq!(
+                Expression::validate(text, mode),
+                Expression::parse_mode(text, mode).map(|_| ()),
+                "{text}"
+            );
+        }
+    }
+}
+
+#[test]
+fn canonical_display_round_trips() {
+    for text in corpus() {
+        let Ok(expr) = Expression::parse_mode(text, ParseMode::LAX) else {
+            continue;
+        };
+
+        let rendered = format!("{expr:#}");
+        let reparsed = Expression::parse(&rendered).unwrap_or_else(|err| panic!("{text}: {err}"));
+        assert_eq!(reparsed, expr, "{text} => {rendered}");
+    }
+}
+
+#[test]
+fn canonicalize_batch_agrees() {
+    use spdx::expression::CanonicalizeResult;
+
+    let texts: Vec<_> = corpus().collect();
+    let results = Expression::canonicalize_batch(texts.iter().copied());
+    assert_eq!(results.len(), texts.len());
+
+    for (text, result) in texts.iter().zip(results) {
+        match result {
+            CanonicalizeResult::Canonical { .. } => {
+                assert_eq!(Expression::canonicalize(text), Ok(None), "{text}");
+            }
+            CanonicalizeResult::Fixed { canonical, .. } => {
+                assert_eq!(Expression::canonicalize(text), Ok(Some(canonical)));
+            }
+            CanonicalizeResult::Error { error, .. } => {
+                assert_eq!(
+                    Expression::parse_mode(text, ParseMode::LAX).unwrap_err(),
+                    error
+                );
+            }
+        }
+    }
+}
+
+/// The outcome of parsing a single corpus entry, one tab separated line per
+/// entry, the input, then either
+///
+/// * `strict`, and the canonical form if it differs from the input
+/// * `lax`, and the canonical form
+/// * `error`, and the reason and span of the `ParseMode::LAX` error
+fn outcome(text: &str) -> String {
+    if Expression::parse(text).is_ok() {
+        match Expression::canonicalize(text) {
+            Ok(Some(can)) => format!("{text}\tstrict\t{can}"),
+            _ => format!("{text}\tstrict"),
+        }
+    } else {
+        match Expression::canonicalize(text)
+            .and_then(|can| Expression::parse_mode(text, ParseMode::LAX).map(|_| can))
+        {
+            Ok(can) => format!("{text}\tlax\t{}", can.as_deref().unwrap_or(text)),
+            Err(err) => format!(
+                "{text}\terror\t{:?} {}..{}",
+                err.reason, err.span.start, err.span.end
+            ),
+        }
+    }
+}
+
+/// Compares the outcome of every corpus entry against the committed
+/// expectations, if the changes are intentional, regenerate the expectations
+/// with `SPDX_BLESS=1 cargo test --test corpus`
+#[test]
+fn corpus_matches_expectations() {
+    let mut actual = String::from(
+        "# Generated by `SPDX_BLESS=1 cargo test --test corpus`, do not edit by hand\n",
+    );
+    for text in corpus() {
+        actual.push_str(&outcome(text));
+        actual.push('\n');
+    }
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(EXPECTED_PATH);
+
+    if std::env::var_os("SPDX_BLESS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_default();
+    similar_asserts::assert_eq!(
+        expected,
+        actual,
+        "run `SPDX_BLESS=1 cargo test --test corpus` if the changes are intentional"
+    );
+}
+
+#[test]
+fn diagnostics_fix_corpus() {
+    for text in corpus() {
+        let mut diagnostics = Vec::new();
+        let Ok(expr) =
+            Expression::parse_mode_with_diagnostics(text, ParseMode::LAX, &mut diagnostics)
+        else {
+            continue;
+        };
+
+        // The diagnostics cover exactly the leniencies that were needed
+        let mut leniencies = spdx::Leniencies::empty();
+        for diag in &diagnostics {
+            leniencies |= diag.leniency;
+        }
+        assert_eq!(leniencies, expr.leniencies(), "{text}");
+
+        // Applying every fix gives a strictly valid expression
+        let mut fixed = String::new();
+        let mut end = 0;
+        for diag in &diagnostics {
+            if diag.span.start < end {
+                assert_eq!(diag.span.end, end, "{text}: overlapping diagnostics");
+                continue;
+            }
+
+            fixed.push_str(&text[end..diag.span.start]);
+            fixed.push_str(
+                diag.replacement
+                    .as_deref()
+                    .unwrap_or(&text[diag.span.clone()]),
+            );
+            end = diag.span.end;
+        }
+        fixed.push_str(&text[end..]);
+
+        let fixed_expr =
+            Expression::parse(&fixed).unwrap_or_else(|err| panic!("{text} => {fixed}: {err}"));
+        let (canonical, _) = Expression::parse_canonical(text, ParseMode::LAX).unwrap();
+        assert_eq!(fixed_expr, canonical, "{text} => {fixed}");
+        // Deprecated ids without a successor, eg. `GPL-2.0`, can't be fixed
+        assert!(!fixed_expr.leniencies().requires_lax(), "{text} => {fixed}");
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "text")]
+
+use spdx::detection::exact_match;
+
+#[test]
+fn matches_exact_texts() {
+    let mit = spdx::license_id("MIT").unwrap();
+    let text = mit.text().replace(
+        "Copyright (c) <year> <copyright holders>",
+        "Copyright (c) 2019 Embark Studios\nCopyright (c) 2020 Someone Else",
+    );
+    assert_eq!(exact_match(&text), Some(mit));
+
+    // Whitespace, including how the text is wrapped, doesn't matter
+    let rewrapped = text
+        .split("\n\n")
+        .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("\r\n  \r\n");
+    assert_eq!(exact_match(&rewrapped), Some(mit));
+
+    // Current licenses are preferred over deprecated ones with the same text
+    let gpl = spdx::license_id("GPL-2.0-only").unwrap();
+    assert_eq!(exact_match(gpl.text()), Some(gpl));
+
+    let apache = spdx::license_id("Apache-2.0").unwrap();
+    assert_eq!(exact_match(apache.text()), Some(apache));
+}
+
+#[test]
+fn rejects_other_texts() {
+    let mit = spdx::license_id("MIT").unwrap().text();
+
+    let reworded = mit.replace("free of charge", "at no cost");
+    assert_eq!(exact_match(&reworded), None);
+
+    // Only copyright notices before the body of the text are ignored
+    let appended = format!("{mit}\n\nCopyright (c) 2019 Embark Studios");
+    assert_eq!(exact_match(&appended), None);
+
+    assert_eq!(exact_match(""), None);
+    assert_eq!(exact_match("MIT"), None);
+}
+
+#[test]
+fn matches_every_text() {
+    for (index, (name, text)) in spdx::text::LICENSE_TEXTS.iter().enumerate() {
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let found = exact_match(text).unwrap_or_else(|| panic!("{name} is not matched"));
+
+        // Licenses with the same text are all matched to the same license
+        if found.index() != index {
+            assert_eq!(exact_match(found.text()), Some(found), "{name}");
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use spdx::{error::DecodeError, Expression, ParseMode};
+
+#[test]
+fn round_trips() {
+    for text in include_str!("fixtures/crates-io-licenses.txt")
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .chain([
+            "DocumentRef-Doc:LicenseRef-A+ WITH LLVM-exception OR LicenseRef-B",
+            "(GPL-2.0+ WITH Classpath-exception-2.0 OR MIT) AND (ISC OR Zlib)",
+        ])
+    {
+        let Ok(expr) = Expression::parse_mode(text, ParseMode::LAX) else {
+            continue;
+        };
+
+        let decoded = Expression::from_bytes(&expr.to_bytes()).unwrap();
+
+        // Expression's PartialEq ignores the spans and original string
+        assert_eq!(decoded.as_ref(), expr.as_ref());
+        assert_eq!(decoded.leniencies(), expr.leniencies());
+        assert_eq!(format!("{decoded:?}"), format!("{expr:?}"));
+        for (a, b) in decoded.iter().zip(expr.iter()) {
+            match (a, b) {
+                (spdx::expression::ExprNode::Req(a), spdx::expression::ExprNode::Req(b)) => {
+                    assert_eq!(a.span, b.span);
+                }
+                (a, b) => assert_eq!(a, b),
+            }
+        }
+    }
+}
+
+#[test]
+fn rejects_mismatched_list() {
+    let expr = Expression::parse("MIT OR Apache-2.0").unwrap();
+    let bytes = expr.to_bytes();
+
+    let version = spdx::identifiers::VERSION;
+    let pos = bytes
+        .windows(version.len())
+        .position(|w| w == version.as_bytes())
+        .unwrap();
+
+    // A different version of the list
+    let mut other = bytes.clone();
+    let last = pos + version.len() - 1;
+    other[last] = if other[last] == b'9' {
+        b'0'
+    } else {
+        other[last] + 1
+    };
+    match Expression::from_bytes(&other).unwrap_err() {
+        DecodeError::ListMismatch { version: found } => {
+            assert_ne!(found, version);
+            assert_eq!(found.len(), version.len());
+        }
+        err => panic!("unexpected error {err:?}"),
+    }
+
+    // The same version, but with different contents
+    let mut other = bytes.clone();
+    other[pos + version.len()] ^= 1;
+    assert_eq!(
+        Expression::from_bytes(&other).unwrap_err(),
+        DecodeError::ListMismatch {
+            version: version.to_owned()
+        }
+    );
+}
+
+#[test]
+fn rejects_malformed() {
+    let expr = Expression::parse("MIT OR Apache-2.0 WITH LLVM-exception").unwrap();
+    let bytes = expr.to_bytes();
+
+    assert_eq!(
+        Expression::from_bytes(b"MIT OR Apache-2.0").unwrap_err(),
+        DecodeError::UnknownFormat
+    );
+
+    // Truncated data never decodes
+    for len in 5..bytes.len() {
+        assert!(Expression::from_bytes(&bytes[..len]).is_err(), "{len}");
+    }
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        Expression::from_bytes(&trailing).unwrap_err(),
+        DecodeError::Malformed
+    );
+
+    // The node count directly follows the original string
+    let encode = |text: &str| {
+        let bytes = Expression::parse(text).unwrap().to_bytes();
+        let count = bytes
+            .windows(text.len())
+            .position(|w| w == text.as_bytes())
+            .unwrap()
+            + text.len();
+        (bytes, count)
+    };
+
+    // An operator without 2 operands
+    let (mut bad, count) = encode("MIT");
+    bad[count] += 1;
+    bad.push(1);
+    assert_eq!(
+        Expression::from_bytes(&bad).unwrap_err(),
+        DecodeError::Malformed
+    );
+
+    // Operands without an operator
+    let (mut bad, count) = encode("MIT OR ISC");
+    assert_eq!(bad.pop(), Some(1));
+    bad[count] -= 1;
+    assert_eq!(
+        Expression::from_bytes(&bad).unwrap_err(),
+        DecodeError::Malformed
+    );
+
+    // A span that splits a character of the original string, which is the
+    // same length so the span is still in bounds
+    let text = "MIT OR Apache-2.0";
+    let (mut bad, count) = encode(text);
+    let start = count - text.len();
+    bad[start..count].copy_from_slice("MI\u{e9}OR Apache-2.0".as_bytes());
+    assert_eq!(
+        Expression::from_bytes(&bad).unwrap_err(),
+        DecodeError::Malformed
+    );
+}
+
+#[test]
+fn flat_round_trips() {
+    use spdx::expression::{ExprNode, FlatNode};
+
+    for text in include_str!("fixtures/crates-io-licenses.txt")
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .chain([
+            "DocumentRef-Doc:LicenseRef-A+ WITH LLVM-exception OR LicenseRef-B",
+            "(GPL-2.0+ WITH Classpath-exception-2.0 OR MIT) AND (ISC OR Zlib)",
+            "LicenseRef-A AND DocumentRef-A:LicenseRef-A AND Apache-2.0+",
+        ])
+    {
+        let Ok(expr) = Expression::parse_mode(text, ParseMode::LAX) else {
+            continue;
+        };
+
+        let flat = expr.flatten();
+        assert_eq!(flat.nodes.len(), expr.iter().count());
+
+        let rebuilt = flat.rebuild().unwrap();
+        assert_eq!(rebuilt.as_ref(), expr.as_ref());
+        assert_eq!(rebuilt.leniencies(), expr.leniencies());
+        assert_eq!(format!("{rebuilt:?}"), format!("{expr:?}"));
+        for (a, b) in rebuilt.iter().zip(expr.iter()) {
+            match (a, b) {
+                (ExprNode::Req(a), ExprNode::Req(b)) => assert_eq!(a.span, b.span),
+                (a, b) => assert_eq!(a, b),
+            }
+        }
+    }
+
+    let flat = Expression::parse(
+        "DocumentRef-Doc:LicenseRef-A WITH LLVM-exception AND (LicenseRef-Doc OR GPL-2.0-or-later)",
+    )
+    .unwrap()
+    .flatten();
+
+    // Strings are shared between nodes
+    assert_eq!(flat.strings, ["A", "Doc"]);
+
+    let llvm = spdx::exception_id("LLVM-exception").unwrap().index() as i32;
+    let gpl = spdx::license_id("GPL-2.0").unwrap().index() as i32;
+    assert_eq!(
+        flat.nodes
+            .iter()
+            .map(|node| {
+                (
+                    node.op_code,
+                    node.license_index,
+                    node.exception_index,
+                    node.or_later,
+                    node.ref_string_index,
+                    node.doc_ref_string_index,
+                )
+            })
+            .collect::<Vec<_>>(),
+        [
+            (FlatNode::REF, -1, llvm, false, 0, 1),
+            (FlatNode::REF, -1, -1, false, 1, -1),
+            (FlatNode::LICENSE, gpl, -1, true, -1, -1),
+            (FlatNode::OR, -1, -1, false, -1, -1),
+            (FlatNode::AND, -1, -1, false, -1, -1),
+        ]
+    );
+}
+
+#[test]
+fn flat_rejects_malformed() {
+    let flat = Expression::parse("MIT OR LicenseRef-A WITH LLVM-exception")
+        .unwrap()
+        .flatten();
+
+    let malformed = |modify: &dyn Fn(&mut spdx::expression::FlatExpr)| {
+        let mut flat = flat.clone();
+        modify(&mut flat);
+        flat.rebuild().unwrap_err()
+    };
+
+    assert_eq!(
+        malformed(&|flat| flat.nodes[0].op_code = 4),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.nodes[0].license_index = -2),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.nodes[0].license_index = i32::MAX),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.nodes[1].exception_index = i32::MAX),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.nodes[1].ref_string_index = 1),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.strings[0] = "not an id".to_owned()),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.nodes[1].span_end = 100),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.original = "MI\u{e9}OR LicenseRef-A WITH LLVM-exception".to_owned()),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| {
+            flat.nodes.pop();
+        }),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.nodes.swap(1, 2)),
+        DecodeError::Malformed
+    );
+    assert_eq!(
+        malformed(&|flat| flat.leniencies = 0xff),
+        DecodeError::Malformed
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserializes() {
+    use serde::{de::value, Deserialize};
+
+    let expr = Expression::parse("MIT OR Apache-2.0 WITH LLVM-exception").unwrap();
+    let bytes = expr.to_bytes();
+
+    let de = value::BytesDeserializer::<value::Error>::new(&bytes);
+    assert_eq!(Expression::deserialize(de).unwrap(), expr);
+
+    // Formats that encode bytes as sequences
+    let de = value::SeqDeserializer::<_, value::Error>::new(bytes.iter().copied());
+    assert_eq!(Expression::deserialize(de).unwrap(), expr);
+
+    let de = value::BytesDeserializer::<value::Error>::new(&bytes[..bytes.len() - 1]);
+    assert_eq!(
+        Expression::deserialize(de).unwrap_err().to_string(),
+        "the encoded expression is malformed"
+    );
+}
@@ -112,7 +112,8 @@ fn fails_with_slash() {
         lexer.next().unwrap().unwrap_err(),
         spdx::ParseError {
             original: "MIT/Apache-2.0".to_owned(),
-            span: 3..14,
+            mode: spdx::ParseMode::STRICT,
+            span: 3..4,
             reason: spdx::error::Reason::InvalidCharacters,
         }
     );
@@ -159,3 +160,175 @@ fn lexes_complex() {
         ]
     );
 }
+
+#[test]
+fn trivia_round_trips() {
+    use spdx::lexer::TriviaToken;
+
+    for (text, mode) in [
+        ("MIT", spdx::ParseMode::STRICT),
+        ("  MIT OR\tApache-2.0  ", spdx::ParseMode::STRICT),
+        (
+            "( Apache-2.0 WITH LLVM-exception )\nOR MIT",
+            spdx::ParseMode::STRICT,
+        ),
+        ("MIT+ AND  LicenseRef-Hello", spdx::ParseMode::STRICT),
+        ("gpl v2 / bsd 2-clause", spdx::ParseMode::LAX),
+        ("", spdx::ParseMode::STRICT),
+        ("   ", spdx::ParseMode::STRICT),
+    ] {
+        let items: Vec<_> = Lexer::new_mode(text, mode)
+            .tokens_with_trivia()
+            .map(Result::unwrap)
+            .collect();
+
+        let mut end = 0;
+        let mut rebuilt = String::new();
+        for item in &items {
+            let span = item.span();
+            assert_eq!(span.start, end, "gap before {item:?} in {text:?}");
+            end = span.end;
+
+            if let TriviaToken::Trivia { span } = item {
+                assert!(text[span.clone()].chars().all(char::is_whitespace));
+            }
+
+            rebuilt.push_str(&text[span]);
+        }
+
+        assert_eq!(rebuilt, text);
+    }
+}
+
+#[test]
+fn trivia_stops_on_error() {
+    use spdx::lexer::TriviaToken;
+
+    let mut lexer = Lexer::new("MIT +").tokens_with_trivia();
+    assert!(matches!(
+        lexer.next().unwrap().unwrap(),
+        TriviaToken::Token(lt) if lt.token == lic_tok!("MIT")
+    ));
+    assert_eq!(
+        lexer.next().unwrap().unwrap_err().reason,
+        spdx::error::Reason::SeparatedPlus
+    );
+    assert!(lexer.next().is_none());
+}
+
+#[test]
+fn recovers_after_error() {
+    use spdx::error::Reason;
+
+    fn collect(mut lexer: Lexer<'_>) -> (Vec<Token<'_>>, Vec<(Reason, std::ops::Range<usize>)>) {
+        let mut tokens = Vec::new();
+        let mut errors = Vec::new();
+
+        while let Some(res) = lexer.next() {
+            match res {
+                Ok(lt) => tokens.push(lt.token),
+                Err(err) => {
+                    errors.push((err.reason, err.span));
+                    lexer.recover();
+                }
+            }
+        }
+
+        (tokens, errors)
+    }
+
+    let lexer = Lexer::new("MIT ?? Apache-2.0");
+    assert_eq!(lexer.original(), "MIT ?? Apache-2.0");
+    assert_eq!(lexer.offset(), 0);
+
+    let (tokens, errors) = collect(lexer);
+    assert_eq!(tokens, [lic_tok!("MIT"), lic_tok!("Apache-2.0")]);
+    assert_eq!(errors, [(Reason::InvalidCharacters, 4..6)]);
+
+    // Without recovering, the same error is yielded again
+    let mut lexer = Lexer::new("MIT ?? Apache-2.0");
+    lexer.next().unwrap().unwrap();
+    let first = lexer.next().unwrap().unwrap_err();
+    assert_eq!(lexer.offset(), 4);
+    assert_eq!(lexer.remaining(), "?? Apache-2.0");
+    assert_eq!(lexer.next().unwrap().unwrap_err(), first);
+
+    // Recovery stops at parentheses, and always makes progress
+    let (tokens, errors) = collect(Lexer::new("(nope+MIT) AND ISC +"));
+    assert_eq!(
+        tokens,
+        [
+            Token::OpenParen,
+            Token::CloseParen,
+            Token::And,
+            lic_tok!("ISC")
+        ]
+    );
+    assert_eq!(
+        errors,
+        [(Reason::UnknownTerm, 1..5), (Reason::SeparatedPlus, 19..20)]
+    );
+
+    let lexer = Lexer::new_mode("mit/apache-2.0", spdx::ParseMode::LAX);
+    assert!(lexer.mode().allow_slash_as_or_operator);
+}
+
+#[test]
+fn imprecise_tokens() {
+    fn tokens<'a>(
+        text: &'a str,
+        synonyms: Option<&'a spdx::Synonyms>,
+    ) -> Vec<(Token<'a>, &'a str, bool)> {
+        let mut lexer = Lexer::new_mode(text, spdx::ParseMode::LAX);
+        if let Some(synonyms) = synonyms {
+            lexer = lexer.with_synonyms(synonyms);
+        }
+
+        lexer
+            .map(|lt| {
+                let lt = lt.unwrap();
+                assert_eq!(lt.text, &text[lt.span.clone()]);
+                (lt.token, lt.text, lt.imprecise)
+            })
+            .collect()
+    }
+
+    assert_eq!(
+        tokens("gpl v2 / bsd 2-clause", None),
+        [
+            (lic_tok!("GPL-2.0"), "gpl v2", true),
+            (Token::Or, "/", false),
+            (lic_tok!("BSD-2-Clause"), "bsd 2-clause", true),
+        ]
+    );
+
+    // Exact ids, and operators, are never imprecise, even if lowercase
+    assert_eq!(
+        tokens("MIT or GPL-2.0+", None),
+        [
+            (lic_tok!("MIT"), "MIT", false),
+            (Token::Or, "or", false),
+            (lic_tok!("GPL-2.0"), "GPL-2.0", false),
+            (Token::Plus, "+", false),
+        ]
+    );
+
+    // Every token of an expanded expression has the text of the whole synonym
+    let mut synonyms = spdx::Synonyms::new();
+    synonyms
+        .add_expression("dual", "MIT OR Apache-2.0")
+        .unwrap();
+
+    assert_eq!(
+        tokens("ISC AND dual", Some(&synonyms)),
+        [
+            (lic_tok!("ISC"), "ISC", false),
+            (Token::And, "AND", false),
+            (Token::OpenParen, "dual", true),
+            (lic_tok!("MIT"), "dual", true),
+            (Token::Or, "dual", true),
+            (lic_tok!("Apache-2.0"), "dual", true),
+            (Token::CloseParen, "dual", true),
+        ]
+    );
+}
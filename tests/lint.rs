@@ -0,0 +1,217 @@
+use spdx::{
+    expression::{LintKind, Severity},
+    Expression, ParseMode,
+};
+
+macro_rules! lints {
+    ($mode:expr, $text:expr => [$($kind:ident @ [$($span:expr),+]),*$(,)?]) => {
+        let expr = Expression::parse_mode($text, $mode).unwrap();
+        let actual: Vec<_> = expr
+            .lint()
+            .into_iter()
+            .map(|lint| {
+                assert_eq!(lint.severity, lint.kind.severity());
+                (lint.kind, lint.spans)
+            })
+            .collect();
+        let expected: Vec<(LintKind, Vec<std::ops::Range<u32>>)> =
+            vec![$((LintKind::$kind, vec![$($span),+])),*];
+
+        similar_asserts::assert_eq!(actual, expected, "{}", $text);
+    };
+    ($text:expr => [$($kind:ident @ [$($span:expr),+]),*$(,)?]) => {
+        lints!(ParseMode::STRICT, $text => [$($kind @ [$($span),+]),*]);
+    };
+}
+
+#[test]
+fn clean() {
+    for text in [
+        "MIT",
+        "MIT OR Apache-2.0",
+        "MIT AND (Apache-2.0 OR ISC)",
+        "Apache-2.0 WITH LLVM-exception OR MIT",
+        "GPL-2.0-only AND LGPL-2.1-or-later",
+        "GPL-2.0-or-later AND GPL-3.0-only",
+        "LicenseRef-A OR DocumentRef-B:LicenseRef-A",
+        "MIT AND (ISC OR MIT)",
+        // Different variants of the same version of a GNU license
+        "GFDL-1.3-invariants-only AND GFDL-1.3-no-invariants-only",
+    ] {
+        lints!(text => []);
+    }
+}
+
+#[test]
+fn duplicates() {
+    lints!("MIT AND MIT" => [DuplicateRequirement @ [0..3, 8..11]]);
+    lints!("MIT OR ISC OR MIT OR MIT" => [DuplicateRequirement @ [0..3, 14..17, 21..24]]);
+    lints!("(MIT AND ISC) AND (Zlib AND ISC)" => [DuplicateRequirement @ [9..12, 28..31]]);
+    lints!("LicenseRef-A AND LicenseRef-A" => [DuplicateRequirement @ [0..12, 17..29]]);
+}
+
+#[test]
+fn deprecated() {
+    lints!("StandardML-NJ OR MIT" => [DeprecatedId @ [0..13]]);
+    lints!("MIT AND wxWindows OR GPL-3.0" => [DeprecatedId @ [8..17], DeprecatedId @ [21..28]]);
+    lints!("GPL-3.0-only WITH Nokia-Qt-exception-1.1" => [DeprecatedId @ [0..40]]);
+    lints!(ParseMode::LAX, "GPL-2.0+" => [DeprecatedId @ [0..8]]);
+}
+
+#[test]
+fn plus_on_or_later() {
+    lints!(ParseMode::LAX, "MIT OR GPL-2.0-or-later+" => [PlusOnOrLater @ [7..24]]);
+}
+
+#[test]
+fn subsumed() {
+    lints!("Apache-2.0 OR Apache-2.0 WITH LLVM-exception" => [SubsumedBranch @ [14..44]]);
+    lints!("Apache-2.0 WITH LLVM-exception OR Apache-2.0" => [SubsumedBranch @ [0..30]]);
+    lints!("MIT OR (ISC OR MIT+)" => [SubsumedBranch @ [0..3]]);
+    lints!("GPL-2.0-only OR GPL-2.0-or-later" => [SubsumedBranch @ [0..12]]);
+    // Only applies to OR
+    lints!("MIT+ AND MIT" => []);
+}
+
+#[test]
+fn exclusive_gnu() {
+    lints!("GPL-2.0-only AND GPL-3.0-only" => [ExclusiveGnuVersions @ [0..12, 17..29]]);
+    lints!("MIT AND (LGPL-2.1-only AND LGPL-3.0-only)" => [ExclusiveGnuVersions @ [9..22, 27..40]]);
+    // Composite ids are compared by the version of the license they include,
+    // but are deprecated
+    lints!("GPL-2.0-only AND GPL-2.0-with-classpath-exception" => [DeprecatedId @ [17..49]]);
+    lints!("GPL-3.0-only AND GPL-2.0-with-classpath-exception" => [ExclusiveGnuVersions @ [0..12, 17..49], DeprecatedId @ [17..49]]);
+    lints!("GFDL-1.2-invariants-only AND GFDL-1.3-only" => [ExclusiveGnuVersions @ [0..24, 29..42]]);
+    // Only applies to AND
+    lints!("GPL-2.0-only OR GPL-3.0-only" => []);
+}
+
+#[test]
+fn severity() {
+    assert_eq!(LintKind::SubsumedBranch.severity(), Severity::Info);
+    assert_eq!(LintKind::DeprecatedId.severity(), Severity::Warning);
+    assert_eq!(LintKind::ExclusiveGnuVersions.severity(), Severity::Error);
+    assert!(Severity::Info < Severity::Warning && Severity::Warning < Severity::Error);
+}
+
+#[test]
+fn parse_diagnostics() {
+    use spdx::Leniencies;
+
+    let diagnose = |text, mode| {
+        let mut diagnostics = Vec::new();
+        let expr = Expression::parse_mode_with_diagnostics(text, mode, &mut diagnostics).unwrap();
+        assert_eq!(expr, Expression::parse_mode(text, mode).unwrap());
+        diagnostics
+    };
+
+    let actual: Vec<_> = diagnose("apache/GPL-2.0+", ParseMode::LAX)
+        .into_iter()
+        .map(|d| {
+            assert_eq!(d.code(), d.leniency.iter().next().unwrap().0);
+            (d.code(), d.severity, d.span, d.replacement)
+        })
+        .collect();
+
+    similar_asserts::assert_eq!(
+        actual,
+        [
+            (
+                "IMPRECISE_NAME",
+                Severity::Warning,
+                0..6,
+                Some("Apache-2.0".to_owned())
+            ),
+            (
+                "SLASH_OPERATOR",
+                Severity::Warning,
+                6..7,
+                Some(" OR ".to_owned())
+            ),
+            (
+                "DEPRECATED_ID",
+                Severity::Warning,
+                7..15,
+                Some("GPL-2.0-or-later".to_owned())
+            ),
+            (
+                "GPL_PLUS",
+                Severity::Warning,
+                7..15,
+                Some("GPL-2.0-or-later".to_owned())
+            ),
+        ]
+    );
+
+    // Strictly valid expressions have no diagnostics, other than deprecated ids
+    assert!(diagnose("MIT OR Apache-2.0", ParseMode::STRICT).is_empty());
+    assert!(diagnose("MIT", ParseMode::LAX).is_empty());
+
+    let actual: Vec<_> = diagnose("'GPL-2.0 and MIT / wxWindows'", ParseMode::LAX)
+        .into_iter()
+        .map(|d| (d.leniency, d.span, d.replacement))
+        .collect();
+
+    similar_asserts::assert_eq!(
+        actual,
+        [
+            (Leniencies::SURROUNDING_QUOTES, 0..1, Some(String::new())),
+            // `GPL-2.0` is the `-only` variant
+            (
+                Leniencies::DEPRECATED_ID,
+                1..8,
+                Some("GPL-2.0-only".to_owned())
+            ),
+            (
+                Leniencies::LOWERCASE_OPERATOR,
+                9..12,
+                Some("AND".to_owned())
+            ),
+            (Leniencies::SLASH_OPERATOR, 17..18, Some("OR".to_owned())),
+            (
+                Leniencies::DEPRECATED_ID,
+                19..28,
+                Some("LGPL-2.0-or-later WITH WxWindows-exception-3.1".to_owned())
+            ),
+            (Leniencies::SURROUNDING_QUOTES, 28..29, Some(String::new())),
+        ]
+    );
+
+    // Deprecated ids are also reported in strict mode, but not every one has a fix
+    let actual: Vec<_> = diagnose("wxWindows WITH Classpath-exception-2.0", ParseMode::STRICT)
+        .into_iter()
+        .map(|d| (d.leniency, d.span, d.replacement))
+        .collect();
+    assert_eq!(actual, [(Leniencies::DEPRECATED_ID, 0..9, None)]);
+
+    // Applying the replacements gives the canonical expression, including the
+    // whitespace next to surrounding quotes
+    for text in [
+        "'MIT or ISC'",
+        " 'MIT or ISC' ",
+        "\u{feff}\"mit/apache2\"",
+        "\u{feff} MIT",
+        "` GPL-2.0+ `",
+        "'GPL-2.0 and MIT / wxWindows'",
+    ] {
+        let mut fixed = text.to_owned();
+        let mut last_span = None;
+
+        for d in diagnose(text, ParseMode::LAX).into_iter().rev() {
+            if last_span.as_ref() == Some(&d.span) {
+                continue;
+            }
+
+            if let Some(replacement) = &d.replacement {
+                fixed.replace_range(d.span.clone(), replacement);
+            }
+            last_span = Some(d.span);
+        }
+
+        assert_eq!(
+            Some(fixed),
+            Expression::canonicalize(text).unwrap(),
+            "{text:?}"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use spdx::{
+    expression::{ExprNode, Operator},
+    identifiers::{EXCEPTIONS, IS_GNU, LICENSES},
+    Expression, LicenseReq, ParseMode,
+};
+
+/// A simple xorshift so that the generated expressions are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, max: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % max as u64) as usize
+    }
+
+    fn one_in(&mut self, n: usize) -> bool {
+        self.next(n) == 0
+    }
+}
+
+/// A token of a generated expression, which is rendered either cleanly, or
+/// mangled with syntax that only [`ParseMode::LAX`] accepts
+enum Tok {
+    Term(String),
+    And,
+    Or,
+    With,
+    Open,
+    Close,
+}
+
+fn gen_term(rng: &mut Rng, toks: &mut Vec<Tok>) {
+    if rng.one_in(10) {
+        let lic_ref = format!("LicenseRef-gen-{}", rng.next(4));
+        let term = if rng.one_in(2) {
+            format!("DocumentRef-doc{}:{lic_ref}", rng.next(3))
+        } else {
+            lic_ref
+        };
+        toks.push(Tok::Term(term));
+        return;
+    }
+
+    // Deprecated ids that end with `+` are a GNU license followed by a `+`,
+    // which isn't allowed in a strict expression
+    let (id, flags) = loop {
+        let (id, _, flags) = LICENSES[rng.next(LICENSES.len())];
+        if !id.ends_with('+') {
+            break (id, flags);
+        }
+    };
+
+    let term = if flags & IS_GNU == 0 && rng.one_in(6) {
+        format!("{id}+")
+    } else {
+        id.to_owned()
+    };
+    toks.push(Tok::Term(term));
+
+    if rng.one_in(6) {
+        toks.push(Tok::With);
+        toks.push(Tok::Term(
+            EXCEPTIONS[rng.next(EXCEPTIONS.len())].0.to_owned(),
+        ));
+    }
+}
+
+/// Generates a random, valid, expression, with operands nested no deeper than
+/// `depth`
+fn gen_expr(rng: &mut Rng, depth: usize, toks: &mut Vec<Tok>) {
+    if depth == 0 || rng.one_in(3) {
+        gen_term(rng, toks);
+        return;
+    }
+
+    // Nested operations are always parenthesized, since the grouping of
+    // unparenthesized operators is not what is being tested
+    let operand = |rng: &mut Rng, toks: &mut Vec<Tok>| {
+        let start = toks.len();
+        gen_expr(rng, depth - 1, toks);
+        if toks.len() - start > 1 || rng.one_in(8) {
+            toks.insert(start, Tok::Open);
+            toks.push(Tok::Close);
+        }
+    };
+
+    operand(rng, toks);
+    toks.push(if rng.one_in(2) { Tok::And } else { Tok::Or });
+    operand(rng, toks);
+}
+
+fn render_clean(toks: &[Tok]) -> String {
+    let mut s = String::new();
+    for tok in toks {
+        if !s.is_empty() && !s.ends_with('(') && !matches!(tok, Tok::Close) {
+            s.push(' ');
+        }
+        s.push_str(match tok {
+            Tok::Term(term) => term,
+            Tok::And => "AND",
+            Tok::Or => "OR",
+            Tok::With => "WITH",
+            Tok::Open => "(",
+            Tok::Close => ")",
+        });
+    }
+    s
+}
+
+fn render_mangled(rng: &mut Rng, toks: &[Tok]) -> String {
+    let mut s = String::new();
+    let mut glue = false;
+    for tok in toks {
+        let text = match tok {
+            Tok::Term(term) => term.as_str(),
+            Tok::And => ["and", "AND"][rng.next(2)],
+            Tok::Or => ["or", "/", "/", "OR"][rng.next(4)],
+            Tok::With => ["with", "WITH"][rng.next(2)],
+            Tok::Open => "(",
+            Tok::Close => ")",
+        };
+
+        // A slash doesn't need to be separated from its operands
+        let is_slash = text == "/";
+        let unspaced = s.is_empty() || glue || is_slash && rng.one_in(2);
+        if !unspaced {
+            s.push(' ');
+        }
+        s.push_str(text);
+        glue = is_slash && rng.one_in(2);
+    }
+    s
+}
+
+/// Evaluates the postfix nodes of the expression directly, which is how its
+/// [`Debug`](std::fmt::Debug) output is written
+fn evaluate_postfix(expr: &Expression, allow: &impl Fn(&LicenseReq) -> bool) -> bool {
+    let mut stack = Vec::new();
+    for node in expr.iter() {
+        match node {
+            ExprNode::Req(er) => stack.push(allow(&er.req)),
+            ExprNode::Op(op) => {
+                let b = stack.pop().unwrap();
+                let a = stack.pop().unwrap();
+                stack.push(match op {
+                    Operator::And => a && b,
+                    Operator::Or => a || b,
+                });
+            }
+        }
+    }
+
+    assert_eq!(stack.len(), 1);
+    stack[0]
+}
+
+#[test]
+fn round_trips() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..3000 {
+        let mut toks = Vec::new();
+        gen_expr(&mut rng, 4, &mut toks);
+
+        let clean = render_clean(&toks);
+        let expr = Expression::parse(&clean)
+            .unwrap_or_else(|err| panic!("failed to parse '{clean}': {err}"));
+
+        // The original and canonical forms both parse to the same expression
+        let display = expr.to_string();
+        assert_eq!(Expression::parse(&display).unwrap(), expr, "{clean}");
+
+        let canonical = format!("{expr:#}");
+        let reparsed = Expression::parse(&canonical)
+            .unwrap_or_else(|err| panic!("failed to parse '{canonical}' from '{clean}': {err}"));
+        assert_eq!(reparsed, expr, "{clean} => {canonical}");
+        assert_eq!(format!("{reparsed:?}"), format!("{expr:?}"));
+
+        // Canonicalizing gives a strict expression, which is already canonical
+        let can = Expression::canonicalize(&clean)
+            .unwrap()
+            .unwrap_or(clean.clone());
+        Expression::parse(&can)
+            .unwrap_or_else(|err| panic!("failed to parse '{can}' from '{clean}': {err}"));
+        assert_eq!(
+            Expression::canonicalize(&can).unwrap(),
+            None,
+            "{clean} => {can}"
+        );
+
+        // The postfix nodes and the infix renderings all evaluate the same
+        for _ in 0..4 {
+            let seed = rng.next(usize::MAX);
+            let allow = |req: &LicenseReq| {
+                let hash = req
+                    .to_string()
+                    .bytes()
+                    .fold(seed, |h, b| h.wrapping_mul(31).wrapping_add(b as usize));
+                hash % 3 != 0
+            };
+
+            let expected = evaluate_postfix(&expr, &allow);
+            assert_eq!(expr.evaluate(allow), expected, "{clean}");
+            assert_eq!(reparsed.evaluate(allow), expected, "{canonical}");
+        }
+
+        // Lax syntax parses to the same expression as the clean form
+        let mangled = render_mangled(&mut rng, &toks);
+        let lax = Expression::parse_mode(&mangled, ParseMode::LAX)
+            .unwrap_or_else(|err| panic!("failed to parse '{mangled}': {err}"));
+        assert_eq!(lax, expr, "{mangled} != {clean}");
+    }
+}
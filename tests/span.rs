@@ -0,0 +1,129 @@
+use spdx::{
+    span::{to_char_indices, to_utf16},
+    Expression, ParseMode,
+};
+
+#[test]
+fn converts_multibyte() {
+    // 2 bytes, 1 UTF-16 code unit, 4 bytes, 2 UTF-16 code units (a surrogate
+    // pair), and 3 bytes, 1 UTF-16 code unit
+    let text = "é😀€ MIT";
+
+    assert_eq!(to_utf16(text, 0..2), 0..1);
+    assert_eq!(to_utf16(text, 2..6), 1..3);
+    assert_eq!(to_utf16(text, 6..9), 3..4);
+    assert_eq!(to_utf16(text, 10..13), 5..8);
+
+    assert_eq!(to_char_indices(text, 0..2), 0..1);
+    assert_eq!(to_char_indices(text, 2..6), 1..2);
+    assert_eq!(to_char_indices(text, 6..9), 2..3);
+    assert_eq!(to_char_indices(text, 10..13), 4..7);
+
+    // Empty spans, eg. an unexpected end of the expression
+    assert_eq!(to_utf16(text, 13..13), 8..8);
+    assert_eq!(to_utf16(text, 0..0), 0..0);
+
+    // Offsets past the end are clamped, and offsets inside of a character
+    // are rounded up
+    assert_eq!(to_utf16(text, 10..100), 5..8);
+    assert_eq!(to_utf16(text, 3..5), 3..3);
+    assert_eq!(to_utf16(text, 1..3), 1..3);
+    assert_eq!(to_char_indices(text, 3..7), 2..3);
+}
+
+#[test]
+fn parse_error_spans() {
+    let err = Expression::parse("MIT OR 😀").unwrap_err();
+    assert_eq!(err.span, 7..11);
+    assert_eq!(err.span_utf16(), 7..9);
+    assert_eq!(to_char_indices(&err.original, err.span.clone()), 7..8);
+
+    // A surrogate pair in a DocumentRef name, which isn't a valid ref
+    let text = "DocumentRef-😀:LicenseRef-Embark";
+    let err = Expression::parse(text).unwrap_err();
+    assert_eq!(err.span_utf16(), 0..12);
+    assert_eq!(to_utf16(text, 12..16), 12..14);
+    assert_eq!(
+        to_utf16(text, 0..text.len()),
+        0..text.encode_utf16().count()
+    );
+    assert_eq!(
+        to_char_indices(text, 0..text.len()),
+        0..text.chars().count()
+    );
+
+    let err = Expression::parse("é OR").unwrap_err();
+    assert_eq!(err.span_utf16(), 0..1);
+
+    let err = Expression::parse_mode("\u{feff}\"MIT OR\"", ParseMode::LAX).unwrap_err();
+    assert_eq!(err.span, 11..11);
+    assert_eq!(err.span_utf16(), 9..9);
+}
+
+#[test]
+fn requirement_spans() {
+    let text = "\u{feff}\"MIT OR LicenseRef-Embark\"";
+    let expr = Expression::parse_mode(text, ParseMode::LAX).unwrap();
+
+    let spans: Vec<_> = expr.requirements().map(|er| er.span_utf16(text)).collect();
+    assert_eq!(spans, [2..5, 9..26]);
+
+    for (er, span) in expr.requirements().zip(spans) {
+        let utf16: Vec<_> = text.encode_utf16().collect();
+        assert_eq!(
+            String::from_utf16(&utf16[span]).unwrap(),
+            er.req.to_string()
+        );
+    }
+}
+
+#[test]
+fn requirement_at_offset() {
+    let text = "(MIT OR Apache-2.0+  WITH LLVM-exception) AND GPL-2.0-or-later";
+    let expr = Expression::parse(text).unwrap();
+
+    let req_at = |offset| expr.req_at_offset(offset).map(|er| er.req.to_string());
+    let apache = Some("Apache-2.0+ WITH LLVM-exception".to_owned());
+
+    // On the parenthesis, the license, and the whitespace and operator after it
+    assert_eq!(req_at(0), None);
+    assert_eq!(req_at(1).as_deref(), Some("MIT"));
+    assert_eq!(req_at(3).as_deref(), Some("MIT"));
+    assert_eq!(req_at(4), None);
+    assert_eq!(req_at(5), None);
+
+    // Anywhere in the license, `+`, `WITH`, exception, and the whitespace
+    // between them
+    for offset in 8..40 {
+        assert_eq!(req_at(offset), apache, "{offset}: {}", &text[offset..]);
+    }
+    assert_eq!(req_at(40), None);
+
+    assert_eq!(req_at(46).as_deref(), Some("GPL-2.0-or-later"));
+    assert_eq!(req_at(text.len() - 1).as_deref(), Some("GPL-2.0-or-later"));
+    assert_eq!(req_at(text.len()), None);
+    assert_eq!(req_at(1000), None);
+
+    // Lowercase operators, and a composite id whose exception isn't written
+    let text = "mit with LLVM-exception/GPL-2.0-with-classpath-exception";
+    let mode = ParseMode {
+        expand_composite_ids: true,
+        ..ParseMode::LAX
+    };
+    let expr = Expression::parse_mode(text, mode).unwrap();
+
+    assert_eq!(
+        expr.req_at_offset(10).map(|er| er.span.clone()),
+        Some(0..23)
+    );
+    assert_eq!(expr.req_at_offset(23), None);
+    assert_eq!(
+        expr.req_at_offset(50).map(|er| er.req.to_string()),
+        Some("GPL-2.0 WITH Classpath-exception-2.0".to_owned())
+    );
+
+    let req = |s| spdx::LicenseReq::parse(s, ParseMode::STRICT).unwrap();
+    assert!(expr.contains_req(&req("MIT WITH LLVM-exception")));
+    assert!(expr.contains_req(&req("GPL-2.0-only WITH Classpath-exception-2.0")));
+    assert!(!expr.contains_req(&req("MIT")));
+}
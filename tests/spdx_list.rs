@@ -65,3 +65,522 @@ fn handles_copyleft() {
     assert!(gpl.is_copyleft());
     assert_eq!(gpl.full_name, "GNU General Public License v3.0 or later");
 }
+
+#[test]
+fn handles_cc_and_public_domain() {
+    // (id, cc, non-commercial, public domain equivalent)
+    for (id, cc, nc, pd) in [
+        ("CC-BY-NC-4.0", true, true, false),
+        ("CC-BY-NC-SA-2.0-UK", true, true, false),
+        ("CC-BY-4.0", true, false, false),
+        ("CC-BY-SA-4.0", true, false, false),
+        ("CC0-1.0", true, false, true),
+        ("CC-PDDC", true, false, true),
+        ("PolyForm-Noncommercial-1.0.0", false, true, false),
+        ("Unlicense", false, false, true),
+        ("0BSD", false, false, true),
+        ("blessing", false, false, true),
+        ("SAX-PD", false, false, true),
+        ("MIT", false, false, false),
+        ("MIT-0", false, false, false),
+        ("NCSA", false, false, false),
+        ("GPL-3.0-or-later", false, false, false),
+    ] {
+        let lic = license_id(id).unwrap();
+        assert_eq!(
+            (
+                lic.is_cc(),
+                lic.is_non_commercial(),
+                lic.is_public_domain_equivalent()
+            ),
+            (cc, nc, pd),
+            "{id}"
+        );
+    }
+
+    // Every non-commercial CC license is a CC license
+    assert!(spdx::identifiers::LICENSES
+        .iter()
+        .filter(|(id, ..)| id.starts_with("CC-"))
+        .all(|(id, ..)| {
+            let lic = license_id(id).unwrap();
+            lic.is_cc() && lic.is_non_commercial() == id.contains("-NC")
+        }));
+}
+
+#[test]
+fn imprecise_names_are_valid_and_sorted() {
+    let names: Vec<_> = spdx::imprecise_names().collect();
+
+    for name in &names {
+        assert!(license_id(name.id).is_some(), "{name:?}");
+        assert_eq!(name.pattern, name.pattern.to_lowercase());
+    }
+
+    // Longest first is required for the first match to be the longest match
+    for pair in names.windows(2) {
+        assert!(
+            pair[0].pattern.len() >= pair[1].pattern.len(),
+            "{:?} must come after {:?}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn imprecise_longest_match() {
+    use spdx::imprecise_license_id;
+
+    // Both "gpl" and "gpl-3.0" match, but the longer one must win
+    assert_eq!(
+        imprecise_license_id("GPL-3.0 or whatever").unwrap(),
+        (license_id("GPL-3.0").unwrap(), 7)
+    );
+    assert_eq!(
+        imprecise_license_id("apache 2.0").unwrap(),
+        (license_id("Apache-2.0").unwrap(), 10)
+    );
+    assert_eq!(
+        imprecise_license_id("apache").unwrap(),
+        (license_id("Apache-2.0").unwrap(), 6)
+    );
+}
+
+#[test]
+fn imprecise_exact() {
+    use spdx::{imprecise_license_id, MatchKind};
+
+    let bsd = spdx::imprecise_names()
+        .find(|name| name.pattern == "bsd")
+        .unwrap();
+    assert_eq!(bsd.kind, MatchKind::Exact);
+
+    for ok in ["bsd", "BSD", "bsd OR mit", "bsd/mit", "bsd)", "bsd+"] {
+        assert_eq!(
+            imprecise_license_id(ok).unwrap(),
+            (license_id("BSD-2-Clause").unwrap(), 3),
+            "{ok}"
+        );
+    }
+
+    // Exact names must not match as a prefix of a longer word
+    for nope in ["bsdl", "bsd-like", "bsd.2", "gplfoo", "lgpl2"] {
+        assert!(imprecise_license_id(nope).is_none(), "{nope}");
+    }
+
+    // But prefix names still do
+    assert_eq!(
+        imprecise_license_id("mitx").unwrap(),
+        (license_id("MIT").unwrap(), 3)
+    );
+}
+
+#[test]
+fn imprecise_exact_entries() {
+    use spdx::{error::Reason, imprecise_license_id, Expression, MatchKind, ParseMode};
+
+    // These names used to match as a prefix of anything, so eg. `mpl-1.1` was
+    // `MPL-2.0`, and `apache license` was only ever `apache`
+    let exact: Vec<_> = spdx::imprecise_names()
+        .filter(|name| name.kind == MatchKind::Exact)
+        .map(|name| name.pattern)
+        .collect();
+    assert_eq!(
+        exact,
+        ["apache license", "agpl", "lgpl", "bsd", "gpl", "mpl"]
+    );
+
+    for nope in ["agpl-1.0", "lgpl-2.1", "bsd-3", "gpl-2", "mpl-1.1"] {
+        assert!(imprecise_license_id(nope).is_none(), "{nope}");
+    }
+
+    let apache = license_id("Apache-2.0").unwrap();
+    assert_eq!(
+        imprecise_license_id("apache license").unwrap(),
+        (apache, 14)
+    );
+    assert_eq!(
+        imprecise_license_id("apache licensed").unwrap(),
+        (apache, 6)
+    );
+
+    // `apache license` now consumes the `license` that used to be the error
+    let err = Expression::parse_mode("Apache License 2.0", ParseMode::LAX).unwrap_err();
+    assert_eq!((err.span, err.reason), (15..18, Reason::UnknownTerm));
+}
+
+#[test]
+fn imprecise_whole_input() {
+    use spdx::{imprecise_license_id_exact, Expression, ParseMode};
+
+    let prose = "apache software foundation contributor agreement";
+    let apache = license_id("Apache-2.0").unwrap();
+
+    // The prefix match happily takes `apache` from the prose
+    assert_eq!(spdx::imprecise_license_id(prose).unwrap(), (apache, 6));
+    assert!(imprecise_license_id_exact(prose).is_none());
+    Expression::parse_mode(prose, ParseMode::LAX).unwrap_err();
+
+    for ok in [
+        "apache license",
+        "Apache License",
+        "  apache license\n",
+        "apache",
+    ] {
+        assert_eq!(imprecise_license_id_exact(ok), Some(apache), "{ok}");
+    }
+    assert_eq!(
+        Expression::canonicalize("apache license").unwrap().unwrap(),
+        "Apache-2.0"
+    );
+
+    for nope in ["", "apache2foo", "mitx", "apache license 2.0"] {
+        assert!(imprecise_license_id_exact(nope).is_none(), "{nope}");
+    }
+}
+
+#[test]
+fn imprecise_token_boundary() {
+    use spdx::{error::Reason, Expression, ParseMode};
+
+    // LAX requires imprecise names to end at a token boundary
+    for text in ["apachesoftware", "mitx", "apache2foo", "gpl-3.0foo"] {
+        let err = Expression::parse_mode(text, ParseMode::LAX).unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (0..text.len(), Reason::UnknownLicense),
+            "{text}"
+        );
+    }
+
+    for (text, expected) in [
+        ("apache2/mit", "Apache-2.0 OR MIT"),
+        ("(mit)", "MIT"),
+        ("gpl-3.0+", "GPL-3.0-or-later"),
+        ("mit and zlib", "MIT AND Zlib"),
+    ] {
+        let expr = Expression::parse_mode(text, ParseMode::LAX).unwrap();
+        assert_eq!(format!("{expr:#}"), expected, "{text}");
+    }
+
+    // But matching a prefix of a word can still be opted into
+    let prefix = ParseMode {
+        allow_imprecise_name_prefix: true,
+        ..ParseMode::LAX
+    };
+    let err = Expression::parse_mode("apachesoftware", prefix).unwrap_err();
+    assert_eq!((err.span, err.reason), (6..14, Reason::UnknownTerm));
+}
+
+#[test]
+fn list_version() {
+    use spdx::ListVersion;
+
+    let current = spdx::license_list_version();
+    assert_eq!(
+        current,
+        spdx::license_version().parse::<ListVersion>().unwrap()
+    );
+    assert!(current.major >= 3);
+
+    let v = |s: &str| s.parse::<ListVersion>().unwrap();
+
+    assert_eq!(
+        v("3.6"),
+        ListVersion {
+            major: 3,
+            minor: 6,
+            patch: 0
+        }
+    );
+    assert_eq!(v("3.6"), v("3.6.0"));
+    assert!(v("3.6") < v("3.19"));
+    assert!(v("3.19") < v("3.19.1"));
+    assert!(v("3.26.0") > v("3.9.9"));
+    assert!(v("4.0") > v("3.100"));
+    assert!(current >= v("3.19"));
+
+    for bad in [
+        "", "3", "3.", ".3", "3..1", "3.1.2.3", "3.x", "v3.1", "3.70000",
+    ] {
+        let err = bad.parse::<ListVersion>().unwrap_err();
+        assert_eq!(err.original, bad);
+    }
+}
+
+/// Hashes the license list the same way as `write_manifest` in
+/// `update/src/main.rs`
+fn content_hash(licenses: &[(&str, &str, u16)], exceptions: &[(&str, u16)]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut update = |bytes: &[u8]| {
+        for b in bytes {
+            hash ^= u64::from(*b);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    };
+
+    for (id, name, flags) in licenses {
+        update(id.as_bytes());
+        update(&[0]);
+        update(name.as_bytes());
+        update(&[0]);
+        update(&flags.to_le_bytes());
+    }
+
+    for (id, flags) in exceptions {
+        update(id.as_bytes());
+        update(&[0]);
+        update(&flags.to_le_bytes());
+    }
+
+    hash
+}
+
+/// Checks the generated data against the manifest written by the update tool
+#[test]
+fn generated_data_matches_manifest() {
+    use spdx::identifiers::{CONTENT_HASH, EXCEPTIONS, EXCEPTION_COUNT, LICENSES, LICENSE_COUNT};
+
+    assert_eq!(LICENSES.len(), LICENSE_COUNT);
+    assert_eq!(EXCEPTIONS.len(), EXCEPTION_COUNT);
+
+    let hash = content_hash(LICENSES, EXCEPTIONS);
+    assert_eq!(hash, CONTENT_HASH, "{hash:#018x}");
+
+    for pair in LICENSES.windows(2) {
+        assert!(pair[0].0 < pair[1].0, "{} >= {}", pair[0].0, pair[1].0);
+    }
+    for pair in EXCEPTIONS.windows(2) {
+        assert!(pair[0].0 < pair[1].0, "{} >= {}", pair[0].0, pair[1].0);
+    }
+    for (exc, _) in EXCEPTIONS {
+        assert!(license_id(exc).is_none(), "{exc}");
+    }
+}
+
+#[test]
+fn content_hash_changes_with_list() {
+    use spdx::identifiers::{CONTENT_HASH, EXCEPTIONS, LICENSES};
+
+    let mut licenses = LICENSES.to_vec();
+    let mut exceptions = EXCEPTIONS.to_vec();
+
+    // Removing a license shifts the index of every license after it
+    let removed = licenses.remove(licenses.len() / 2);
+    assert_ne!(content_hash(&licenses, EXCEPTIONS), CONTENT_HASH);
+    licenses.push(removed);
+    licenses.sort_unstable();
+    assert_eq!(content_hash(&licenses, EXCEPTIONS), CONTENT_HASH);
+
+    licenses.push(("ZZZ-New-License", "A new license", 0));
+    assert_ne!(content_hash(&licenses, EXCEPTIONS), CONTENT_HASH);
+    licenses.pop();
+
+    // Changes to the metadata of an entry are also detected
+    licenses[0].2 ^= spdx::identifiers::IS_DEPRECATED;
+    assert_ne!(content_hash(&licenses, EXCEPTIONS), CONTENT_HASH);
+    licenses[0].2 ^= spdx::identifiers::IS_DEPRECATED;
+
+    exceptions[0].0 = "A-Renamed-exception";
+    assert_ne!(content_hash(&licenses, &exceptions), CONTENT_HASH);
+}
+
+#[test]
+fn text_info() {
+    use spdx::identifiers::{LICENSES, LICENSE_TEXT_INFO};
+
+    assert_eq!(LICENSE_TEXT_INFO.len(), LICENSES.len());
+
+    for (name, len, hash) in [
+        ("0BSD", 643, 0x8c42_bf46_98cb_f8e3),
+        ("Apache-2.0", 10280, 0xa017_3a70_41e5_8fd6),
+        ("GPL-2.0-only", 17337, 0x9751_1456_7222_e92d),
+        ("MIT", 1087, 0xd45f_308a_2255_eb62),
+    ] {
+        let id = license_id(name).unwrap();
+        assert!(id.has_text(), "{name}");
+        assert_eq!(id.text_len(), len, "{name}");
+        assert_eq!(id.text_hash(), hash, "{name}");
+    }
+
+    let noassertion = license_id("NOASSERTION").unwrap();
+    assert!(!noassertion.has_text());
+    assert_eq!(noassertion.text_len(), 0);
+    assert_eq!(noassertion.text_hash(), 0);
+
+    // Every other license has a text
+    assert_eq!(
+        LICENSES
+            .iter()
+            .filter(|(name, ..)| !license_id(name).unwrap().has_text())
+            .count(),
+        1
+    );
+
+    #[cfg(feature = "text")]
+    for (name, ..) in LICENSES {
+        let id = license_id(name).unwrap();
+        let text = id.text();
+
+        assert_eq!(id.text_len(), text.len(), "{name}");
+        if id.has_text() {
+            let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, b| {
+                (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+            });
+            assert_eq!(id.text_hash(), hash, "{name}");
+        }
+    }
+}
+
+#[test]
+fn ids_from_index() {
+    use spdx::{
+        identifiers::{EXCEPTIONS, EXCEPTION_COUNT, LICENSES, LICENSE_COUNT},
+        ExceptionId, LicenseId,
+    };
+
+    for (index, (name, ..)) in LICENSES.iter().enumerate() {
+        let id = LicenseId::from_index(index).unwrap();
+        assert_eq!(id.index(), index);
+        assert_eq!(id.name, *name);
+
+        if !name.ends_with('+') {
+            assert_eq!(license_id(name), Some(id));
+        }
+    }
+
+    for (index, (name, _)) in EXCEPTIONS.iter().enumerate() {
+        let id = ExceptionId::from_index(index).unwrap();
+        assert_eq!(id.index(), index);
+        assert_eq!(spdx::exception_id(name), Some(id));
+    }
+
+    assert!(LicenseId::from_index(LICENSE_COUNT).is_none());
+    assert!(LicenseId::from_index(usize::MAX).is_none());
+    assert!(ExceptionId::from_index(EXCEPTION_COUNT).is_none());
+    assert!(ExceptionId::from_index(usize::MAX).is_none());
+}
+
+#[test]
+fn ids_from_str() {
+    use spdx::{ExceptionId, LicenseId};
+    use std::convert::TryFrom;
+
+    for (name, _, _) in spdx::identifiers::LICENSES {
+        // The deprecated `GPL-2.0+` etc ids are parsed the same as `license_id`
+        // does, ie. as the license without the `+`
+        if name.ends_with('+') {
+            continue;
+        }
+
+        let id: LicenseId = name.parse().unwrap();
+        assert_eq!(id.to_string(), *name);
+        assert_eq!(LicenseId::try_from(*name).unwrap(), id);
+    }
+
+    for (name, _) in spdx::identifiers::EXCEPTIONS {
+        let id: ExceptionId = name.parse().unwrap();
+        assert_eq!(id.to_string(), *name);
+        assert_eq!(ExceptionId::try_from(*name).unwrap(), id);
+    }
+
+    assert_eq!(
+        format!(
+            "{} WITH {}",
+            license_id("Apache-2.0").unwrap(),
+            spdx::exception_id("LLVM-exception").unwrap()
+        ),
+        "Apache-2.0 WITH LLVM-exception"
+    );
+
+    let expr = spdx::Expression::try_from("MIT OR Apache-2.0").unwrap();
+    assert_eq!(expr.requirement_count(), 2);
+    let licensee = spdx::Licensee::try_from("MIT").unwrap();
+    assert_eq!(licensee.to_string(), "MIT");
+    assert!(spdx::Licensee::try_from("MIT OR ISC").is_err());
+}
+
+#[test]
+fn unknown_id_suggestions() {
+    use spdx::{error::UnknownIdError, ExceptionId, LicenseId};
+
+    let err = |s: &str| s.parse::<LicenseId>().unwrap_err();
+
+    // Wrong case
+    assert_eq!(
+        err("mit"),
+        UnknownIdError {
+            input: "mit".to_owned(),
+            suggestions: vec!["MIT"],
+        }
+    );
+    assert_eq!(err("apache-2.0").suggestions[0], "Apache-2.0");
+    // Typos
+    assert_eq!(err("BSD-3-Clasue").suggestions[0], "BSD-3-Clause");
+    assert!(err("GPL-3.0-or-latr")
+        .suggestions
+        .contains(&"GPL-3.0-or-later"));
+    // Suggestions are limited, and the closest comes first
+    let gpl = err("GPL-2.1");
+    assert!(gpl.suggestions.len() <= 3);
+    assert_eq!(gpl.suggestions[0], "GPL-2.0");
+    // Nothing similar
+    assert!(err("this is not a license").suggestions.is_empty());
+    assert!(err("").suggestions.is_empty());
+
+    assert_eq!(
+        "llvm-exception"
+            .parse::<ExceptionId>()
+            .unwrap_err()
+            .suggestions,
+        ["LLVM-exception"]
+    );
+
+    assert_eq!(
+        err("mti").to_string(),
+        "unknown SPDX identifier 'mti', did you mean 'MIT'?"
+    );
+    assert_eq!(
+        UnknownIdError {
+            input: "nope".to_owned(),
+            suggestions: vec!["A", "B", "C"],
+        }
+        .to_string(),
+        "unknown SPDX identifier 'nope', did you mean 'A', 'B', or 'C'?"
+    );
+    assert_eq!(
+        err("this is not a license").to_string(),
+        "unknown SPDX identifier 'this is not a license'"
+    );
+}
+
+#[test]
+fn added_in() {
+    use spdx::{
+        identifiers::{ADDED_IN, EARLIEST_ADDED_IN, VERSION},
+        ListVersion,
+    };
+
+    let version = |v: &str| v.parse::<ListVersion>().unwrap();
+    let earliest = version(EARLIEST_ADDED_IN);
+    assert!(earliest <= version(VERSION));
+
+    for pair in ADDED_IN.windows(2) {
+        assert!(pair[0].0 < pair[1].0, "{} >= {}", pair[0].0, pair[1].0);
+    }
+    for (id, added) in ADDED_IN {
+        assert!(
+            license_id(id).is_some() || spdx::exception_id(id).is_some(),
+            "{id}"
+        );
+        assert!(version(added) > earliest, "{id} {added}");
+        assert!(version(added) <= version(VERSION), "{id} {added}");
+    }
+
+    // MIT has been in the list since the beginning, and Elastic-2.0 was
+    // added in 3.12
+    assert_eq!(license_id("MIT").unwrap().added_in(), EARLIEST_ADDED_IN);
+    assert!(version(license_id("Elastic-2.0").unwrap().added_in()) >= version("3.12"));
+    assert!(version(spdx::exception_id("LLVM-exception").unwrap().added_in()) >= earliest);
+}
@@ -27,6 +27,7 @@ macro_rules! err {
 
         let expected = ParseError {
             original: $text.to_owned(),
+            mode: spdx::ParseMode::STRICT,
             span: $range,
             reason: spdx::error::Reason::$reason,
         };
@@ -39,6 +40,7 @@ macro_rules! err {
 
         let expected = ParseError {
             original: $text.to_owned(),
+            mode: spdx::ParseMode::STRICT,
             span: $range,
             reason: spdx::error::Reason::Unexpected($unexpected),
         };
@@ -84,23 +86,125 @@ fn fails_bad_exception() {
     err!("Apache-2.0 WITH" => &["<exception>"]; 15..15);
 }
 
+#[test]
+fn unknown_license_or_exception() {
+    // Only a license is valid at the start, or after an operator or `(`
+    err!("MTI" => UnknownLicense @ 0..3);
+    err!("MIT OR Apahce-2.0" => UnknownLicense @ 7..17);
+    err!("MIT AND (Nope OR ISC)" => UnknownLicense @ 9..13);
+
+    // Only an exception is valid after `WITH`
+    err!("Apache-2.0 WITH LLVM-exeption" => UnknownException @ 16..29);
+    err!("(MIT OR Apache-2.0 WITH LLVM-exeption) AND ISC" => UnknownException @ 24..37);
+    err!("GPL-2.0-or-later WITH Classpath-exception-2" => UnknownException @ 22..43);
+
+    // An unknown term after a license could be a misspelled operator
+    err!("MIT Or Apache-2.0" => UnknownTerm @ 4..6);
+
+    // The suggestions come from the table that was expected
+    let err = spdx::Expression::parse("Apache-2.0 WITH llvm-exception").unwrap_err();
+    assert_eq!(err.suggestions(), ["LLVM-exception"]);
+    let err = spdx::Expression::parse("apache-2.0 WITH LLVM-exception").unwrap_err();
+    assert_eq!(err.suggestions(), ["Apache-2.0"]);
+    let err = spdx::Expression::parse("MIT OR").unwrap_err();
+    assert!(err.suggestions().is_empty());
+
+    // Canonicalizing reports the same errors
+    for text in ["MIT OR nope", "Apache-2.0 with nope"] {
+        assert_eq!(
+            spdx::Expression::canonicalize(text).unwrap_err(),
+            spdx::Expression::parse_mode(text, spdx::ParseMode::LAX).unwrap_err()
+        );
+    }
+
+    // As do licensees
+    assert_eq!(
+        spdx::Licensee::parse("Apache-2.0 WITH LLVM-exeption")
+            .unwrap_err()
+            .reason,
+        spdx::error::Reason::UnknownException
+    );
+    assert_eq!(
+        spdx::Licensee::parse("Apahce-2.0").unwrap_err().reason,
+        spdx::error::Reason::UnknownLicense
+    );
+}
+
 #[test]
 fn fails_bad_plus() {
-    err!("LAL-1.2 +" => SeparatedPlus @ 7..8);
+    err!("LAL-1.2 +" => SeparatedPlus @ 8..9);
     err!("+LAL-1.2" => &["<license>", "("]; 0..1);
     err!("++LAL-1.2" => &["<license>", "("]; 0..1);
-    err!("LAL+-1.2" => UnknownTerm @ 0..3);
-    err!("LAL-+1.2" => UnknownTerm @ 0..4);
-    err!("LAL-1.+2" => UnknownTerm @ 0..6);
+    err!("LAL+-1.2" => UnknownLicense @ 0..3);
+    err!("LAL-+1.2" => UnknownLicense @ 0..4);
+    err!("LAL-1.+2" => UnknownLicense @ 0..6);
     err!("LAL-1.2++" => &["AND", "OR", "WITH", ")"]; 8..9);
     // + can only be applied to valid SDPX short identifiers, not license/doc refs
     err!("LicenseRef-Nope+" => &["AND", "OR", "WITH", ")"]; 15..16);
     err!("LAL-1.2 AND+" => &["<license>", "("]; 11..12);
-    err!("LAL-1.2 OR +" => SeparatedPlus @ 10..11);
+    err!("LAL-1.2 OR +" => SeparatedPlus @ 11..12);
+    err!("LAL-1.2 \t\n+" => SeparatedPlus @ 10..11);
     err!("LAL-1.2 WITH+ LLVM-exception" => &["<exception>"]; 12..13);
     err!("LAL-1.2 WITH LLVM-exception+" => &["AND", "OR", ")"]; 27..28);
 }
 
+#[test]
+fn fails_invalid_characters() {
+    // Only the run of invalid characters is spanned, not the rest of the input
+    err!("MIT & Apache-2.0 &&& ISC" => InvalidCharacters @ 4..5);
+    err!("MIT &&&/ ISC" => InvalidCharacters @ 4..8);
+    err!("MIT OR !!(ISC)" => InvalidCharacters @ 7..9);
+    err!("MIT ©® OR ISC" => InvalidCharacters @ 4..8);
+    err!("MIT OR ISC;" => InvalidCharacters @ 10..11);
+    err!("MIT/Apache-2.0" => InvalidCharacters @ 3..4);
+
+    let err = spdx::Expression::parse_mode("MIT &&&/ ISC", spdx::ParseMode::LAX).unwrap_err();
+    assert_eq!(err.span, 4..7);
+    assert_eq!(
+        err.to_string(),
+        "MIT &&&/ ISC\n    ^^^ invalid character(s)"
+    );
+
+    let err = spdx::Expression::parse("MIT  +").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "MIT  +\n     ^ `+` must not follow whitespace"
+    );
+}
+
+#[test]
+fn fails_malformed_refs() {
+    err!("DocumentRef-Foo:MIT" => MalformedDocumentRef @ 16..19);
+    err!("MIT OR DocumentRef-Foo:" => MalformedDocumentRef @ 22..23);
+    err!("DocumentRef-Foo" => MalformedDocumentRef @ 0..15);
+    err!("DocumentRef-a:LicenseRef-b:LicenseRef-c" => MalformedDocumentRef @ 26..39);
+    err!("DocumentRef-:LicenseRef-b" => EmptyRefId @ 0..12);
+    err!("DocumentRef-a:LicenseRef-" => EmptyRefId @ 14..25);
+    err!("MIT AND LicenseRef-" => EmptyRefId @ 8..19);
+    err!("LicenseRef-a:b" => InvalidCharacters @ 12..14);
+
+    // Refs must consume the entire token
+    err!("LicenseRef-foo$bar" => InvalidCharacters @ 14..18);
+    err!("LicenseRef-foo$bar OR MIT" => InvalidCharacters @ 14..18);
+    err!("(DocumentRef-a:LicenseRef-b#1)" => InvalidCharacters @ 27..29);
+    err!("LicenseRef-a:b OR MIT" => InvalidCharacters @ 12..14);
+
+    // Only document refs can contain a `:`
+    err!("MIT:" => InvalidCharacters @ 3..4);
+    err!("MIT:something" => InvalidCharacters @ 3..4);
+    err!("Apache-2.0 WITH LLVM-exception:x" => InvalidCharacters @ 30..31);
+
+    // Well formed refs are unaffected
+    test_validate!(ok [
+        "LicenseRef-a" => ["LicenseRef-a"],
+        "LicenseRef-a.b-C9 OR DocumentRef-x.Y-2:LicenseRef-Z" => [
+            "LicenseRef-a.b-C9",
+            "DocumentRef-x.Y-2:LicenseRef-Z",
+        ],
+        "(DocumentRef-d:LicenseRef-l)" => ["DocumentRef-d:LicenseRef-l"],
+    ]);
+}
+
 #[test]
 fn fails_bad_ops() {
     err!("MIT-advertising AND" => &["<license>", "("]; 19..19);
@@ -255,3 +359,1621 @@ fn canonicalization() {
         "Apache-2.0 WITH LLVM-exception OR MPL-2.0"
     );
 }
+
+#[test]
+fn parse_canonical() {
+    use spdx::{Expression, ParseMode};
+
+    for (input, expected) in [
+        ("Apache-2.0 OR MIT", "Apache-2.0 OR MIT"),
+        ("Apache-2.0/MIT", "Apache-2.0 OR MIT"),
+        ("MIT and GPL-3.0+", "MIT AND GPL-3.0-or-later"),
+        (
+            "simplified bsd license or gpl-2.0+",
+            "BSD-2-Clause OR GPL-2.0-or-later",
+        ),
+        (
+            "apache with LLVM-exception/mpl",
+            "Apache-2.0 WITH LLVM-exception OR MPL-2.0",
+        ),
+        (
+            "( MIT OR Apache-2.0 )AND ISC",
+            "(MIT OR Apache-2.0) AND ISC",
+        ),
+        ("(MIT)  OR\t( ISC )", "(MIT) OR (ISC)"),
+    ] {
+        let (expr, modified) = Expression::parse_canonical(input, ParseMode::LAX).unwrap();
+
+        assert_eq!(expr.to_string(), expected);
+        assert_eq!(modified, input != expected);
+        assert_eq!(expr, Expression::parse(expected).unwrap());
+        assert_eq!(
+            Expression::canonicalize(input).unwrap().as_deref(),
+            modified.then_some(expected)
+        );
+
+        // The requirement spans refer to the canonical string
+        for er in expr.requirements() {
+            let span = er.span.start as usize..er.span.end as usize;
+            assert_eq!(&expr.as_ref()[span], format!("{:#}", er.req));
+        }
+    }
+
+    // The deprecated id's successor includes the exception, which is part of
+    // the requirement's span
+    let (expr, _) =
+        Expression::parse_canonical("mit or GPL-2.0-with-classpath-exception+", ParseMode::LAX)
+            .unwrap();
+    let er = expr.requirements().next_back().unwrap();
+    assert_eq!(
+        &expr.as_ref()[er.span.start as usize..er.span.end as usize],
+        "GPL-2.0-or-later WITH Classpath-exception-2.0"
+    );
+
+    // Strict only normalizes whitespace
+    let (expr, modified) =
+        Expression::parse_canonical("( MIT OR Apache-2.0 )", ParseMode::STRICT).unwrap();
+    assert!(modified);
+    assert_eq!(expr.to_string(), "(MIT OR Apache-2.0)");
+
+    assert_eq!(
+        Expression::parse_canonical("MIT/Apache-2.0", ParseMode::STRICT)
+            .unwrap_err()
+            .reason,
+        spdx::error::Reason::InvalidCharacters
+    );
+
+    // Strict doesn't allow a `+` on GNU licenses, so it isn't rewritten
+    let err = Expression::parse_canonical("MIT OR GPL-2.0+", ParseMode::STRICT).unwrap_err();
+    assert_eq!(
+        (err.reason, err.span),
+        (spdx::error::Reason::GnuNoPlus, 14..15)
+    );
+
+    // Errors are reported against the original string, not the canonical one
+    let err = Expression::parse_canonical("(mit or  apache", ParseMode::LAX).unwrap_err();
+    assert_eq!(err.original, "(mit or  apache");
+    assert_eq!(err.reason, spdx::error::Reason::UnclosedParens);
+
+    let err = Expression::parse_canonical("mit/apache2 AND (ISC", ParseMode::LAX).unwrap_err();
+    assert_eq!(err.original, "mit/apache2 AND (ISC");
+    assert_eq!(
+        (err.reason, err.span),
+        (spdx::error::Reason::UnclosedParens, 16..17)
+    );
+}
+
+#[test]
+fn canonicalizes_deprecated() {
+    use spdx::Expression;
+
+    assert_eq!(
+        Expression::canonicalize("StandardML-NJ OR MIT")
+            .unwrap()
+            .unwrap(),
+        "SMLNJ OR MIT"
+    );
+    assert_eq!(
+        Expression::canonicalize("BSD-2-Clause-FreeBSD+ AND Nunit")
+            .unwrap()
+            .unwrap(),
+        "BSD-2-Clause+ AND zlib-acknowledgement"
+    );
+    assert_eq!(
+        Expression::canonicalize("wxWindows").unwrap().unwrap(),
+        "LGPL-2.0-or-later WITH WxWindows-exception-3.1"
+    );
+    assert_eq!(
+        Expression::canonicalize("MIT OR (eCos-2.0)")
+            .unwrap()
+            .unwrap(),
+        "MIT OR (GPL-2.0-or-later WITH eCos-exception-2.0)"
+    );
+
+    // The bare GNU licenses are replaced with their `-only` variant
+    assert_eq!(
+        Expression::canonicalize("GPL-2.0 and mit")
+            .unwrap()
+            .unwrap(),
+        "GPL-2.0-only AND MIT"
+    );
+    for bare in [
+        "AGPL-1.0", "AGPL-3.0", "GFDL-1.1", "GFDL-1.2", "GFDL-1.3", "GPL-1.0", "GPL-2.0",
+        "GPL-3.0", "LGPL-2.0", "LGPL-2.1", "LGPL-3.0",
+    ] {
+        let (succ, exc) = spdx::license_id(bare).unwrap().successor().unwrap();
+        assert_eq!((succ.name, exc), (format!("{bare}-only").as_str(), None));
+        assert_eq!(
+            Expression::canonicalize(bare).unwrap().unwrap(),
+            format!("{bare}-only")
+        );
+    }
+
+    // Successors with an exception can't be used if the deprecated license
+    // already has an exception or a `+`
+    assert!(Expression::canonicalize("wxWindows WITH LLVM-exception")
+        .unwrap()
+        .is_none());
+    assert!(Expression::canonicalize("eCos-2.0+").unwrap().is_none());
+
+    // Deprecated licenses without a successor are left as is
+    assert!(Expression::canonicalize("Net-SNMP OR MIT")
+        .unwrap()
+        .is_none());
+
+    for (dep, succ, exc) in spdx::identifiers::DEPRECATED_SUCCESSORS {
+        let can = Expression::canonicalize(dep).unwrap().unwrap();
+        let expected = exc.map_or_else(|| (*succ).to_owned(), |exc| format!("{succ} WITH {exc}"));
+        assert_eq!(can, expected);
+        Expression::parse(&can).unwrap();
+    }
+}
+
+#[test]
+fn canonicalizes_gnu_or_later() {
+    use spdx::Expression;
+
+    for (input, expected) in [
+        ("GPL-2.0+", "GPL-2.0-or-later"),
+        ("GPL-2.0-or-later+", "GPL-2.0-or-later"),
+        ("GFDL-1.2+", "GFDL-1.2-or-later"),
+        ("GFDL-1.2-invariants+", "GFDL-1.2-invariants-or-later"),
+        (
+            "GFDL-1.3-no-invariants+ OR MIT",
+            "GFDL-1.3-no-invariants-or-later OR MIT",
+        ),
+        (
+            "GPL-2.0-with-classpath-exception",
+            "GPL-2.0-only WITH Classpath-exception-2.0",
+        ),
+        (
+            "GPL-2.0-with-classpath-exception+",
+            "GPL-2.0-or-later WITH Classpath-exception-2.0",
+        ),
+        (
+            "MIT AND (GPL-3.0-with-GCC-exception+ OR Apache-2.0)",
+            "MIT AND (GPL-3.0-or-later WITH GCC-exception-3.1 OR Apache-2.0)",
+        ),
+        (
+            "GPL-2.0-with-bison-exception+/MIT",
+            "GPL-2.0-or-later WITH Bison-exception-2.2 OR MIT",
+        ),
+    ] {
+        let can = Expression::canonicalize(input).unwrap().unwrap();
+        assert_eq!(can, expected, "{input}");
+        Expression::parse(&can).unwrap();
+    }
+
+    // A composite id can't have a second exception, so it is left as is
+    assert!(
+        Expression::canonicalize("GPL-2.0-with-font-exception WITH Classpath-exception-2.0")
+            .unwrap()
+            .is_none()
+    );
+
+    // The deprecated composite ids all have an -or-later variant
+    for (dep, ..) in spdx::identifiers::DEPRECATED_SUCCESSORS {
+        let id = spdx::license_id(dep).unwrap();
+        if !id.is_gnu() {
+            continue;
+        }
+
+        let can = Expression::canonicalize(&format!("{dep}+"))
+            .unwrap()
+            .unwrap();
+        let expr = Expression::parse(&can).unwrap();
+        assert!(expr.requirements().all(|er| er.req.or_later()), "{can}");
+    }
+}
+
+#[test]
+fn expands_composite_ids() {
+    use spdx::{Expression, Licensee, ParseMode};
+
+    let expand = ParseMode {
+        expand_composite_ids: true,
+        ..ParseMode::LAX
+    };
+
+    for (composite, license, exception) in [
+        (
+            "GPL-2.0-with-GCC-exception",
+            "GPL-2.0-only",
+            "GCC-exception-2.0",
+        ),
+        (
+            "GPL-2.0-with-autoconf-exception",
+            "GPL-2.0-only",
+            "Autoconf-exception-2.0",
+        ),
+        (
+            "GPL-2.0-with-bison-exception",
+            "GPL-2.0-only",
+            "Bison-exception-2.2",
+        ),
+        (
+            "GPL-2.0-with-classpath-exception",
+            "GPL-2.0-only",
+            "Classpath-exception-2.0",
+        ),
+        (
+            "GPL-2.0-with-font-exception",
+            "GPL-2.0-only",
+            "Font-exception-2.0",
+        ),
+        (
+            "GPL-3.0-with-GCC-exception",
+            "GPL-3.0-only",
+            "GCC-exception-3.1",
+        ),
+        (
+            "GPL-3.0-with-autoconf-exception",
+            "GPL-3.0-only",
+            "Autoconf-exception-3.0",
+        ),
+    ] {
+        let (id, exc) = spdx::license_id(composite).unwrap().decompose().unwrap();
+        assert_eq!((id.name, exc.name), (license, exception));
+
+        let expected = format!("{license} WITH {exception}");
+        assert_eq!(
+            Expression::canonicalize(composite).unwrap().unwrap(),
+            expected
+        );
+
+        // The composite is a single requirement unless it is expanded
+        let kept = Expression::parse_mode(composite, ParseMode::LAX).unwrap();
+        let req = &kept.requirements().next().unwrap().req;
+        assert!(req.exception.is_none());
+
+        let expanded = Expression::parse_mode(composite, expand).unwrap();
+        assert_eq!(expanded, Expression::parse(&expected).unwrap());
+
+        let er = expanded.requirements().next().unwrap();
+        assert_eq!(er.span, 0..composite.len() as u32);
+        assert!(!er.req.or_later());
+
+        // So that it satisfies policies that only list the modern form
+        let licensee = Licensee::parse(&format!(
+            "{} WITH {exception}",
+            license.trim_end_matches("-only")
+        ))
+        .unwrap();
+        assert!(expanded.evaluate(|req| licensee.satisfies(req)));
+        assert!(!kept.evaluate(|req| licensee.satisfies(req)));
+
+        let or_later = format!("({composite}+) OR MIT");
+        let expanded = Expression::parse_mode(&or_later, expand).unwrap();
+        assert_eq!(
+            format!("{expanded:#}"),
+            format!(
+                "{}-or-later WITH {exception} OR MIT",
+                license.trim_end_matches("-only")
+            )
+        );
+    }
+
+    // A composite that has its own exception can't also have the composite's
+    let expr = Expression::parse_mode(
+        "GPL-2.0-with-font-exception WITH Classpath-exception-2.0",
+        expand,
+    )
+    .unwrap();
+    let req = &expr.requirements().next().unwrap().req;
+    assert_eq!(
+        req.license.id().unwrap().name,
+        "GPL-2.0-with-font-exception"
+    );
+    assert_eq!(req.exception.unwrap().name, "Classpath-exception-2.0");
+
+    // Validating gives the same result as parsing
+    assert!(Expression::validate("MIT AND GPL-3.0-with-GCC-exception+", expand).is_ok());
+}
+
+#[test]
+fn canonicalize_rejects_misplaced_plus() {
+    use spdx::{error::Reason, Expression};
+
+    for (input, expected, span) in [
+        (
+            "LicenseRef-Foo+ OR MIT",
+            &["AND", "OR", "WITH", ")"][..],
+            14..15,
+        ),
+        (
+            "Apache-2.0 WITH LLVM-exception+",
+            &["AND", "OR", ")"][..],
+            30..31,
+        ),
+        ("(GPL-2.0)+", &["AND", "OR"][..], 9..10),
+        (
+            "gpl-2.0 with Classpath-exception-2.0+",
+            &["AND", "OR", ")"][..],
+            36..37,
+        ),
+    ] {
+        let err = Expression::canonicalize(input).unwrap_err();
+        assert_eq!(err.reason, Reason::Unexpected(expected), "{input}");
+        assert_eq!(err.span, span, "{input}");
+
+        // The parser gives the same error for the same input
+        assert_eq!(
+            Expression::parse_mode(input, spdx::ParseMode::LAX).unwrap_err(),
+            err
+        );
+    }
+
+    // The `+` still applies to the license id it follows
+    assert_eq!(
+        Expression::canonicalize("gpl-2.0+ with Classpath-exception-2.0")
+            .unwrap()
+            .unwrap(),
+        "GPL-2.0-or-later WITH Classpath-exception-2.0"
+    );
+}
+
+#[test]
+fn canonicalize_batch() {
+    use spdx::{error::Reason, expression::CanonicalizeResult, Expression};
+
+    let inputs = [
+        "MIT OR Apache-2.0",
+        "mit/apache2",
+        "",
+        "MIT OR Nope",
+        "GPL-2.0+ WITH Classpath-exception-2.0",
+        "Apache-2.0 WITH LLVM-exception",
+        "(MIT)",
+    ];
+    let results = Expression::canonicalize_batch(inputs.iter().copied());
+
+    assert_eq!(results.len(), inputs.len());
+    assert!(results.iter().enumerate().all(|(i, res)| res.index() == i));
+
+    let fixed = |index, canonical: &str| CanonicalizeResult::Fixed {
+        index,
+        canonical: canonical.to_owned(),
+    };
+
+    assert_eq!(results[0], CanonicalizeResult::Canonical { index: 0 });
+    assert_eq!(results[1], fixed(1, "MIT OR Apache-2.0"));
+    assert!(matches!(
+        &results[2],
+        CanonicalizeResult::Error { index: 2, error } if error.reason == Reason::Empty
+    ));
+    assert!(matches!(
+        &results[3],
+        CanonicalizeResult::Error { index: 3, error } if error.span == (7..11)
+    ));
+    assert_eq!(
+        results[4],
+        fixed(4, "GPL-2.0-or-later WITH Classpath-exception-2.0")
+    );
+    assert_eq!(results[5], CanonicalizeResult::Canonical { index: 5 });
+    assert_eq!(results[6], CanonicalizeResult::Canonical { index: 6 });
+
+    // Each result is the same as canonicalizing the input by itself, with
+    // the errors of parsing it
+    for (input, result) in inputs.iter().zip(results) {
+        let can = Expression::parse_mode(input, spdx::ParseMode::LAX)
+            .and_then(|_| Expression::canonicalize(input));
+
+        let expected = match can {
+            Ok(None) => CanonicalizeResult::Canonical {
+                index: result.index(),
+            },
+            Ok(Some(canonical)) => fixed(result.index(), &canonical),
+            Err(error) => CanonicalizeResult::Error {
+                index: result.index(),
+                error,
+            },
+        };
+        assert_eq!(result, expected, "{input}");
+    }
+
+    assert!(Expression::canonicalize_batch([]).is_empty());
+}
+
+#[test]
+fn reusable_parser() {
+    use spdx::{expression::ExpressionParser, Expression, ParseMode};
+
+    let texts = [
+        "MIT",
+        "((((Apache-2.0 WITH LLVM-exception) OR (Apache-2.0)) AND (OpenSSL)) OR (MIT))",
+        "MIT OR",
+        "Apache-2.0 WITH LLVM-exception OR Apache-2.0 AND (OpenSSL OR MIT)",
+        "(Apache-2.0",
+        "mit/apache",
+        "BSD-3-Clause",
+    ];
+
+    let mut parser = ExpressionParser::new();
+
+    for mode in [ParseMode::STRICT, ParseMode::LAX] {
+        for text in texts {
+            match (
+                parser.parse_into(text, mode),
+                Expression::parse_mode(text, mode),
+            ) {
+                (Ok(a), Ok(b)) => {
+                    assert_eq!(a, b);
+                    assert_eq!(a.to_string(), b.to_string());
+                    assert_eq!(format!("{a:?}"), format!("{b:?}"));
+                }
+                (Err(a), Err(b)) => assert_eq!(a, b),
+                (a, b) => panic!("{text}: {a:?} != {b:?}"),
+            }
+        }
+    }
+
+    let (exprs, errors) = parser.parse_many(texts, ParseMode::STRICT);
+    assert_eq!(
+        exprs.iter().map(|e| e.as_ref()).collect::<Vec<_>>(),
+        [texts[0], texts[1], texts[3], texts[6]]
+    );
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| e.original.as_str())
+            .collect::<Vec<_>>(),
+        [texts[2], texts[4], texts[5]]
+    );
+}
+
+fn reqs_with_synonyms(text: &str, mode: spdx::ParseMode, synonyms: &spdx::Synonyms) -> Vec<String> {
+    spdx::Expression::parse_with_synonyms(text, mode, synonyms)
+        .unwrap()
+        .requirements()
+        .map(|er| er.req.to_string())
+        .collect()
+}
+
+#[test]
+fn custom_synonym() {
+    let mut synonyms = spdx::Synonyms::new();
+    synonyms.add_license("mitish", spdx::license_id("MIT").unwrap());
+    synonyms.add_license_ref("acme", Some("Corp"), "Acme-1.0");
+
+    assert_eq!(
+        reqs_with_synonyms("MITish OR acme", spdx::ParseMode::STRICT, &synonyms),
+        ["MIT", "DocumentRef-Corp:LicenseRef-Acme-1.0"]
+    );
+
+    // Synonyms only match whole words
+    assert_eq!(
+        spdx::Expression::parse_with_synonyms("acme-2", spdx::ParseMode::STRICT, &synonyms)
+            .unwrap_err()
+            .reason,
+        spdx::error::Reason::UnknownLicense
+    );
+
+    // Without the synonyms the expression is invalid
+    assert!(spdx::Expression::parse("MITish OR acme").is_err());
+
+    // Only ASCII letters are matched case-insensitively
+    synonyms.add_license("Lizenz Über", spdx::license_id("MIT").unwrap());
+    for text in ["Lizenz Über", "LIZENZ Über", "lizenz Über"] {
+        assert_eq!(
+            reqs_with_synonyms(text, spdx::ParseMode::STRICT, &synonyms),
+            ["MIT"],
+            "{text}"
+        );
+    }
+    assert!(spdx::Expression::parse_with_synonyms(
+        "lizenz über",
+        spdx::ParseMode::STRICT,
+        &synonyms
+    )
+    .is_err());
+}
+
+#[test]
+fn multi_word_synonym() {
+    let mut synonyms = spdx::Synonyms::new();
+    synonyms.add_license_ref("Company Standard License", None, "Acme-1.0");
+    synonyms.add_license_ref("Company Standard License 2", None, "Acme-2.0");
+    synonyms
+        .add_expression("apache/MIT dual", "Apache-2.0 OR MIT")
+        .unwrap();
+
+    let text = "company standard license AND COMPANY STANDARD LICENSE 2 AND apache/mit dual";
+    let expr =
+        spdx::Expression::parse_with_synonyms(text, spdx::ParseMode::LAX, &synonyms).unwrap();
+
+    let reqs: Vec<_> = expr
+        .requirements()
+        .map(|er| (er.req.to_string(), er.span.clone()))
+        .collect();
+    assert_eq!(
+        reqs,
+        [
+            ("LicenseRef-Acme-1.0".to_owned(), 0..24),
+            ("LicenseRef-Acme-2.0".to_owned(), 29..55),
+            ("Apache-2.0".to_owned(), 60..75),
+            ("MIT".to_owned(), 60..75),
+        ]
+    );
+
+    // The whitespace in the synonym must match exactly, so the first one is unknown
+    assert!(spdx::Expression::parse_with_synonyms(
+        "company  standard license",
+        spdx::ParseMode::LAX,
+        &synonyms
+    )
+    .is_err());
+
+    // The expression is grouped, so AND binds to the whole thing
+    let expr = spdx::Expression::parse_with_synonyms(
+        "apache/mit dual AND Zlib",
+        spdx::ParseMode::STRICT,
+        &synonyms,
+    )
+    .unwrap();
+    assert!(expr.evaluate(|req| req.license.id() != spdx::license_id("Apache-2.0")));
+    assert!(!expr.evaluate(|req| req.license.id() != spdx::license_id("Zlib")));
+
+    assert_eq!(
+        spdx::Synonyms::new()
+            .add_expression("nope", "MIT OR")
+            .unwrap_err()
+            .original,
+        "MIT OR"
+    );
+}
+
+#[test]
+fn synonym_precedence() {
+    let mut synonyms = spdx::Synonyms::new();
+    synonyms.add_license_ref("MIT", None, "Acme-MIT");
+    synonyms.add_license_ref("apache", None, "Acme-Apache");
+
+    // Actual identifiers win by default
+    assert_eq!(
+        reqs_with_synonyms("MIT", spdx::ParseMode::STRICT, &synonyms),
+        ["MIT"]
+    );
+    // As do the built-in imprecise names when they are the same length
+    assert_eq!(
+        reqs_with_synonyms("apache", spdx::ParseMode::LAX, &synonyms),
+        ["Apache-2.0"]
+    );
+    // But the imprecise names aren't used in strict mode
+    assert_eq!(
+        reqs_with_synonyms("apache", spdx::ParseMode::STRICT, &synonyms),
+        ["LicenseRef-Acme-Apache"]
+    );
+
+    synonyms.prefer_synonyms = true;
+    assert_eq!(
+        reqs_with_synonyms("MIT OR apache", spdx::ParseMode::LAX, &synonyms),
+        ["LicenseRef-Acme-MIT", "LicenseRef-Acme-Apache"]
+    );
+
+    // Adding the same name again replaces the previous synonym
+    synonyms.add_license("mit", spdx::license_id("0BSD").unwrap());
+    assert_eq!(synonyms.iter().count(), 2);
+    assert_eq!(
+        reqs_with_synonyms("MIT", spdx::ParseMode::STRICT, &synonyms),
+        ["0BSD"]
+    );
+}
+
+#[test]
+fn requirement_and_operator_counts() {
+    for (text, reqs) in [
+        ("MIT", 1),
+        ("MIT OR Apache-2.0", 2),
+        ("(MIT AND ISC) OR (Apache-2.0 AND Zlib) OR BSD-3-Clause", 5),
+        (
+            "GPL-2.0-or-later WITH Classpath-exception-2.0 AND LicenseRef-Embark",
+            2,
+        ),
+    ] {
+        let expr = spdx::Expression::parse(text).unwrap();
+
+        assert_eq!(expr.requirement_count(), reqs, "{text}");
+        assert_eq!(expr.operator_count(), reqs - 1, "{text}");
+        assert_eq!(
+            expr.iter().len(),
+            expr.requirement_count() + expr.operator_count()
+        );
+        assert_eq!((&expr).into_iter().count(), expr.iter().len());
+
+        // The size hint must be exact after each step, from either end
+        let mut iter = expr.requirements();
+        let mut remaining = reqs;
+        let mut forward = true;
+        while remaining > 0 {
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            assert_eq!(iter.len(), remaining);
+            if forward {
+                iter.next().unwrap();
+            } else {
+                iter.next_back().unwrap();
+            }
+            remaining -= 1;
+            forward = !forward;
+        }
+        assert_eq!(iter.len(), 0);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+
+        let forward: Vec<_> = expr.requirements().map(|er| er.req.to_string()).collect();
+        let mut backward: Vec<_> = expr
+            .requirements()
+            .rev()
+            .map(|er| er.req.to_string())
+            .collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+    }
+}
+
+#[test]
+fn refs() {
+    for (text, refs) in [
+        ("MIT", &[][..]),
+        ("MIT OR Apache-2.0 WITH LLVM-exception", &[]),
+        ("LicenseRef-A", &["LicenseRef-A"]),
+        (
+            "MIT AND (LicenseRef-A OR DocumentRef-Doc:LicenseRef-B) AND ISC",
+            &["LicenseRef-A", "DocumentRef-Doc:LicenseRef-B"],
+        ),
+        (
+            "DocumentRef-Doc:LicenseRef-A OR GPL-2.0-or-later",
+            &["DocumentRef-Doc:LicenseRef-A"],
+        ),
+    ] {
+        let expr = spdx::Expression::parse(text).unwrap();
+
+        assert_eq!(
+            expr.refs()
+                .map(|er| &text[er.span.start as usize..er.span.end as usize])
+                .collect::<Vec<_>>(),
+            refs,
+            "{text}"
+        );
+        assert_eq!(expr.has_refs(), !refs.is_empty(), "{text}");
+        assert_eq!(expr.is_pure_spdx(), refs.is_empty(), "{text}");
+    }
+}
+
+#[test]
+fn parse_lossy() {
+    use spdx::expression::ParseFidelity as PF;
+
+    for (text, expected, fidelity) in [
+        ("MIT OR Apache-2.0", "MIT OR Apache-2.0", PF::Strict),
+        ("MIT/Apache-2.0", "MIT OR Apache-2.0", PF::Lax),
+        ("MIT and Apache-2.0", "MIT AND Apache-2.0", PF::Lax),
+        ("GPL-2.0+", "GPL-2.0-or-later", PF::Lax),
+        ("apache2", "Apache-2.0", PF::LaxCanonicalized),
+        ("MIT/apache2", "MIT OR Apache-2.0", PF::LaxCanonicalized),
+    ] {
+        let (expr, fid) = spdx::Expression::parse_lossy(text).unwrap();
+        assert_eq!(fid, fidelity, "{text}");
+        assert_eq!(expr, spdx::Expression::parse(expected).unwrap(), "{text}");
+    }
+
+    spdx::Expression::parse_lossy("MIT OR").unwrap_err();
+}
+
+#[test]
+fn leniencies() {
+    use spdx::{Expression, Leniencies as L, ParseMode};
+
+    for (text, expected) in [
+        ("MIT OR Apache-2.0 WITH LLVM-exception", L::empty()),
+        ("MIT/Apache-2.0", L::SLASH_OPERATOR),
+        ("mit or apache2", L::LOWERCASE_OPERATOR | L::IMPRECISE_NAME),
+        (
+            "GPL-3.0+ WITH Classpath-exception-2.0",
+            L::GPL_PLUS | L::DEPRECATED_ID,
+        ),
+        (
+            "GPL-2.0-only and LGPL-2.1",
+            L::LOWERCASE_OPERATOR | L::DEPRECATED_ID,
+        ),
+        (
+            "(apache2 with LLVM-exception)/GPL-2.0+",
+            L::SLASH_OPERATOR
+                | L::LOWERCASE_OPERATOR
+                | L::IMPRECISE_NAME
+                | L::GPL_PLUS
+                | L::DEPRECATED_ID,
+        ),
+    ] {
+        let expr = Expression::parse_mode(text, ParseMode::LAX).unwrap();
+        assert_eq!(expr.leniencies(), expected, "{text}");
+
+        let (can, _) = Expression::parse_canonical(text, ParseMode::LAX).unwrap();
+        assert_eq!(can.leniencies(), expected, "{text}");
+    }
+
+    // Deprecated ids are still valid in strict mode
+    assert_eq!(
+        Expression::parse("GPL-2.0").unwrap().leniencies(),
+        L::DEPRECATED_ID
+    );
+    assert!(!L::DEPRECATED_ID.requires_lax());
+    assert_eq!(
+        format!("{:?}", L::SLASH_OPERATOR | L::GPL_PLUS),
+        "Leniencies(SLASH_OPERATOR | GPL_PLUS)"
+    );
+}
+
+#[test]
+fn walk() {
+    use spdx::expression::{Operator, WalkEvent};
+
+    let events = |text: &str| {
+        let expr = spdx::Expression::parse(text).unwrap();
+        let mut events = Vec::new();
+        expr.walk(|event| {
+            events.push(match event {
+                WalkEvent::EnterGroup(Operator::And) => "AND(".to_owned(),
+                WalkEvent::EnterGroup(Operator::Or) => "OR(".to_owned(),
+                WalkEvent::LeaveGroup => ")".to_owned(),
+                WalkEvent::Requirement(er) => er.req.to_string(),
+            });
+        });
+        events
+    };
+
+    assert_eq!(
+        events("((Apache-2.0 WITH LLVM-exception) OR Apache-2.0) AND OpenSSL OR MIT"),
+        [
+            "OR(",
+            "AND(",
+            "OR(",
+            "Apache-2.0 WITH LLVM-exception",
+            "Apache-2.0",
+            ")",
+            "OpenSSL",
+            ")",
+            "MIT",
+            ")",
+        ]
+    );
+
+    // Precedence, rather than parentheses, decides the groups, and terms joined
+    // by the same operator are flattened into a single group
+    assert_eq!(
+        events("MIT OR (ISC OR Zlib AND (0BSD)) OR Apache-2.0"),
+        [
+            "OR(",
+            "MIT",
+            "ISC",
+            "AND(",
+            "Zlib",
+            "0BSD",
+            ")",
+            "Apache-2.0",
+            ")"
+        ]
+    );
+
+    assert_eq!(events("MIT"), ["MIT"]);
+}
+
+#[test]
+fn stats() {
+    use spdx::expression::ExprStats;
+
+    // depth, and_count, or_count, req_count, with_count, ref_count
+    let table: &[(&str, [usize; 6])] = &[
+        ("MIT", [0, 0, 0, 1, 0, 0]),
+        ("LicenseRef-a", [0, 0, 0, 1, 0, 1]),
+        (
+            "LicenseRef-a.b-C9 OR DocumentRef-x.Y-2:LicenseRef-Z",
+            [1, 0, 1, 2, 0, 2],
+        ),
+        ("(DocumentRef-d:LicenseRef-l)", [0, 0, 0, 1, 0, 1]),
+        ("Apache-2.0 OR MIT", [1, 0, 1, 2, 0, 0]),
+        ("(Apache-2.0 OR MIT)", [1, 0, 1, 2, 0, 0]),
+        ("Apache-2.0 WITH LLVM-exception", [0, 0, 0, 1, 1, 0]),
+        (
+            "(Apache-2.0 WITH LLVM-exception OR Apache-2.0) AND MIT",
+            [2, 1, 1, 3, 1, 0],
+        ),
+        (
+            "(MIT AND (BitTorrent-1.1+ OR BSD-3-Clause))",
+            [2, 1, 1, 3, 0, 0],
+        ),
+        (
+            "((Apache-2.0 WITH LLVM-exception) OR Apache-2.0) AND OpenSSL OR MIT",
+            [3, 1, 2, 4, 1, 0],
+        ),
+        (
+            "Apache-2.0 WITH LLVM-exception OR Apache-2.0 AND (OpenSSL OR MIT)",
+            [3, 1, 2, 4, 1, 0],
+        ),
+        (
+            "Apache-2.0 WITH LLVM-exception OR (Apache-2.0 AND OpenSSL) OR MIT",
+            [2, 1, 2, 4, 1, 0],
+        ),
+        (
+            "((((Apache-2.0 WITH LLVM-exception) OR (Apache-2.0)) AND (OpenSSL)) OR (MIT))",
+            [3, 1, 2, 4, 1, 0],
+        ),
+        // Terms joined by the same operator are in a single group
+        (
+            "MIT OR (ISC OR Zlib AND (0BSD)) OR Apache-2.0",
+            [2, 1, 3, 5, 0, 0],
+        ),
+    ];
+
+    for (text, [depth, and_count, or_count, req_count, with_count, ref_count]) in table {
+        let expected = ExprStats {
+            depth: *depth,
+            and_count: *and_count,
+            or_count: *or_count,
+            req_count: *req_count,
+            with_count: *with_count,
+            ref_count: *ref_count,
+        };
+
+        similar_asserts::assert_eq!(
+            spdx::Expression::parse(text).unwrap().stats(),
+            expected,
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn would_lax_help() {
+    use spdx::{Expression, ParseMode};
+
+    let hint = |text: &str| Expression::parse(text).unwrap_err().would_lax_help();
+
+    assert_eq!(hint("MIT/Apache-2.0").unwrap(), "MIT OR Apache-2.0");
+    assert_eq!(hint("mit or apache2").unwrap(), "MIT OR Apache-2.0");
+    assert_eq!(
+        hint("Apache-2.0 with LLVM-exception").unwrap(),
+        "Apache-2.0 WITH LLVM-exception"
+    );
+    // The deprecated GPL-2.0 with a `+`
+    assert_eq!(hint("GPL-2.0+ OR MIT").unwrap(), "GPL-2.0-or-later OR MIT");
+
+    // Errors that lax parsing doesn't fix
+    assert_eq!(hint("MIT OR"), None);
+    assert_eq!(hint("MIT/Apache-2.0 AND (ISC"), None);
+    assert_eq!(hint("Definitely not a license"), None);
+    assert_eq!(hint("GPL-2.0-only+"), None);
+
+    // Retrying can't help if the expression was already parsed leniently
+    let err = Expression::parse_mode("MIT/Nope", ParseMode::LAX).unwrap_err();
+    assert_eq!(err.mode, ParseMode::LAX);
+    assert_eq!(err.would_lax_help(), None);
+
+    // The error is never fixed with the mode that was used, even if the
+    // original is changed to something that lax parsing could fix
+    let mut err = Expression::parse_mode("MIT/Nope", ParseMode::LAX).unwrap_err();
+    err.original = "MIT/Apache-2.0".to_owned();
+    assert_eq!(err.would_lax_help(), None);
+
+    // Any mode that is missing some of the lax leniencies is retried
+    let mode = ParseMode {
+        allow_slash_as_or_operator: false,
+        ..ParseMode::LAX
+    };
+    let err = Expression::parse_mode("mit/apache2", mode).unwrap_err();
+    assert_eq!(err.mode, mode);
+    assert_eq!(err.would_lax_help().unwrap(), "MIT OR Apache-2.0");
+}
+
+#[test]
+fn surrounding_quotes() {
+    use spdx::{error::Reason, Expression, Leniencies, ParseMode};
+
+    let spans = |expr: &Expression| {
+        expr.requirements()
+            .map(|er| er.span.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let expr = Expression::parse_mode("\u{feff}MIT", ParseMode::LAX).unwrap();
+    assert_eq!(spans(&expr), vec![3..6]);
+    assert_eq!(expr.leniencies(), Leniencies::SURROUNDING_QUOTES);
+
+    let expr = Expression::parse_mode("\"MIT OR Apache-2.0\"", ParseMode::LAX).unwrap();
+    assert_eq!(spans(&expr), [1..4, 8..18]);
+    assert_eq!(expr, Expression::parse("MIT OR Apache-2.0").unwrap());
+
+    for text in ["`MIT`", "'MIT'", " \"MIT\"\n", "\u{feff}\"MIT\""] {
+        assert_eq!(
+            Expression::parse_mode(text, ParseMode::LAX).unwrap(),
+            Expression::parse("MIT").unwrap(),
+            "{text}"
+        );
+    }
+
+    assert_eq!(
+        Expression::canonicalize("\u{feff}\"mit/apache2\"")
+            .unwrap()
+            .unwrap(),
+        "MIT OR Apache-2.0"
+    );
+
+    // Errors still point at the original input
+    let err = Expression::parse_mode("\"MIT OR Nope\"", ParseMode::LAX).unwrap_err();
+    assert_eq!((err.span, err.reason), (8..12, Reason::UnknownLicense));
+
+    // Only a single pair of matching quotes is removed
+    for (text, span) in [("\"MIT", 0..1), ("\"MIT'", 0..1), ("\"\"MIT\"\"", 1..2)] {
+        let err = Expression::parse_mode(text, ParseMode::LAX).unwrap_err();
+        assert_eq!(
+            (err.span, err.reason),
+            (span, Reason::InvalidCharacters),
+            "{text}"
+        );
+    }
+
+    // STRICT doesn't allow either
+    for text in ["\u{feff}MIT", "\"MIT\""] {
+        let err = Expression::parse(text).unwrap_err();
+        assert_eq!(err.reason, Reason::InvalidCharacters, "{text}");
+    }
+}
+
+#[test]
+fn gnu_plus_suffixes() {
+    use spdx::{error::Reason, Expression, ParseMode};
+
+    let plus_allowed = ParseMode {
+        allow_postfix_plus_on_gpl: true,
+        ..ParseMode::STRICT
+    };
+
+    for mode in [ParseMode::STRICT, plus_allowed, ParseMode::LAX] {
+        let allowed = mode.allow_postfix_plus_on_gpl;
+
+        // No suffix
+        let res = Expression::parse_mode("GPL-3.0+", mode);
+        if allowed {
+            assert_eq!(res.unwrap(), Expression::parse("GPL-3.0-or-later").unwrap());
+        } else {
+            assert_eq!(res.unwrap_err().reason, Reason::GnuNoPlus);
+        }
+
+        // `-or-later` is redundant
+        let res = Expression::parse_mode("GPL-3.0-or-later+ OR MIT", mode);
+        if allowed {
+            assert_eq!(
+                res.unwrap(),
+                Expression::parse("GPL-3.0-or-later OR MIT").unwrap()
+            );
+        } else {
+            assert_eq!(res.unwrap_err().reason, Reason::GnuNoPlus);
+        }
+
+        // `-only` contradicts itself
+        let err = Expression::parse_mode("MIT AND GPL-2.0-only+", mode).unwrap_err();
+        assert_eq!(
+            (err.reason, err.span),
+            (
+                if allowed {
+                    Reason::GnuPlusWithSuffix
+                } else {
+                    Reason::GnuNoPlus
+                },
+                20..21
+            )
+        );
+    }
+
+    assert_eq!(
+        Expression::canonicalize("LGPL-2.1+ OR GPL-3.0-or-later+")
+            .unwrap()
+            .unwrap(),
+        "LGPL-2.1-or-later OR GPL-3.0-or-later"
+    );
+    let err = Expression::canonicalize("MIT AND GPL-2.0-only+").unwrap_err();
+    assert_eq!((err.reason, err.span), (Reason::GnuPlusWithSuffix, 20..21));
+    let err = Expression::parse_canonical("GPL-2.0-only+", ParseMode::LAX).unwrap_err();
+    assert_eq!(err.reason, Reason::GnuPlusWithSuffix);
+}
+
+#[test]
+fn canonical_display() {
+    use spdx::{Expression, ParseMode};
+
+    for (text, canonical) in [
+        ("MIT", "MIT"),
+        ("mit/apache2", "MIT OR Apache-2.0"),
+        ("(MIT)", "MIT"),
+        ("MIT OR (Apache-2.0 AND ISC)", "MIT OR Apache-2.0 AND ISC"),
+        ("(MIT OR Apache-2.0) AND ISC", "(MIT OR Apache-2.0) AND ISC"),
+        ("MIT AND (Apache-2.0 OR ISC)", "MIT AND (Apache-2.0 OR ISC)"),
+        ("MIT OR (Apache-2.0 OR ISC)", "MIT OR Apache-2.0 OR ISC"),
+        // The left hand side needs to stay parenthesized to parse the same
+        ("(MIT OR Apache-2.0) OR ISC", "(MIT OR Apache-2.0) OR ISC"),
+        (
+            "((Apache-2.0 WITH LLVM-exception) OR Apache-2.0) AND OpenSSL OR MIT",
+            "(Apache-2.0 WITH LLVM-exception OR Apache-2.0) AND OpenSSL OR MIT",
+        ),
+        (
+            "gpl-2.0+ with Classpath-exception-2.0 OR LGPL-2.1-only OR GPL-3.0-or-later",
+            "GPL-2.0-or-later WITH Classpath-exception-2.0 OR LGPL-2.1-only OR GPL-3.0-or-later",
+        ),
+        ("GPL-2.0-only AND MIT", "GPL-2.0-only AND MIT"),
+        (
+            "GPL-2.0 AND GFDL-1.3-invariants",
+            "GPL-2.0-only AND GFDL-1.3-invariants-only",
+        ),
+        (
+            "GPL-2.0-with-classpath-exception OR MIT",
+            "GPL-2.0-with-classpath-exception OR MIT",
+        ),
+        (
+            "GFDL-1.2-invariants-or-later AND Zlib+",
+            "GFDL-1.2-invariants-or-later AND Zlib+",
+        ),
+        (
+            "LicenseRef-A OR DocumentRef-B:LicenseRef-C",
+            "LicenseRef-A OR DocumentRef-B:LicenseRef-C",
+        ),
+    ] {
+        let expr = Expression::parse_mode(text, ParseMode::LAX).unwrap();
+        let rendered = format!("{expr:#}");
+        assert_eq!(rendered, canonical, "{text}");
+
+        let reparsed = Expression::parse(&rendered).unwrap();
+        assert_eq!(format!("{reparsed:?}"), format!("{expr:?}"), "{text}");
+        assert_eq!(reparsed, expr, "{text}");
+    }
+
+    // Composite GNU ids with a `+` have no `-or-later` variant, so they are
+    // expanded into the license `WITH` the exception, the same as canonicalize
+    let expand = ParseMode {
+        expand_composite_ids: true,
+        ..ParseMode::LAX
+    };
+    for (text, canonical) in [
+        (
+            "GPL-2.0-with-classpath-exception+",
+            "GPL-2.0-or-later WITH Classpath-exception-2.0",
+        ),
+        (
+            "MIT OR GPL-2.0-with-GCC-exception+",
+            "MIT OR GPL-2.0-or-later WITH GCC-exception-2.0",
+        ),
+        (
+            "GPL-2.0-with-autoconf-exception+ AND GPL-2.0-with-bison-exception+",
+            "GPL-2.0-or-later WITH Autoconf-exception-2.0 AND GPL-2.0-or-later WITH Bison-exception-2.2",
+        ),
+        (
+            "GPL-2.0-with-font-exception+ OR GPL-3.0-with-GCC-exception+",
+            "GPL-2.0-or-later WITH Font-exception-2.0 OR GPL-3.0-or-later WITH GCC-exception-3.1",
+        ),
+        (
+            "GPL-3.0-with-autoconf-exception+",
+            "GPL-3.0-or-later WITH Autoconf-exception-3.0",
+        ),
+    ] {
+        let expr = Expression::parse_mode(text, ParseMode::LAX).unwrap();
+        let rendered = format!("{expr:#}");
+        assert_eq!(rendered, canonical, "{text}");
+        assert_eq!(
+            Some(rendered.clone()),
+            Expression::canonicalize(text).unwrap(),
+            "{text}"
+        );
+
+        let reparsed = Expression::parse(&rendered).unwrap();
+        assert_eq!(
+            reparsed,
+            Expression::parse_mode(text, expand).unwrap(),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn license_req_builders() {
+    use spdx::{error::LicenseReqError as E, exception_id, license_id, LicenseReq};
+
+    let apache = LicenseReq::new(license_id("Apache-2.0").unwrap());
+    assert_eq!(apache.to_string(), "Apache-2.0");
+
+    let llvm = exception_id("LLVM-exception").unwrap();
+    let req = apache.clone().with_exception(llvm).unwrap();
+    assert_eq!(req.to_string(), "Apache-2.0 WITH LLVM-exception");
+    assert_eq!(
+        req.clone()
+            .with_exception(exception_id("Classpath-exception-2.0").unwrap())
+            .unwrap_err(),
+        E::ExceptionAlreadySet(llvm)
+    );
+
+    let req = req.with_or_later().unwrap();
+    assert_eq!(req.to_string(), "Apache-2.0+ WITH LLVM-exception");
+    assert_eq!(
+        req,
+        LicenseReq::parse("Apache-2.0+ WITH LLVM-exception", spdx::ParseMode::STRICT).unwrap()
+    );
+
+    // GNU licenses use the -or-later variant, if there is one
+    assert_eq!(
+        LicenseReq::new(license_id("GPL-2.0-only").unwrap())
+            .with_or_later()
+            .unwrap(),
+        LicenseReq::new(license_id("GPL-2.0-or-later").unwrap())
+    );
+    assert_eq!(
+        LicenseReq::new(license_id("GPL-2.0-with-GCC-exception").unwrap())
+            .with_or_later()
+            .unwrap_err(),
+        E::OrLaterNotAllowed
+    );
+
+    let lref = LicenseReq::license_ref(None, "Embark-1.0").unwrap();
+    assert_eq!(lref.to_string(), "LicenseRef-Embark-1.0");
+    assert_eq!(
+        lref.clone().with_or_later().unwrap_err(),
+        E::OrLaterNotAllowed
+    );
+    assert_eq!(
+        lref.with_exception(llvm).unwrap().to_string(),
+        "LicenseRef-Embark-1.0 WITH LLVM-exception"
+    );
+    assert_eq!(
+        LicenseReq::license_ref(Some("Doc.2"), "A")
+            .unwrap()
+            .to_string(),
+        "DocumentRef-Doc.2:LicenseRef-A"
+    );
+
+    assert_eq!(
+        LicenseReq::license_ref(None, "").unwrap_err(),
+        E::EmptyRefId
+    );
+    assert_eq!(
+        LicenseReq::license_ref(Some(""), "A").unwrap_err(),
+        E::EmptyRefId
+    );
+    assert_eq!(
+        LicenseReq::license_ref(None, "Embark 1.0").unwrap_err(),
+        E::InvalidRefCharacter(' ')
+    );
+    assert_eq!(
+        LicenseReq::license_ref(Some("Doc:"), "A").unwrap_err(),
+        E::InvalidRefCharacter(':')
+    );
+}
+
+#[test]
+fn license_ref_idstrings() {
+    use spdx::{
+        error::{LicenseReqError as E, NodesError},
+        expression::{ExprNode, ExpressionReq, Operator},
+        Expression, LicenseItem, LicenseReq,
+    };
+
+    for id in ["A", "Embark-1.0", "0", "a.b-c", "-", "...", "ALL-CAPS-99"] {
+        let item = LicenseItem::license_ref(None, id).unwrap();
+        assert_eq!(item.validate(), Ok(()));
+        assert_eq!(item.to_string(), format!("LicenseRef-{id}"));
+
+        // Every valid ref round trips through an expression
+        let text = format!("DocumentRef-{id}:LicenseRef-{id}");
+        let item = LicenseItem::license_ref(Some(id), id).unwrap();
+        assert_eq!(item.to_string(), text);
+        assert_eq!(
+            Expression::parse(&text)
+                .unwrap()
+                .requirements()
+                .next()
+                .unwrap()
+                .req
+                .license,
+            item
+        );
+    }
+
+    for (id, err) in [
+        ("", E::EmptyRefId),
+        ("has spaces!", E::InvalidRefCharacter(' ')),
+        ("Embark_1.0", E::InvalidRefCharacter('_')),
+        ("Doc:Ref", E::InvalidRefCharacter(':')),
+        ("LicenseRef+", E::InvalidRefCharacter('+')),
+        ("café", E::InvalidRefCharacter('é')),
+        ("\u{0661}", E::InvalidRefCharacter('\u{0661}')),
+        ("日本", E::InvalidRefCharacter('日')),
+    ] {
+        assert_eq!(
+            LicenseItem::license_ref(None, id).unwrap_err(),
+            err,
+            "{id:?}"
+        );
+        assert_eq!(
+            LicenseItem::license_ref(Some(id), "A").unwrap_err(),
+            err,
+            "{id:?}"
+        );
+        assert_eq!(
+            LicenseReq::license_ref(None, id).unwrap_err(),
+            err,
+            "{id:?}"
+        );
+
+        let item = LicenseItem::Other {
+            doc_ref: Some("Doc".to_owned()),
+            lic_ref: id.to_owned(),
+        };
+        assert_eq!(item.validate().unwrap_err(), err, "{id:?}");
+
+        // Building an expression from nodes also validates refs
+        let nodes = vec![
+            ExprNode::Req(ExpressionReq {
+                req: LicenseReq::from(spdx::license_id("MIT").unwrap()),
+                span: 0..0,
+            }),
+            ExprNode::Req(ExpressionReq {
+                req: LicenseReq {
+                    license: item,
+                    exception: None,
+                },
+                span: 0..0,
+            }),
+            ExprNode::Op(Operator::And),
+        ];
+        assert_eq!(
+            Expression::from_nodes(nodes).unwrap_err(),
+            NodesError::InvalidRef {
+                index: 1,
+                error: err
+            }
+        );
+    }
+}
+
+#[test]
+fn license_req_parse() {
+    use spdx::{error::Reason, LicenseReq, ParseMode};
+
+    for (text, mode, expected) in [
+        ("MIT", ParseMode::STRICT, "MIT"),
+        ("MIT+", ParseMode::STRICT, "MIT+"),
+        ("GPL-2.0-only", ParseMode::STRICT, "GPL-2.0"),
+        ("GPL-3.0-or-later", ParseMode::STRICT, "GPL-3.0-or-later"),
+        (
+            "Apache-2.0 WITH LLVM-exception",
+            ParseMode::STRICT,
+            "Apache-2.0 WITH LLVM-exception",
+        ),
+        (
+            "LicenseRef-A WITH LLVM-exception",
+            ParseMode::STRICT,
+            "LicenseRef-A WITH LLVM-exception",
+        ),
+        (
+            "LGPL-2.1+ with Classpath-exception-2.0",
+            ParseMode::LAX,
+            "LGPL-2.1-or-later WITH Classpath-exception-2.0",
+        ),
+        ("apache2", ParseMode::LAX, "Apache-2.0"),
+    ] {
+        assert_eq!(
+            LicenseReq::parse(text, mode).unwrap().to_string(),
+            expected,
+            "{text}"
+        );
+    }
+
+    for (text, mode, span, reason) in [
+        ("", ParseMode::STRICT, 0..0, Reason::Empty),
+        (
+            "MIT OR ISC",
+            ParseMode::STRICT,
+            4..6,
+            Reason::ExpressionNotAllowed,
+        ),
+        (
+            "MIT/ISC",
+            ParseMode::LAX,
+            3..4,
+            Reason::ExpressionNotAllowed,
+        ),
+        (
+            "(MIT)",
+            ParseMode::STRICT,
+            0..1,
+            Reason::Unexpected(&["<license>"]),
+        ),
+        (
+            "MIT ISC",
+            ParseMode::STRICT,
+            4..7,
+            Reason::Unexpected(&["+", "WITH"]),
+        ),
+        (
+            "LicenseRef-A+",
+            ParseMode::STRICT,
+            12..13,
+            Reason::Unexpected(&["WITH"]),
+        ),
+        (
+            "MIT WITH",
+            ParseMode::STRICT,
+            8..8,
+            Reason::Unexpected(&["<exception>"]),
+        ),
+        (
+            "MIT WITH ISC",
+            ParseMode::STRICT,
+            9..12,
+            Reason::Unexpected(&["<exception>"]),
+        ),
+        ("GPL-2.0+", ParseMode::STRICT, 7..8, Reason::GnuNoPlus),
+        (
+            "GPL-2.0-only+",
+            ParseMode::LAX,
+            12..13,
+            Reason::GnuPlusWithSuffix,
+        ),
+        (
+            "MIT WITH LLVM-exception MIT",
+            ParseMode::STRICT,
+            24..27,
+            Reason::Unexpected(&[]),
+        ),
+    ] {
+        let err = LicenseReq::parse(text, mode).unwrap_err();
+        assert_eq!((err.span, err.reason), (span, reason), "{text}");
+    }
+}
+
+#[test]
+fn single_id_fast_path() {
+    use spdx::{expression::ExpressionParser, Expression, ParseMode};
+
+    // `ExpressionParser` always takes the slow path, so every public behavior
+    // of the fast path must match it
+    let mut parser = ExpressionParser::new();
+
+    for (name, ..) in spdx::identifiers::LICENSES {
+        for mode in [ParseMode::STRICT, ParseMode::LAX] {
+            // Some of the deprecated ids, eg. `GPL-2.0+`, can't be parsed
+            let (fast, slow) = match (
+                Expression::parse_mode(name, mode),
+                parser.parse_into(name, mode),
+            ) {
+                (Ok(fast), Ok(slow)) => (fast, slow),
+                (fast, slow) => {
+                    assert_eq!(fast.unwrap_err(), slow.unwrap_err());
+                    continue;
+                }
+            };
+
+            assert_eq!(fast, slow, "{name}");
+            assert_eq!(fast.as_ref(), slow.as_ref());
+            assert_eq!(fast.to_string(), slow.to_string());
+            assert_eq!(format!("{fast:#}"), format!("{slow:#}"));
+            assert_eq!(format!("{fast:?}"), format!("{slow:?}"));
+            assert_eq!(fast.leniencies(), slow.leniencies(), "{name}");
+            assert!(fast
+                .requirements()
+                .map(|er| &er.span)
+                .eq(slow.requirements().map(|er| &er.span)));
+        }
+    }
+
+    // Anything other than exactly an id still goes through the parser
+    for text in ["MIT+", " MIT", "mit", "MIT WITH LLVM-exception"] {
+        assert_eq!(
+            Expression::parse(text).map_err(|err| err.reason),
+            parser
+                .parse_into(text, ParseMode::STRICT)
+                .map_err(|err| err.reason),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn equality_levels() {
+    use spdx::Expression;
+
+    let parse = |text| Expression::parse(text).unwrap();
+
+    let plain = parse("MIT OR Apache-2.0");
+    let parens = parse("(MIT OR Apache-2.0)");
+    let swapped = parse("Apache-2.0 OR MIT");
+
+    // Identical
+    assert!(plain.identical(&parse("MIT OR Apache-2.0")));
+    assert!(!plain.identical(&parens));
+    assert!(!plain.identical(&swapped));
+
+    // Node-equal, the spans and original differ
+    assert!(plain.semantically_eq(&parens));
+    assert_eq!(plain, parens);
+    assert!(!plain.semantically_eq(&swapped));
+    assert_ne!(plain, swapped);
+
+    // Equivalent
+    assert!(plain.equivalent(&parens));
+    assert!(plain.equivalent(&swapped));
+    assert!(!plain.equivalent(&parse("MIT AND Apache-2.0")));
+    assert!(!plain.equivalent(&parse("MIT OR Apache-2.0 WITH LLVM-exception")));
+    assert!(!plain.equivalent(&parse("MIT OR Apache-2.0 OR ISC")));
+
+    // Building the canonical expression from its nodes gives the same
+    // original string and spans
+    let rebuilt = Expression::from_nodes(plain.iter().cloned().collect()).unwrap();
+    assert!(plain.identical(&rebuilt));
+    assert!(!parens.identical(&rebuilt));
+
+    // Grouping and repetition of terms joined by the same operator
+    let nested = parse("(ISC AND MIT) AND (Zlib OR MIT OR Zlib)");
+    assert!(nested.equivalent(&parse("MIT AND ISC AND (MIT OR Zlib)")));
+    assert!(!nested.equivalent(&parse("MIT AND ISC AND Zlib OR MIT")));
+}
+
+#[test]
+fn requirements_sorted() {
+    use spdx::Expression;
+
+    let names = |text| {
+        Expression::parse(text)
+            .unwrap()
+            .requirements_sorted()
+            .into_iter()
+            .map(|er| er.req.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names("MIT AND Apache-2.0"), names("Apache-2.0 AND MIT"));
+    assert_eq!(names("Apache-2.0 AND MIT"), ["Apache-2.0", "MIT"]);
+
+    // Duplicates collapse, but distinct `+` and exceptions do not
+    assert_eq!(
+        names(
+            "MIT OR (ISC AND MIT) OR Apache-2.0+ OR Apache-2.0 WITH LLVM-exception OR Apache-2.0"
+        ),
+        [
+            "Apache-2.0",
+            "Apache-2.0 WITH LLVM-exception",
+            "Apache-2.0+",
+            "ISC",
+            "MIT"
+        ]
+    );
+
+    // SPDX ids are ordered before LicenseRefs, which are ordered by document first
+    assert_eq!(
+        names("LicenseRef-b OR DocumentRef-x:LicenseRef-a OR Zlib OR LicenseRef-b"),
+        ["Zlib", "LicenseRef-b", "DocumentRef-x:LicenseRef-a"]
+    );
+
+    // The first occurrence is the one that is kept
+    let expr = Expression::parse("MIT OR ISC OR MIT").unwrap();
+    let sorted = expr.requirements_sorted();
+    assert_eq!(sorted.len(), 2);
+    assert_eq!(sorted[1].span, 0..3);
+
+    let owned = expr.sorted_unique_reqs();
+    assert_eq!(
+        owned,
+        sorted.iter().map(|er| er.req.clone()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn validate_refs() {
+    use spdx::{error::UnknownRef, Expression};
+
+    let expr = Expression::parse(
+        "LicenseRef-Known AND (DocumentRef-a:LicenseRef-x OR DocumentRef-b:LicenseRef-Known) \
+         AND (LicenseRef-Unknown OR MIT OR DocumentRef-a:LicenseRef-y)",
+    )
+    .unwrap();
+
+    assert_eq!(expr.document_refs().collect::<Vec<_>>(), ["a", "b"]);
+
+    expr.validate_refs(&["a", "b"], &["Known", "Unknown"])
+        .unwrap();
+
+    let errors: Vec<_> = expr
+        .validate_refs(&["a"], &["Known", "Other"])
+        .unwrap_err()
+        .into_iter()
+        .map(|err| {
+            let text = &expr.as_ref()[err.span.start as usize..err.span.end as usize];
+            (text.to_owned(), err.id, err.unknown)
+        })
+        .collect();
+
+    assert_eq!(
+        errors,
+        [
+            (
+                "DocumentRef-b:LicenseRef-Known".to_owned(),
+                "b".to_owned(),
+                UnknownRef::Document
+            ),
+            (
+                "LicenseRef-Unknown".to_owned(),
+                "Unknown".to_owned(),
+                UnknownRef::License
+            ),
+        ]
+    );
+
+    // Licenses in external documents aren't checked against the known licenses
+    let errors = expr.validate_refs(&[], &[]).unwrap_err();
+    assert_eq!(
+        errors.iter().map(|err| err.to_string()).collect::<Vec<_>>(),
+        [
+            "unknown license 'LicenseRef-Known'",
+            "unknown document 'DocumentRef-a'",
+            "unknown document 'DocumentRef-b'",
+            "unknown license 'LicenseRef-Unknown'",
+            "unknown document 'DocumentRef-a'",
+        ]
+    );
+
+    // Expressions without refs are always valid
+    let expr = Expression::parse("MIT OR Apache-2.0").unwrap();
+    assert_eq!(expr.document_refs().count(), 0);
+    expr.validate_refs(&[], &[]).unwrap();
+}
+
+#[test]
+fn requirement_spans_cover_plus_and_exception() {
+    use spdx::{expression::ExpressionParser, Expression, ParseMode};
+
+    for (text, mode, expected) in [
+        (
+            "Apache-2.0 WITH LLVM-exception",
+            ParseMode::STRICT,
+            &["Apache-2.0 WITH LLVM-exception"][..],
+        ),
+        (
+            "MIT+ OR (Apache-2.0+  WITH LLVM-exception AND ISC)",
+            ParseMode::STRICT,
+            &["MIT+", "Apache-2.0+  WITH LLVM-exception", "ISC"],
+        ),
+        (
+            "LicenseRef-A WITH Classpath-exception-2.0 OR DocumentRef-B:LicenseRef-C",
+            ParseMode::STRICT,
+            &[
+                "LicenseRef-A WITH Classpath-exception-2.0",
+                "DocumentRef-B:LicenseRef-C",
+            ],
+        ),
+        (
+            "gpl-2.0+ with Classpath-exception-2.0/mit+",
+            ParseMode::LAX,
+            &["gpl-2.0+ with Classpath-exception-2.0", "mit+"],
+        ),
+        (
+            "GPL-2.0-or-later+ AND LGPL-2.1-only",
+            ParseMode::LAX,
+            &["GPL-2.0-or-later+", "LGPL-2.1-only"],
+        ),
+    ] {
+        let expr = Expression::parse_mode(text, mode).unwrap();
+        let spans: Vec<_> = expr
+            .requirements()
+            .map(|er| &text[er.span.start as usize..er.span.end as usize])
+            .collect();
+        assert_eq!(spans, expected, "{text}");
+
+        // The reusable parser is the same parser
+        let reused = ExpressionParser::new().parse_into(text, mode).unwrap();
+        assert!(reused.identical(&expr), "{text}");
+    }
+
+    // The rendered canonical form also spans the whole requirement
+    let expr = Expression::parse("(MIT+ OR Apache-2.0 WITH LLVM-exception)").unwrap();
+    let canonical = Expression::from_nodes(expr.iter().cloned().collect()).unwrap();
+    let spans: Vec<_> = canonical
+        .requirements()
+        .map(|er| &canonical.as_ref()[er.span.start as usize..er.span.end as usize])
+        .collect();
+    assert_eq!(spans, ["MIT+", "Apache-2.0 WITH LLVM-exception"]);
+}
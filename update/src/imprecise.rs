@@ -1,33 +1,39 @@
+use crate::ImpreciseName;
+
 /// Pairs an invalid license identifier with its valid SPDX license identifier.
 /// These invalid identifiers are only allowed when using `Lax` parsing.
-pub const IMPRECISE_NAMES: &[(&str, &str)] = &[
-    ("agplv3", "AGPL-3.0"),
-    ("agpl", "AGPL-3.0"),
-    ("apache 2.0", "Apache-2.0"),
-    ("apache-2", "Apache-2.0"),
-    ("apache2", "Apache-2.0"),
-    ("apache", "Apache-2.0"),
-    ("asl2.0", "Apache-2.0"),
-    ("bsd 2-clause", "BSD-2-Clause"),
-    ("bsd-2clause", "BSD-2-Clause"),
-    ("bsd3", "BSD-3-Clause"),
-    ("bsd", "BSD-2-Clause"),
-    ("cc0", "CC0-1.0"),
-    ("gnu gpl v2", "GPL-3.0"),
-    ("gnu gpl v3", "GPL-3.0"),
-    ("gpl v2", "GPL-2.0"),
-    ("gpl v3", "GPL-3.0"),
-    ("gpl-2.0", "GPL-2.0"),
-    ("gpl-3.0", "GPL-3.0"),
-    ("gpl2", "GPL-2.0"),
-    ("gpl3", "GPL-3.0"),
-    ("gplv2", "GPL-2.0"),
-    ("gplv3", "GPL-3.0"),
-    ("gpl", "GPL-2.0"),
-    ("lgpl", "LGPL-2.0"),
-    ("mit", "MIT"),
-    ("mpl2", "MPL-2.0"),
-    ("mpl", "MPL-2.0"),
-    ("simplified bsd license", "BSD-2-Clause"),
-    ("zlib", "Zlib"),
+///
+/// The entries are sorted longest pattern first, so that the first entry that
+/// matches is always the longest match.
+pub const IMPRECISE_NAMES: &[ImpreciseName] = &[
+    ImpreciseName::prefix("simplified bsd license", "BSD-2-Clause"),
+    ImpreciseName::exact("apache license", "Apache-2.0"),
+    ImpreciseName::prefix("bsd 2-clause", "BSD-2-Clause"),
+    ImpreciseName::prefix("bsd-2clause", "BSD-2-Clause"),
+    ImpreciseName::prefix("apache 2.0", "Apache-2.0"),
+    ImpreciseName::prefix("gnu gpl v2", "GPL-3.0"),
+    ImpreciseName::prefix("gnu gpl v3", "GPL-3.0"),
+    ImpreciseName::prefix("apache-2", "Apache-2.0"),
+    ImpreciseName::prefix("apache2", "Apache-2.0"),
+    ImpreciseName::prefix("gpl-2.0", "GPL-2.0"),
+    ImpreciseName::prefix("gpl-3.0", "GPL-3.0"),
+    ImpreciseName::prefix("agplv3", "AGPL-3.0"),
+    ImpreciseName::prefix("apache", "Apache-2.0"),
+    ImpreciseName::prefix("asl2.0", "Apache-2.0"),
+    ImpreciseName::prefix("gpl v2", "GPL-2.0"),
+    ImpreciseName::prefix("gpl v3", "GPL-3.0"),
+    ImpreciseName::prefix("gplv2", "GPL-2.0"),
+    ImpreciseName::prefix("gplv3", "GPL-3.0"),
+    ImpreciseName::exact("agpl", "AGPL-3.0"),
+    ImpreciseName::prefix("bsd3", "BSD-3-Clause"),
+    ImpreciseName::prefix("gpl2", "GPL-2.0"),
+    ImpreciseName::prefix("gpl3", "GPL-3.0"),
+    ImpreciseName::exact("lgpl", "LGPL-2.0"),
+    ImpreciseName::prefix("mpl2", "MPL-2.0"),
+    ImpreciseName::prefix("zlib", "Zlib"),
+    ImpreciseName::exact("bsd", "BSD-2-Clause"),
+    ImpreciseName::prefix("cc0", "CC0-1.0"),
+    ImpreciseName::exact("gpl", "GPL-2.0"),
+    ImpreciseName::prefix("mit", "MIT"),
+    ImpreciseName::exact("mpl", "MPL-2.0"),
 ];
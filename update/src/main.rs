@@ -1,8 +1,10 @@
 use anyhow::{bail, Context as _, Result};
 use serde_json::{map, Value};
 use std::{
+    collections::BTreeMap,
     env,
     io::{self, Write},
+    path::{Path, PathBuf},
     process,
 };
 
@@ -14,10 +16,129 @@ fn get<'a>(m: &'a Map, k: &str) -> Result<&'a Value> {
         .with_context(|| format!("Malformed JSON: {:?} lacks {}", m, k))
 }
 
+/// The `json` directory of license-list-data, either from a git checkout or an
+/// extracted release archive
+#[derive(Debug)]
+struct DataDir(PathBuf);
+
+impl DataDir {
+    /// Accepts either the `json` directory itself, or the root directory that
+    /// contains it, ie. a checkout or an extracted release archive
+    fn new(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        if !path.is_dir() {
+            bail!("data directory {} does not exist", path.display());
+        }
+
+        let json = path.join("json");
+        let dir = if json.is_dir() { json } else { path };
+
+        // Fail up front rather than after partially writing the output
+        for file in ["licenses.json", "exceptions.json"] {
+            if !dir.join(file).is_file() {
+                bail!(
+                    "{} does not contain {}, it should be the `json` directory of license-list-data, or its parent",
+                    dir.display(),
+                    file
+                );
+            }
+        }
+
+        Ok(Self(dir))
+    }
+
+    /// Reads and deserializes the JSON file at the path relative to the data directory
+    fn read(&self, file: &str) -> Result<Map> {
+        let path = self.0.join(file);
+        if !path.is_file() {
+            bail!("{} does not exist", path.display());
+        }
+
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("unable to read {}", path.display()))?;
+        serde_json::from_str(&contents)
+            .with_context(|| format!("unable to deserialize {}", path.display()))
+    }
+}
+
+/// Extracts a release archive of license-list-data, eg.
+/// `license-list-data-3.20.tar.gz`, into `dest`
+fn extract_archive(archive: &Path, dest: &Path) -> Result<()> {
+    if !archive.is_file() {
+        bail!("archive {} does not exist", archive.display());
+    }
+
+    if dest.exists() {
+        std::fs::remove_dir_all(dest)
+            .with_context(|| format!("failed to remove {}", dest.display()))?;
+    }
+    std::fs::create_dir_all(dest)
+        .with_context(|| format!("failed to create {}", dest.display()))?;
+
+    // Release archives have a single top level `license-list-data-<version>` directory
+    let status = process::Command::new("tar")
+        .arg("-xzf")
+        .arg(archive)
+        .arg("-C")
+        .arg(dest)
+        .arg("--strip-components=1")
+        .status()
+        .context("failed to run tar")?;
+
+    if !status.success() {
+        bail!("failed to extract {}", archive.display());
+    }
+
+    Ok(())
+}
+
+/// Clones or fetches license-list-data into `dest` and checks out the tag
+fn checkout(dest: &Path, upstream_tag: &str) -> Result<()> {
+    let git = |args: &[&str]| -> Result<()> {
+        let status = process::Command::new("git")
+            .args(args)
+            .status()
+            .context("failed to run git")?;
+
+        if !status.success() {
+            bail!("git {} failed", args.join(" "));
+        }
+
+        Ok(())
+    };
+
+    let dest = dest.to_str().context("non-utf8 path")?;
+
+    if !Path::new(dest).exists() {
+        println!("cloning...");
+        git(&[
+            "clone",
+            "https://github.com/spdx/license-list-data.git",
+            dest,
+        ])?;
+    } else {
+        println!("fetching...");
+        git(&["-C", dest, "fetch"])?;
+    }
+
+    println!("checking out...");
+    git(&["-C", dest, "checkout", upstream_tag])
+}
+
+// Shared with the crate so that the hashes of the license texts always match
+#[path = "../../src/detection/normalize.rs"]
+mod normalize;
+// Shared with the crate so that versions are ordered the same way
+#[path = "../../src/version.rs"]
+mod version;
+
 const IMPRECISE: &str = include_str!("imprecise.rs");
+const SUCCESSORS: &str = include_str!("successors.rs");
 
 fn write_exception_texts(
     texts: &mut impl Write,
+    data: &DataDir,
+    text_dir: &Path,
     exceptions: impl Iterator<Item = impl AsRef<str>>,
 ) -> Result<()> {
     // Splat the license text into their own file and accumulate
@@ -25,13 +146,9 @@ fn write_exception_texts(
 
     for exc in exceptions {
         let exc = exc.as_ref();
-        let text_path = format!("src/text/exceptions/{}", exc);
-        if !std::path::Path::new(&text_path).exists() {
-            let json: Map = serde_json::from_str(
-                &std::fs::read_to_string(format!("spdx-data/json/exceptions/{}.json", exc))
-                    .with_context(|| format!("unable to open exceptions/{}.json", exc))?,
-            )
-            .with_context(|| format!("unable to deserialize exceptions/{}.json", exc))?;
+        let text_path = text_dir.join("exceptions").join(exc);
+        if !text_path.exists() {
+            let json = data.read(&format!("exceptions/{}.json", exc))?;
 
             let text = get(&json, "licenseExceptionText")
                 .with_context(|| format!("failed to get license exception text for {}", exc))?;
@@ -59,12 +176,13 @@ fn write_exception_texts(
     Ok(())
 }
 
-fn write_exceptions(identifiers: &mut impl Write, texts: &mut impl Write) -> Result<()> {
-    let json: Map = serde_json::from_str(
-        &std::fs::read_to_string("spdx-data/json/exceptions.json")
-            .context("unable to open exceptions.json")?,
-    )
-    .context("failed to deserialize exceptions.json")?;
+fn write_exceptions(
+    identifiers: &mut impl Write,
+    texts: &mut impl Write,
+    data: &DataDir,
+    text_dir: &Path,
+) -> Result<Vec<(String, u16)>> {
+    let json = data.read("exceptions.json")?;
 
     let exceptions = get(&json, "exceptions")?;
     let exceptions = if let Value::Array(ref v) = exceptions {
@@ -101,14 +219,18 @@ fn write_exceptions(identifiers: &mut impl Write, texts: &mut impl Write) -> Res
         };
     }
 
-    writeln!(identifiers, "pub const EXCEPTIONS: &[(&str, u8)] = &[")?;
+    writeln!(identifiers, "pub const EXCEPTIONS: &[(&str, u16)] = &[")?;
     v.sort_by_key(|v| v.0);
     for (exc, flags) in v.iter() {
         writeln!(identifiers, "    (\"{}\", {}),", exc, flags)?;
     }
     writeln!(identifiers, "];")?;
 
-    write_exception_texts(texts, v.into_iter().map(|(exc, _)| exc))
+    write_exception_texts(texts, data, text_dir, v.iter().map(|(exc, _)| exc))?;
+
+    Ok(v.into_iter()
+        .map(|(exc, flags)| (exc.clone(), flag_bits(flags)))
+        .collect())
 }
 
 fn is_copyleft(license: &str) -> bool {
@@ -146,17 +268,57 @@ fn is_gnu(license: &str) -> bool {
         || license.starts_with("LGPL-")
 }
 
+fn is_cc(license: &str) -> bool {
+    // All of the Creative Commons licenses, including the public domain
+    // dedication and mark
+    license.starts_with("CC-") || license.starts_with("CC0-")
+}
+
+fn is_non_commercial(license: &str) -> bool {
+    license.starts_with("CC-BY-NC-")
+        || license == "NCGL-UK-2.0"
+        || license == "PolyForm-Noncommercial-1.0.0"
+}
+
+fn is_public_domain_equivalent(license: &str) -> bool {
+    // Public domain dedications and notices, and licenses without any
+    // conditions at all, eg. not even attribution
+    license.ends_with("-PD")
+        || license == "0BSD"
+        || license == "blessing"
+        || license == "CC-PDDC"
+        || license == "CC-PDM-1.0"
+        || license == "CC0-1.0"
+        || license == "PDDL-1.0"
+        || license == "SAX-PD-2.0"
+        || license == "Unlicense"
+}
+
+/// The 64-bit FNV-1a hash of a license text, exactly as it is in the SPDX data
+fn content_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Writes the text of each license, returning the length and
+/// [`content_hash`] of each text, or `(0, 0)` if the license has no text
 fn write_license_texts<'lic>(
     texts: &mut impl Write,
+    data: &DataDir,
+    text_dir: &Path,
     licenses: impl Iterator<Item = impl AsRef<str>>,
-) -> Result<()> {
+) -> Result<Vec<(usize, u64)>> {
     // Splat the license text into their own file and accumulate
     writeln!(texts, "pub const LICENSE_TEXTS: &[(&str, &str)] = &[")?;
 
-    for license in licenses {
+    let mut hashes = Vec::new();
+    let mut info = Vec::new();
+    for (index, license) in licenses.enumerate() {
         let license = license.as_ref();
         if license == "NOASSERTION" {
             writeln!(texts, "    (\"{0}\", \"\"),", license)?;
+            info.push((0, 0));
             continue;
         }
 
@@ -171,19 +333,15 @@ fn write_license_texts<'lic>(
             Cow::Borrowed(license)
         };
 
-        let text_path = format!("src/text/licenses/{}", license_name);
-        if !std::path::Path::new(&text_path).exists() {
-            let json: Map = serde_json::from_str(
-                &std::fs::read_to_string(format!("spdx-data/json/details/{}.json", license_name))
-                    .with_context(|| format!("unable to open details/{}.json", license_name))?,
-            )
-            .with_context(|| format!("unable to deserialize details/{}.json", license_name))?;
+        let text_path = text_dir.join("licenses").join(license_name.as_ref());
+        if !text_path.exists() {
+            let json = data.read(&format!("details/{}.json", license_name))?;
 
             let text = get(&json, "licenseText")
                 .with_context(|| format!("failed to get license text for {}", license_name))?;
 
             std::fs::write(
-                text_path,
+                &text_path,
                 format!(
                     "r#\"{}\"#",
                     text.as_str().context("licenseText is not a string")?
@@ -192,6 +350,18 @@ fn write_license_texts<'lic>(
             .with_context(|| format!("failed to write license text for {}", license_name))?;
         }
 
+        let text = std::fs::read_to_string(&text_path)
+            .with_context(|| format!("failed to read license text for {}", license_name))?;
+        let text = text
+            .strip_prefix("r#\"")
+            .and_then(|text| text.strip_suffix("\"#"))
+            .with_context(|| format!("license text for {} is not a raw string", license_name))?;
+
+        if let Some(hash) = normalize::text_hash(text) {
+            hashes.push((hash, index));
+        }
+        info.push((text.len(), content_hash(text)));
+
         writeln!(
             texts,
             "    (\"{}\", include!(\"text/licenses/{}\")),",
@@ -201,26 +371,38 @@ fn write_license_texts<'lic>(
 
     writeln!(texts, "];\n")?;
 
-    Ok(())
+    // Used by `detection::exact_match` to lookup the license for a text
+    hashes.sort_unstable();
+    writeln!(texts, "pub const LICENSE_TEXT_HASHES: &[(u64, usize)] = &[")?;
+    for (hash, index) in hashes {
+        writeln!(texts, "    (0x{:016x}, {}),", hash, index)?;
+    }
+    writeln!(texts, "];\n")?;
+
+    Ok(info)
 }
 
-fn write_licenses(identifiers: &mut impl Write, texts: &mut impl Write) -> Result<()> {
+fn write_licenses(
+    identifiers: &mut impl Write,
+    texts: &mut impl Write,
+    data: &DataDir,
+    text_dir: &Path,
+) -> Result<Vec<(String, String, u16)>> {
     writeln!(
         identifiers,
         "
-pub const IS_FSF_LIBRE: u8 = 0x1;
-pub const IS_OSI_APPROVED: u8 = 0x2;
-pub const IS_DEPRECATED: u8 = 0x4;
-pub const IS_COPYLEFT: u8 = 0x8;
-pub const IS_GNU: u8 = 0x10;
+pub const IS_FSF_LIBRE: u16 = 0x1;
+pub const IS_OSI_APPROVED: u16 = 0x2;
+pub const IS_DEPRECATED: u16 = 0x4;
+pub const IS_COPYLEFT: u16 = 0x8;
+pub const IS_GNU: u16 = 0x10;
+pub const IS_CC: u16 = 0x20;
+pub const IS_NON_COMMERCIAL: u16 = 0x40;
+pub const IS_PUBLIC_DOMAIN_EQUIVALENT: u16 = 0x80;
 "
     )?;
 
-    let json: Map = serde_json::from_str(
-        &std::fs::read_to_string("spdx-data/json/licenses.json")
-            .context("unable to open licenses.json")?,
-    )
-    .context("failed to deserialize licenses.json")?;
+    let json = data.read("licenses.json")?;
 
     let licenses = get(&json, "licenses")?;
     let licenses = if let Value::Array(v) = licenses {
@@ -268,6 +450,18 @@ pub const IS_GNU: u8 = 0x10;
                 flags.push_str("IS_GNU | ");
             }
 
+            if is_cc(id) {
+                flags.push_str("IS_CC | ");
+            }
+
+            if is_non_commercial(id) {
+                flags.push_str("IS_NON_COMMERCIAL | ");
+            }
+
+            if is_public_domain_equivalent(id) {
+                flags.push_str("IS_PUBLIC_DOMAIN_EQUIVALENT | ");
+            }
+
             if flags.is_empty() {
                 flags.push_str("0x0");
             } else {
@@ -308,72 +502,480 @@ pub const IS_GNU: u8 = 0x10;
     } else {
         bail!("Malformed JSON: {:?}", lic_list_ver)
     }
+    let release_date = get(&json, "releaseDate")?;
+    if let Value::String(s) = release_date {
+        writeln!(identifiers, "pub const RELEASE_DATE: &str = {:?};", s)?;
+    } else {
+        bail!("Malformed JSON: {:?}", release_date)
+    }
     writeln!(identifiers)?;
-    writeln!(identifiers, "pub const LICENSES: &[(&str, &str, u8)] = &[")?;
+    writeln!(identifiers, "pub const LICENSES: &[(&str, &str, u16)] = &[")?;
     for (id, name, flags) in &v {
         writeln!(identifiers, "    (\"{}\", r#\"{}\"#, {}),", id, name, flags)?;
     }
     writeln!(identifiers, "];\n")?;
 
-    write_license_texts(texts, v.into_iter().map(|(name, _, _)| name))
+    let info = write_license_texts(texts, data, text_dir, v.iter().map(|(name, _, _)| name))?;
+
+    // The text metadata is always available, unlike the texts themselves,
+    // which are only included with the `text` feature
+    writeln!(
+        identifiers,
+        "/// The length in bytes, and 64-bit FNV-1a hash, of the SPDX text of each
+/// license in [`LICENSES`], or `(0, 0)` if the license has no text
+pub const LICENSE_TEXT_INFO: &[(u32, u64)] = &["
+    )?;
+    for (len, hash) in info {
+        writeln!(identifiers, "    ({}, 0x{:016x}),", len, hash)?;
+    }
+    writeln!(identifiers, "];\n")?;
+
+    Ok(v.into_iter()
+        .map(|(id, name, flags)| (id, name.clone(), flag_bits(&flags)))
+        .collect())
+}
+
+/// Finds the version of the license list each license and exception was first
+/// added in, by reading licenses.json and exceptions.json at each tag of
+/// license-list-data up to `version`, if the data directory is a git checkout
+/// of it.
+///
+/// Returns the earliest version that was read, which is `version` itself if
+/// there is no history, along with the versions the ids were added in.
+fn added_in_history(data: &DataDir, version: &str) -> Result<(String, BTreeMap<String, String>)> {
+    let git = |dir: &Path, args: &[&str]| -> Option<String> {
+        let output = process::Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        String::from_utf8(output.stdout).ok()
+    };
+
+    let mut added_in = BTreeMap::new();
+
+    // Data directories that aren't a checkout of license-list-data, eg. an
+    // extracted release archive, have no history
+    let root = match git(&data.0, &["rev-parse", "--show-toplevel"]) {
+        Some(root) => PathBuf::from(root.trim()),
+        None => return Ok((version.to_owned(), added_in)),
+    };
+    if !root.join("json/licenses.json").is_file() {
+        return Ok((version.to_owned(), added_in));
+    }
+
+    let tags = git(&root, &["tag", "--list", "v*"]).context("failed to list tags")?;
+    let mut versions: Vec<_> = tags
+        .lines()
+        .filter_map(|tag| tag.strip_prefix('v'))
+        .filter(|tag| version::compare_versions(tag, version) != std::cmp::Ordering::Greater)
+        .collect();
+    versions.sort_by(|a, b| version::compare_versions(a, b));
+
+    let mut earliest = None;
+    for tag_version in versions {
+        for (file, list, key) in [
+            ("licenses.json", "licenses", "licenseId"),
+            ("exceptions.json", "exceptions", "licenseExceptionId"),
+        ] {
+            // Older tags may not have the JSON data at all
+            let contents = match git(&root, &["show", &format!("v{}:json/{}", tag_version, file)]) {
+                Some(contents) => contents,
+                None => continue,
+            };
+
+            let json: Map = serde_json::from_str(&contents)
+                .with_context(|| format!("unable to deserialize {} at v{}", file, tag_version))?;
+
+            let entries = match get(&json, list)? {
+                Value::Array(entries) => entries,
+                entries => bail!("Malformed JSON: {:?}", entries),
+            };
+
+            for entry in entries {
+                let id = match entry {
+                    Value::Object(entry) => get(entry, key)?,
+                    entry => bail!("Malformed JSON: {:?}", entry),
+                };
+
+                if let Value::String(id) = id {
+                    added_in
+                        .entry(id.clone())
+                        .or_insert_with(|| tag_version.to_owned());
+                } else {
+                    bail!("Malformed JSON: {:?}", id)
+                }
+            }
+
+            earliest.get_or_insert(tag_version);
+        }
+    }
+
+    Ok((earliest.unwrap_or(version).to_owned(), added_in))
+}
+
+/// Writes the version of the license list each license and exception was
+/// added in, for the ids that were added after the earliest version in the
+/// history. Ids that aren't in the history at all were added in `version`.
+fn write_added_in(
+    identifiers: &mut impl Write,
+    version: &str,
+    (earliest, added_in): &(String, BTreeMap<String, String>),
+    licenses: &[(String, String, u16)],
+    exceptions: &[(String, u16)],
+) -> Result<()> {
+    let mut ids: Vec<_> = licenses
+        .iter()
+        .map(|(id, ..)| id)
+        .chain(exceptions.iter().map(|(id, _)| id))
+        .collect();
+    ids.sort();
+
+    writeln!(
+        identifiers,
+        "
+/// The earliest version of the license list that the update tool had the
+/// history of, the licenses and exceptions that were already in it may have
+/// been added in an earlier version
+pub const EARLIEST_ADDED_IN: &str = {:?};
+
+/// The version of the license list each license and exception was added in,
+/// sorted by id, for the ids that were added after [`EARLIEST_ADDED_IN`]
+pub const ADDED_IN: &[(&str, &str)] = &[",
+        earliest
+    )?;
+
+    for id in ids {
+        let added = added_in.get(id).map_or(version, String::as_str);
+        if added != earliest {
+            writeln!(identifiers, "    ({:?}, {:?}),", id, added)?;
+        }
+    }
+
+    writeln!(identifiers, "];")?;
+    Ok(())
+}
+
+/// Writes the table of the licenses that replaced deprecated license
+/// identifiers, which is the hand written table in successors.rs, along with
+/// the `-only` variant of each bare GNU license, eg. `GPL-2.0-only` for
+/// `GPL-2.0`, which are derived from the license list
+fn write_successors(
+    identifiers: &mut impl Write,
+    licenses: &[(String, String, u16)],
+) -> Result<()> {
+    let mut successors = Vec::new();
+    for entry in table_entries(SUCCESSORS)? {
+        match entry.as_slice() {
+            [dep, id] => successors.push((dep.to_string(), id.to_string(), None)),
+            [dep, id, exc] => {
+                successors.push((dep.to_string(), id.to_string(), Some(exc.to_string())))
+            }
+            _ => bail!("malformed successor {:?}", entry),
+        }
+    }
+
+    for (dep, id) in gnu_successors(licenses) {
+        successors.push((dep, id, None));
+    }
+
+    successors.sort();
+    for pair in successors.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            bail!(
+                "deprecated license {:?} has more than one successor",
+                pair[0].0
+            );
+        }
+    }
+
+    writeln!(
+        identifiers,
+        "/// Pairs a deprecated license identifier with the license identifier, and
+/// optional exception identifier, that replaced it in the SPDX license list.
+///
+/// Deprecated identifiers that were not replaced 1:1, eg. `Net-SNMP`, which
+/// was split into several licenses, are not included.
+pub const DEPRECATED_SUCCESSORS: &[(&str, &str, Option<&str>)] = &["
+    )?;
+    for (dep, id, exc) in &successors {
+        match exc {
+            Some(exc) => writeln!(identifiers, "    ({:?}, {:?}, Some({:?})),", dep, id, exc)?,
+            None => writeln!(identifiers, "    ({:?}, {:?}, None),", dep, id)?,
+        }
+    }
+    writeln!(identifiers, "];\n")?;
+
+    Ok(())
+}
+
+/// Derives the successor of each deprecated, bare, GNU license, eg. `GPL-2.0`,
+/// which is its `-only` variant, since the license list doesn't say what
+/// replaced a deprecated identifier
+fn gnu_successors(licenses: &[(String, String, u16)]) -> Vec<(String, String)> {
+    let has_license = |id: &str| {
+        licenses
+            .binary_search_by(|(lic, _, _)| lic.as_str().cmp(id))
+            .is_ok()
+    };
+
+    licenses
+        .iter()
+        // Only the deprecated licenses, ie. `IS_DEPRECATED`
+        .filter(|(id, _, flags)| flags & 0x4 != 0 && is_gnu(id))
+        .filter_map(|(id, _, _)| {
+            let only = format!("{}-only", id);
+            has_license(&only).then(|| (id.clone(), only))
+        })
+        .collect()
+}
+
+/// Converts the flags expression written to identifiers.rs into its value
+fn flag_bits(flags: &str) -> u16 {
+    flags
+        .split(" | ")
+        .map(|flag| match flag {
+            "IS_FSF_LIBRE" => 0x1,
+            "IS_OSI_APPROVED" => 0x2,
+            "IS_DEPRECATED" => 0x4,
+            "IS_COPYLEFT" => 0x8,
+            "IS_GNU" => 0x10,
+            "IS_CC" => 0x20,
+            "IS_NON_COMMERCIAL" => 0x40,
+            "IS_PUBLIC_DOMAIN_EQUIVALENT" => 0x80,
+            _ => 0,
+        })
+        .fold(0, |acc, bit| acc | bit)
+}
+
+/// Gets the quoted strings of each entry in one of the hand written tables,
+/// eg. `["gpl", "GPL-2.0"]` for `ImpreciseName::exact("gpl", "GPL-2.0"),`
+fn table_entries(table: &str) -> Result<Vec<Vec<&str>>> {
+    let start = table
+        .find("= &[")
+        .context("unable to find start of table")?
+        + 4;
+
+    let mut entries = Vec::new();
+    let mut entry = Vec::new();
+    let mut depth = 0;
+    let mut rest = &table[start..];
+
+    while let Some(i) = rest.find(|c| matches!(c, '(' | ')' | '"' | ']')) {
+        match rest.as_bytes()[i] {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    entries.push(std::mem::take(&mut entry));
+                }
+            }
+            b'"' => {
+                let len = rest[i + 1..]
+                    .find('"')
+                    .context("unterminated string in table")?;
+                entry.push(&rest[i + 1..i + 1 + len]);
+                rest = &rest[i + 2 + len..];
+                continue;
+            }
+            _ if depth == 0 => break,
+            _ => {}
+        }
+
+        rest = &rest[i + 1..];
+    }
+
+    Ok(entries)
+}
+
+/// Validates the generated license and exception data, and the hand written
+/// tables that refer to it, so that mistakes are caught when generating
+/// rather than as missing identifiers at runtime
+fn validate(
+    licenses: &[(String, String, u16)],
+    exceptions: &[(String, u16)],
+    text_dir: &Path,
+) -> Result<()> {
+    let has_license = |id: &str| {
+        licenses
+            .binary_search_by(|(lic, _, _)| lic.as_str().cmp(id))
+            .is_ok()
+    };
+    let has_exception = |id: &str| {
+        exceptions
+            .binary_search_by(|(exc, _)| exc.as_str().cmp(id))
+            .is_ok()
+    };
+
+    for pair in licenses.windows(2) {
+        if pair[0].0 >= pair[1].0 {
+            bail!("license {:?} is duplicated or out of order", pair[1].0);
+        }
+    }
+
+    for pair in exceptions.windows(2) {
+        if pair[0].0 >= pair[1].0 {
+            bail!("exception {:?} is duplicated or out of order", pair[1].0);
+        }
+    }
+
+    for (exc, _) in exceptions {
+        if has_license(exc) {
+            bail!("exception {:?} has the same name as a license", exc);
+        }
+    }
+
+    for (id, _, _) in licenses {
+        if let Some(root) = id
+            .strip_prefix("GFDL-")
+            .and_then(|_| id.strip_suffix("-invariants"))
+        {
+            let alias = format!("{}-invariants-only", root);
+            if !has_license(&alias) {
+                bail!("{:?} is an alias of {:?} which doesn't exist", id, alias);
+            }
+
+            if !text_dir.join("licenses").join(&alias).exists() {
+                bail!("{:?} is an alias of {:?} which has no text", id, alias);
+            }
+        }
+    }
+
+    for entry in table_entries(IMPRECISE)? {
+        match entry.as_slice() {
+            [_, id] if has_license(id) => {}
+            _ => bail!("imprecise name {:?} is not a known license", entry),
+        }
+    }
+
+    for entry in table_entries(SUCCESSORS)? {
+        match entry.as_slice() {
+            [dep, id] if has_license(dep) && has_license(id) => {}
+            [dep, id, exc] if has_license(dep) && has_license(id) && has_exception(exc) => {}
+            _ => bail!("successor {:?} is not a known license", entry),
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the number of licenses and exceptions, and a hash of their contents,
+/// so that the library tests can check that the generated data is consistent.
+///
+/// The hash is the 64-bit FNV-1a of each license's id, `0`, name, `0`, and
+/// little-endian flags, followed by each exception's id, `0`, and
+/// little-endian flags
+fn write_manifest(
+    identifiers: &mut impl Write,
+    licenses: &[(String, String, u16)],
+    exceptions: &[(String, u16)],
+) -> Result<()> {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    let mut update = |bytes: &[u8]| {
+        for b in bytes {
+            hash ^= u64::from(*b);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    };
+
+    for (id, name, flags) in licenses {
+        update(id.as_bytes());
+        update(&[0]);
+        update(name.as_bytes());
+        update(&[0]);
+        update(&flags.to_le_bytes());
+    }
+
+    for (id, flags) in exceptions {
+        update(id.as_bytes());
+        update(&[0]);
+        update(&flags.to_le_bytes());
+    }
+
+    writeln!(
+        identifiers,
+        "
+/// The number of licenses in [`LICENSES`]
+pub const LICENSE_COUNT: usize = {};
+/// The number of exceptions in [`EXCEPTIONS`]
+pub const EXCEPTION_COUNT: usize = {};
+/// A hash of the id, name, and flags of every license and exception, which
+/// changes whenever the list does, so that data keyed by
+/// [`LicenseId::index`](crate::LicenseId::index) or
+/// [`ExceptionId::index`](crate::ExceptionId::index) can detect that it was
+/// created with a different version of the list
+pub const CONTENT_HASH: u64 = {:#018x};",
+        licenses.len(),
+        exceptions.len(),
+        hash
+    )?;
+
+    Ok(())
 }
 
 fn real_main() -> Result<()> {
     let mut upstream_tag = None;
+    let mut data_dir = None;
     let mut debug = false;
-    for e in env::args().skip(1) {
+    let mut args = env::args().skip(1);
+    while let Some(e) = args.next() {
         match e.as_str() {
             "-d" => {
                 debug = true;
             }
+            "--data-dir" => {
+                data_dir = Some(PathBuf::from(
+                    args.next().context("--data-dir requires a path")?,
+                ));
+            }
             s if s.starts_with('v') => upstream_tag = Some(s.to_owned()),
             _ => bail!("Unknown option {:?}", e),
         }
     }
 
-    let upstream_tag = match upstream_tag {
-        None => {
+    // Use a local checkout, extracted release, or release archive if one was
+    // specified, so that neither git nor network access are needed
+    let data = if let Some(path) = data_dir {
+        let path = if path.is_file() {
+            let dest = PathBuf::from("spdx-data-archive");
+            println!("extracting {}...", path.display());
+            extract_archive(&path, &dest)?;
+            dest
+        } else {
+            path
+        };
+
+        DataDir::new(path)?
+    } else {
+        let upstream_tag = upstream_tag.as_deref().unwrap_or_else(|| {
             eprintln!(
                 "WARN: fetching data from the master branch of spdx/license-list-data; \
                  consider specifying a tag (e.g. v3.0)"
             );
 
-            "master".to_owned()
-        }
-        Some(ut) => {
-            if debug {
-                eprintln!("Using tag {:?}", ut);
-            }
-            ut
+            "master"
+        });
+
+        if debug {
+            eprintln!("Using tag {:?}", upstream_tag);
         }
-    };
 
-    if !std::path::Path::new("spdx-data").exists() {
-        println!("cloning...");
-        assert!(std::process::Command::new("git")
-            .args(&[
-                "clone",
-                "https://github.com/spdx/license-list-data.git",
-                "spdx-data"
-            ])
-            .status()
-            .unwrap()
-            .success());
-    } else {
-        println!("fetching...");
-        assert!(std::process::Command::new("git")
-            .args(&["-C", "spdx-data", "fetch"])
-            .status()
-            .unwrap()
-            .success());
-    }
+        checkout(Path::new("spdx-data"), upstream_tag)?;
+        DataDir::new("spdx-data")?
+    };
 
-    println!("checking out...");
-    assert!(std::process::Command::new("git")
-        .args(&["-C", "spdx-data", "checkout", &upstream_tag])
-        .status()
-        .unwrap()
-        .success());
+    // The local data is the source of truth for the version, the tag is only
+    // used to select it when checking out
+    let upstream_tag = match get(&data.read("licenses.json")?, "licenseListVersion")? {
+        Value::String(version) => format!("v{}", version),
+        version => bail!("Malformed JSON: {:?}", version),
+    };
 
     {
         let mut identifiers = io::BufWriter::new(std::fs::File::create("src/identifiers.rs")?);
@@ -398,15 +1000,28 @@ fn real_main() -> Result<()> {
 
         std::fs::create_dir_all("src/text/licenses")
             .context("failed to create licenses text dir")?;
-        write_licenses(&mut identifiers, &mut texts)?;
+        let text_dir = Path::new("src/text");
+        let licenses = write_licenses(&mut identifiers, &mut texts, &data, text_dir)?;
 
         // Add the contents or imprecise.rs, which maps invalid identifiers to
         // valid ones
         writeln!(identifiers, "{}", IMPRECISE)?;
 
+        // Add the successors of the deprecated identifiers, both those derived
+        // from the license list and those in successors.rs
+        write_successors(&mut identifiers, &licenses)?;
+
         std::fs::create_dir_all("src/text/exceptions")
             .context("failed to create exceptions text dir")?;
-        write_exceptions(&mut identifiers, &mut texts)?;
+        let exceptions = write_exceptions(&mut identifiers, &mut texts, &data, text_dir)?;
+
+        // The version of the checked out data, without the `v`
+        let version = &upstream_tag[1..];
+        let history = added_in_history(&data, version)?;
+        write_added_in(&mut identifiers, version, &history, &licenses, &exceptions)?;
+
+        validate(&licenses, &exceptions, text_dir)?;
+        write_manifest(&mut identifiers, &licenses, &exceptions)?;
     }
 
     // Run rustfmt on the final files
@@ -455,3 +1070,244 @@ fn main() {
         process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/license-list-data")
+    }
+
+    /// Creates an empty output directory for the license and exception texts
+    fn text_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("spdx-update-{}", name));
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+
+        std::fs::create_dir_all(dir.join("licenses")).unwrap();
+        std::fs::create_dir_all(dir.join("exceptions")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn data_dir() {
+        let root = DataDir::new(fixture()).unwrap();
+        let json = DataDir::new(fixture().join("json")).unwrap();
+        assert_eq!(root.0, json.0);
+
+        let err = DataDir::new(fixture().join("nope")).unwrap_err();
+        assert!(err.to_string().contains("does not exist"), "{}", err);
+
+        let err = DataDir::new(fixture().join("json/details")).unwrap_err();
+        assert!(
+            err.to_string().contains("does not contain licenses.json"),
+            "{}",
+            err
+        );
+
+        let err = root.read("details/Nope.json").unwrap_err();
+        assert!(err.to_string().contains("does not exist"), "{}", err);
+    }
+
+    #[test]
+    fn generates_from_data_dir() {
+        let data = DataDir::new(fixture()).unwrap();
+        let text_dir = text_dir("generates");
+
+        let mut identifiers = Vec::new();
+        let mut texts = Vec::new();
+
+        let licenses = write_licenses(&mut identifiers, &mut texts, &data, &text_dir).unwrap();
+        let exceptions = write_exceptions(&mut identifiers, &mut texts, &data, &text_dir).unwrap();
+
+        let ids: Vec<_> = licenses.iter().map(|(id, ..)| id.as_str()).collect();
+        assert_eq!(ids, ["GPL-2.0-only", "MIT", "NOASSERTION"]);
+        assert_eq!(exceptions, [("LLVM-exception".to_owned(), 0)]);
+
+        let identifiers = String::from_utf8(identifiers).unwrap();
+        assert!(identifiers.contains("pub const VERSION: &str = \"3.99\";"));
+        assert!(
+            identifiers.contains("(\"MIT\", r#\"MIT License\"#, IS_OSI_APPROVED | IS_FSF_LIBRE),")
+        );
+        assert!(identifiers.contains("(\"LLVM-exception\", 0),"));
+        // GPL-2.0-only, MIT, and NOASSERTION, which has no text
+        assert!(identifiers.contains(&format!(
+            "    ({}, 0x{:016x}),\n    (0, 0x0000000000000000),\n];",
+            "MIT License text".len(),
+            content_hash("MIT License text")
+        )));
+
+        let texts = String::from_utf8(texts).unwrap();
+        assert!(texts.contains("(\"MIT\", include!(\"text/licenses/MIT\")),"));
+        assert!(texts.contains("pub const LICENSE_TEXT_HASHES: &[(u64, usize)] = &[\n    (0x"));
+        assert!(
+            texts.contains("(\"LLVM-exception\", include!(\"text/exceptions/LLVM-exception\")),")
+        );
+
+        assert_eq!(
+            std::fs::read_to_string(text_dir.join("licenses/MIT")).unwrap(),
+            "r#\"MIT License text\"#"
+        );
+        assert_eq!(
+            std::fs::read_to_string(text_dir.join("exceptions/LLVM-exception")).unwrap(),
+            "r#\"LLVM Exception text\"#"
+        );
+    }
+
+    #[test]
+    fn derives_gnu_successors() {
+        let licenses: Vec<_> = [
+            ("GFDL-1.3", 0x14),
+            ("GFDL-1.3-invariants-only", 0x10),
+            ("GFDL-1.3-only", 0x10),
+            ("GPL-2.0", 0x14),
+            ("GPL-2.0+", 0x14),
+            ("GPL-2.0-only", 0x10),
+            ("GPL-2.0-with-GCC-exception", 0x14),
+            ("GPL-3.0-only", 0x10),
+            ("StandardML-NJ", 0x4),
+        ]
+        .iter()
+        .map(|(id, flags)| (id.to_string(), String::new(), *flags))
+        .collect();
+
+        assert_eq!(
+            gnu_successors(&licenses),
+            [
+                ("GFDL-1.3".to_owned(), "GFDL-1.3-only".to_owned()),
+                ("GPL-2.0".to_owned(), "GPL-2.0-only".to_owned()),
+            ]
+        );
+
+        let mut identifiers = Vec::new();
+        write_successors(&mut identifiers, &licenses).unwrap();
+        let identifiers = String::from_utf8(identifiers).unwrap();
+        assert!(identifiers.contains(
+            "    (\"GPL-2.0\", \"GPL-2.0-only\", None),\n    (\"GPL-2.0-with-GCC-exception\","
+        ));
+        assert!(identifiers.contains("    (\"StandardML-NJ\", \"SMLNJ\", None),"));
+    }
+
+    #[test]
+    fn derives_added_in() {
+        let repo = env::temp_dir().join("spdx-update-history");
+        if repo.exists() {
+            std::fs::remove_dir_all(&repo).unwrap();
+        }
+        std::fs::create_dir_all(repo.join("json")).unwrap();
+
+        let git = |args: &[&str]| {
+            let status = process::Command::new("git")
+                .arg("-C")
+                .arg(&repo)
+                .args(&["-c", "user.name=spdx", "-c", "user.email=spdx@example.com"])
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {:?}", args);
+        };
+
+        git(&["init", "-q"]);
+        for (tag, licenses, exceptions) in [
+            ("v3.11", &["MIT"][..], None),
+            ("v3.12", &["Elastic-2.0", "MIT"][..], Some("LLVM-exception")),
+            (
+                "v3.13",
+                &["Elastic-2.0", "MIT", "Nope"][..],
+                Some("LLVM-exception"),
+            ),
+        ] {
+            let licenses: Vec<_> = licenses
+                .iter()
+                .map(|id| format!("{{\"licenseId\": {:?}}}", id))
+                .collect();
+            std::fs::write(
+                repo.join("json/licenses.json"),
+                format!("{{\"licenses\": [{}]}}", licenses.join(", ")),
+            )
+            .unwrap();
+
+            // The first tag doesn't have any exceptions data
+            if let Some(exc) = exceptions {
+                std::fs::write(
+                    repo.join("json/exceptions.json"),
+                    format!(
+                        "{{\"exceptions\": [{{\"licenseExceptionId\": {:?}}}]}}",
+                        exc
+                    ),
+                )
+                .unwrap();
+            }
+
+            git(&["add", "-A"]);
+            git(&["commit", "-q", "-m", tag]);
+            git(&["tag", tag]);
+        }
+
+        // Tags after the version are ignored
+        let history = added_in_history(&DataDir::new(&repo).unwrap(), "3.12").unwrap();
+        assert_eq!(history.0, "3.11");
+        assert_eq!(history.1["MIT"], "3.11");
+        assert_eq!(history.1["Elastic-2.0"], "3.12");
+        assert_eq!(history.1["LLVM-exception"], "3.12");
+        assert!(!history.1.contains_key("Nope"));
+
+        let licenses: Vec<_> = ["Elastic-2.0", "MIT", "NOASSERTION"]
+            .iter()
+            .map(|id| (id.to_string(), String::new(), 0))
+            .collect();
+        let mut identifiers = Vec::new();
+        write_added_in(
+            &mut identifiers,
+            "3.12",
+            &history,
+            &licenses,
+            &[("LLVM-exception".to_owned(), 0)],
+        )
+        .unwrap();
+        let identifiers = String::from_utf8(identifiers).unwrap();
+
+        assert!(identifiers.contains("pub const EARLIEST_ADDED_IN: &str = \"3.11\";"));
+        // NOASSERTION isn't in the license list, so it is in the current version
+        assert!(identifiers.contains(
+            "    (\"Elastic-2.0\", \"3.12\"),\n    (\"LLVM-exception\", \"3.12\"),\n    (\"NOASSERTION\", \"3.12\"),\n];"
+        ));
+
+        // Without any history, everything is in the current version
+        let history = added_in_history(&DataDir::new(fixture()).unwrap(), "3.99").unwrap();
+        assert_eq!(history, ("3.99".to_owned(), BTreeMap::new()));
+    }
+
+    #[test]
+    fn missing_details() {
+        let data = DataDir::new(fixture()).unwrap();
+        let text_dir = text_dir("missing");
+
+        let err =
+            write_license_texts(&mut Vec::new(), &data, &text_dir, ["Nope"].iter()).unwrap_err();
+        assert!(
+            err.to_string().contains("Nope.json does not exist"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn gfdl_alias_text() {
+        let text_dir = text_dir("gfdl-alias");
+        let licenses = vec![
+            ("GFDL-1.1-invariants".to_owned(), String::new(), 0),
+            ("GFDL-1.1-invariants-only".to_owned(), String::new(), 0),
+        ];
+
+        // The alias's text is looked for in the given text directory
+        let err = validate(&licenses, &[], &text_dir).unwrap_err();
+        assert!(err.to_string().contains("which has no text"), "{}", err);
+
+        std::fs::write(text_dir.join("licenses/GFDL-1.1-invariants-only"), "text").unwrap();
+        let err = validate(&licenses, &[], &text_dir).unwrap_err();
+        assert!(!err.to_string().contains("which has no text"), "{}", err);
+    }
+}
@@ -0,0 +1,55 @@
+/// Pairs a deprecated license identifier with the license identifier, and
+/// optional exception identifier, that replaced it in the SPDX license list.
+///
+/// Deprecated identifiers that were not replaced 1:1, eg. `Net-SNMP`, which
+/// was split into several licenses, are not included. The bare GNU licenses,
+/// eg. `GPL-2.0`, are not listed here either, as their `-only` successors are
+/// derived from the license list when generating the table.
+pub const DEPRECATED_SUCCESSORS: &[(&str, &str, Option<&str>)] = &[
+    ("BSD-2-Clause-FreeBSD", "BSD-2-Clause", None),
+    ("BSD-2-Clause-NetBSD", "BSD-2-Clause", None),
+    (
+        "GPL-2.0-with-GCC-exception",
+        "GPL-2.0-only",
+        Some("GCC-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-autoconf-exception",
+        "GPL-2.0-only",
+        Some("Autoconf-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-bison-exception",
+        "GPL-2.0-only",
+        Some("Bison-exception-2.2"),
+    ),
+    (
+        "GPL-2.0-with-classpath-exception",
+        "GPL-2.0-only",
+        Some("Classpath-exception-2.0"),
+    ),
+    (
+        "GPL-2.0-with-font-exception",
+        "GPL-2.0-only",
+        Some("Font-exception-2.0"),
+    ),
+    (
+        "GPL-3.0-with-GCC-exception",
+        "GPL-3.0-only",
+        Some("GCC-exception-3.1"),
+    ),
+    (
+        "GPL-3.0-with-autoconf-exception",
+        "GPL-3.0-only",
+        Some("Autoconf-exception-3.0"),
+    ),
+    ("Nunit", "zlib-acknowledgement", None),
+    ("StandardML-NJ", "SMLNJ", None),
+    ("bzip2-1.0.5", "bzip2-1.0.6", None),
+    ("eCos-2.0", "GPL-2.0-or-later", Some("eCos-exception-2.0")),
+    (
+        "wxWindows",
+        "LGPL-2.0-or-later",
+        Some("WxWindows-exception-3.1"),
+    ),
+];